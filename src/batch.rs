@@ -0,0 +1,256 @@
+//! Batch clipping over many subject and clip pairs.
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+use num_traits::ToPrimitive;
+
+use crate::{Edge, Geometry, IsClose, Shape, Vertex};
+
+/// The boolean operation to perform over a pair of shapes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    /// The union of the subject and clip shapes.
+    Union,
+    /// The difference of the clip shape on the subject one.
+    Difference,
+    /// The intersection of the subject and clip shapes.
+    Intersection,
+}
+
+impl Operation {
+    /// Performs this operation over the given operands.
+    fn apply<T>(
+        self,
+        subject: Shape<T>,
+        clip: Shape<T>,
+        tolerance: <T::Vertex as IsClose>::Tolerance,
+    ) -> Option<Shape<T>>
+    where
+        T: Geometry + Clone + IntoIterator<Item = T::Vertex>,
+        T::Vertex: Copy + PartialEq + PartialOrd,
+        for<'a> T::Edge<'a>: Edge<'a>,
+        <T::Vertex as Vertex>::Scalar: Copy + PartialOrd + ToPrimitive,
+        <T::Vertex as IsClose>::Tolerance: Clone,
+    {
+        match self {
+            Operation::Union => subject.or(clip, tolerance),
+            Operation::Difference => subject.not(clip, tolerance),
+            Operation::Intersection => subject.and(clip, tolerance),
+        }
+    }
+}
+
+/// An incremental union builder amortizing work across insertions.
+///
+/// Folding a union left to right re-traverses the whole accumulated shape on every insertion,
+/// making the total cost quadratic in the amount of inserted edges. The accumulator instead
+/// merges like a binary counter: each level holds at most one partial union of roughly two to
+/// the level inputs, so an insertion only ever pairs partials of comparable size and the total
+/// cost stays at one pairing per input per level.
+pub struct UnionAccumulator<T>
+where
+    T: Geometry,
+{
+    /// The partial unions accumulated so far, indexed by merge depth.
+    levels: Vec<Option<Shape<T>>>,
+    /// The tolerance every merge is performed with.
+    tolerance: <T::Vertex as IsClose>::Tolerance,
+}
+
+impl<T> UnionAccumulator<T>
+where
+    T: Geometry + Clone + IntoIterator<Item = T::Vertex>,
+    T::Vertex: Copy + PartialEq + PartialOrd,
+    for<'a> T::Edge<'a>: Edge<'a>,
+    <T::Vertex as Vertex>::Scalar: Copy + PartialOrd + ToPrimitive,
+    <T::Vertex as IsClose>::Tolerance: Clone,
+{
+    /// Returns an empty accumulator merging with the given tolerance.
+    pub fn new(tolerance: <T::Vertex as IsClose>::Tolerance) -> Self {
+        Self {
+            levels: Vec::new(),
+            tolerance,
+        }
+    }
+
+    /// Adds the given shape to the running union.
+    ///
+    /// Shapes whose merge fails to produce an output are silently discarded, matching the
+    /// behavior of [`Shape::or`].
+    pub fn add(&mut self, shape: Shape<T>) {
+        let mut carry = shape;
+        for level in self.levels.iter_mut() {
+            match level.take() {
+                Some(resident) => {
+                    let Some(union) = resident.or_ref(&carry, self.tolerance.clone()) else {
+                        // A failed merge must cost only the carry, never the union accumulated
+                        // at this level.
+                        *level = Some(resident);
+                        return;
+                    };
+
+                    carry = union;
+                }
+                None => {
+                    *level = Some(carry);
+                    return;
+                }
+            }
+        }
+
+        self.levels.push(Some(carry));
+    }
+
+    /// Returns the union of every added shape, or none if nothing was added.
+    pub fn finish(self) -> Option<Shape<T>> {
+        let Self { levels, tolerance } = self;
+        levels
+            .into_iter()
+            .flatten()
+            .fold(None, |accumulated, shape| match accumulated {
+                None => Some(shape),
+                Some(accumulated) => accumulated.or(shape, tolerance.clone()),
+            })
+    }
+}
+
+/// Performs the given operation over each pair of subject and clip shapes, processing pairs in
+/// parallel.
+///
+/// Pairs are claimed from a shared queue by as many workers as the available parallelism allows,
+/// so a slow pair never stalls the remaining ones. The output preserves the input order.
+pub fn clip_all<T>(
+    pairs: Vec<(Shape<T>, Shape<T>)>,
+    operation: Operation,
+    tolerance: <T::Vertex as IsClose>::Tolerance,
+) -> Vec<Option<Shape<T>>>
+where
+    T: Geometry + Clone + IntoIterator<Item = T::Vertex> + Send,
+    T::Vertex: Copy + PartialEq + PartialOrd + Send,
+    for<'a> T::Edge<'a>: Edge<'a>,
+    <T::Vertex as Vertex>::Scalar: Copy + PartialOrd + ToPrimitive,
+    <T::Vertex as IsClose>::Tolerance: Clone + Sync,
+{
+    // Each pending pair lives behind its own lock, claimed at most once by a single worker.
+    type Job<T> = Mutex<Option<(Shape<T>, Shape<T>)>>;
+
+    let total = pairs.len();
+    let jobs: Vec<Job<T>> = pairs
+        .into_iter()
+        .map(|pair| Mutex::new(Some(pair)))
+        .collect();
+
+    let results: Vec<Mutex<Option<Shape<T>>>> = (0..total).map(|_| Mutex::new(None)).collect();
+    let cursor = AtomicUsize::new(0);
+
+    let workers = thread::available_parallelism()
+        .map(usize::from)
+        .unwrap_or(1)
+        .min(total.max(1));
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let index = cursor.fetch_add(1, Ordering::Relaxed);
+                if index >= total {
+                    break;
+                }
+
+                let Some((subject, clip)) = jobs[index].lock().ok().and_then(|mut job| job.take())
+                else {
+                    continue;
+                };
+
+                if let Ok(mut result) = results[index].lock() {
+                    *result = operation.apply(subject, clip, tolerance.clone());
+                };
+            });
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|result| result.into_inner().unwrap_or_default())
+        .collect()
+}
+
+#[cfg(test)]
+#[cfg(feature = "cartesian")]
+mod tests {
+    use crate::{
+        batch::{clip_all, Operation},
+        cartesian::Polygon,
+        Shape,
+    };
+
+    #[test]
+    fn union_accumulator_matches_the_sequential_fold() {
+        use crate::batch::UnionAccumulator;
+
+        let shapes: Vec<Shape<Polygon<f64>>> = vec![
+            Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+            Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]),
+            Shape::new(vec![[10., 0.], [14., 0.], [14., 4.], [10., 4.]]),
+            Shape::new(vec![[20., 0.], [24., 0.], [24., 4.], [20., 4.]]),
+        ];
+
+        let want = shapes
+            .iter()
+            .cloned()
+            .reduce(|accumulated, shape| {
+                accumulated
+                    .or(shape, Default::default())
+                    .expect("the union must exist")
+            })
+            .expect("the shapes must not be empty");
+
+        let mut accumulator = UnionAccumulator::new(Default::default());
+        for shape in shapes {
+            accumulator.add(shape);
+        }
+
+        assert_eq!(accumulator.finish(), Some(want));
+
+        let empty: UnionAccumulator<Polygon<f64>> = UnionAccumulator::new(Default::default());
+        assert_eq!(empty.finish(), None, "an empty accumulator must yield nothing");
+    }
+
+    #[test]
+    fn batch_clipping_preserves_input_order() {
+        type Pair = (Shape<Polygon<f64>>, Shape<Polygon<f64>>);
+
+        let pairs: Vec<Pair> = (0..32)
+            .map(|index| {
+                let offset = index as f64;
+                (
+                    Shape::new(vec![
+                        [offset, 0.],
+                        [offset + 4., 0.],
+                        [offset + 4., 4.],
+                        [offset, 4.],
+                    ]),
+                    Shape::new(vec![
+                        [offset + 2., 2.],
+                        [offset + 6., 2.],
+                        [offset + 6., 6.],
+                        [offset + 2., 6.],
+                    ]),
+                )
+            })
+            .collect();
+
+        let want: Vec<Option<Shape<Polygon<f64>>>> = pairs
+            .iter()
+            .map(|(subject, clip)| subject.clone().or(clip.clone(), Default::default()))
+            .collect();
+
+        let got = clip_all(pairs, Operation::Union, Default::default());
+        assert_eq!(got, want);
+    }
+}
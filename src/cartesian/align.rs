@@ -0,0 +1,247 @@
+//! Rigid registration of cartesian shapes.
+
+use num_traits::{Float, Signed};
+
+use crate::{
+    cartesian::{Point, Polygon},
+    Shape, Vertex as _,
+};
+
+/// The amount of points each shape is resampled to before registration.
+const ALIGNMENT_RESOLUTION: usize = 128;
+
+/// The amount of refinement iterations the registration performs.
+const ALIGNMENT_ITERATIONS: usize = 32;
+
+/// A similarity transform registering one shape onto another.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Alignment<T> {
+    /// The counterclockwise rotation, in radians.
+    pub rotation: T,
+    /// The uniform scale factor.
+    pub scale: T,
+    /// The translation applied after rotating and scaling.
+    pub translation: Point<T>,
+}
+
+impl<T> Alignment<T>
+where
+    T: Signed + Float,
+{
+    /// Returns the given vertex under this transform.
+    pub fn transform(&self, vertex: Point<T>) -> Point<T> {
+        let (sin, cos) = self.rotation.sin_cos();
+        let rotated = Point {
+            x: vertex.x * cos - vertex.y * sin,
+            y: vertex.x * sin + vertex.y * cos,
+        };
+
+        rotated * self.scale + self.translation
+    }
+}
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float,
+{
+    /// Returns the rigid transform that best registers this shape onto the other, or none if
+    /// either shape has no perimeter.
+    ///
+    /// Both boundaries are resampled uniformly by arc length and registered by iterating
+    /// nearest-neighbour correspondences with a least-squares transform estimate, as in the
+    /// iterative closest point algorithm. When `with_scale` is set the estimate also fits a
+    /// uniform scale factor; otherwise the transform is a pure rotation and translation.
+    ///
+    /// As any local registration, this expects the shapes to be roughly aligned already: it
+    /// removes the residual drift between survey epochs, not arbitrary poses.
+    pub fn align_to(&self, other: &Self, with_scale: bool) -> Option<Alignment<T>> {
+        let source = resampled(self)?;
+        let target = resampled(other)?;
+
+        let mut alignment = Alignment {
+            rotation: T::zero(),
+            scale: T::one(),
+            translation: Point {
+                x: T::zero(),
+                y: T::zero(),
+            },
+        };
+
+        for _ in 0..ALIGNMENT_ITERATIONS {
+            let matches: Vec<Point<T>> = source
+                .iter()
+                .map(|&point| {
+                    let moved = alignment.transform(point);
+                    target
+                        .iter()
+                        .copied()
+                        .min_by(|a, b| {
+                            moved
+                                .distance(a)
+                                .partial_cmp(&moved.distance(b))
+                                .unwrap_or(std::cmp::Ordering::Equal)
+                        })
+                        .unwrap_or(moved)
+                })
+                .collect();
+
+            alignment = estimated(&source, &matches, with_scale)?;
+        }
+
+        Some(alignment)
+    }
+}
+
+/// Returns the least-squares [`Alignment`] mapping each source point onto its counterpart, or
+/// none if the sources are all coincident.
+fn estimated<T>(source: &[Point<T>], target: &[Point<T>], with_scale: bool) -> Option<Alignment<T>>
+where
+    T: Signed + Float,
+{
+    let len = T::from(source.len())?;
+    let mean = |points: &[Point<T>]| {
+        points.iter().fold(
+            Point {
+                x: T::zero(),
+                y: T::zero(),
+            },
+            |mean, point| mean + *point,
+        ) * (T::one() / len)
+    };
+
+    let source_mean = mean(source);
+    let target_mean = mean(target);
+
+    let (dot, cross, spread) = source.iter().zip(target).fold(
+        (T::zero(), T::zero(), T::zero()),
+        |(dot, cross, spread), (&s, &t)| {
+            let s = s - source_mean;
+            let t = t - target_mean;
+            (
+                dot + s.x * t.x + s.y * t.y,
+                cross + s.x * t.y - s.y * t.x,
+                spread + s.x * s.x + s.y * s.y,
+            )
+        },
+    );
+
+    if spread.is_zero() {
+        return None;
+    }
+
+    let rotation = cross.atan2(dot);
+    let scale = if with_scale {
+        dot.hypot(cross) / spread
+    } else {
+        T::one()
+    };
+
+    let (sin, cos) = rotation.sin_cos();
+    let translation = Point {
+        x: target_mean.x - (source_mean.x * cos - source_mean.y * sin) * scale,
+        y: target_mean.y - (source_mean.x * sin + source_mean.y * cos) * scale,
+    };
+
+    Some(Alignment {
+        rotation,
+        scale,
+        translation,
+    })
+}
+
+/// Returns the given shape resampled to [`ALIGNMENT_RESOLUTION`] points uniformly spaced by arc
+/// length along its boundaries, or none if the shape has no perimeter.
+fn resampled<T>(shape: &Shape<Polygon<T>>) -> Option<Vec<Point<T>>>
+where
+    T: Signed + Float,
+{
+    let edges: Vec<(Point<T>, Point<T>)> = shape
+        .boundaries
+        .iter()
+        .flat_map(|boundary| {
+            boundary
+                .vertices
+                .iter()
+                .zip(boundary.vertices.iter().cycle().skip(1))
+                .map(|(&from, &to)| (from, to))
+        })
+        .collect();
+
+    let perimeter = edges
+        .iter()
+        .fold(T::zero(), |total, (from, to)| total + from.distance(to));
+
+    if perimeter.is_zero() {
+        return None;
+    }
+
+    let spacing = perimeter / T::from(ALIGNMENT_RESOLUTION)?;
+    let mut samples = Vec::with_capacity(ALIGNMENT_RESOLUTION);
+    let mut next = T::zero();
+    let mut walked = T::zero();
+
+    for (from, to) in edges {
+        let length = from.distance(&to);
+        while next < walked + length && samples.len() < ALIGNMENT_RESOLUTION {
+            let fraction = (next - walked) / length;
+            samples.push(from + (to - from) * fraction);
+            next = next + spacing;
+        }
+
+        walked = walked + length;
+    }
+
+    Some(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Shape};
+
+    #[test]
+    fn alignment_recovers_a_rigid_drift() {
+        let source: Shape<Polygon<f64>> =
+            Shape::new(vec![[0., 0.], [4., 0.], [4., 2.], [0., 2.]]);
+
+        let drift = super::Alignment {
+            rotation: 0.3,
+            scale: 1.,
+            translation: [1., -2.].into(),
+        };
+
+        let target = source
+            .clone()
+            .transform_coords(|vertex| drift.transform(vertex));
+
+        let got = source
+            .align_to(&target, false)
+            .expect("the alignment must be estimated");
+
+        assert!(
+            (got.rotation - drift.rotation).abs() < 1e-6,
+            "the rotation must be recovered, got {}",
+            got.rotation
+        );
+        assert!(
+            (got.translation.x - drift.translation.x).abs() < 1e-6
+                && (got.translation.y - drift.translation.y).abs() < 1e-6,
+            "the translation must be recovered, got {:?}",
+            got.translation
+        );
+        assert_eq!(got.scale, 1., "an unscaled alignment must keep a unit scale");
+
+        let scaled = source
+            .clone()
+            .transform_coords(|vertex| vertex * 1.25 + [0.5, 0.5].into());
+
+        let got = source
+            .align_to(&scaled, true)
+            .expect("the scaled alignment must be estimated");
+
+        assert!(
+            (got.scale - 1.25).abs() < 1e-2,
+            "the scale must be recovered, got {}",
+            got.scale
+        );
+    }
+}
@@ -0,0 +1,221 @@
+//! Bezier profiles over the cartesian backend.
+//!
+//! Like circular-arc profiles, Bezier boundaries cannot implement [`Geometry`] directly: the
+//! clipper reconstructs edges from endpoint pairs alone, which would drop the control points at
+//! every inserted intersection vertex. Rings are instead flattened into ordinary polygons within
+//! an explicit deviation bound before taking part in boolean operations, whose outputs remain
+//! polygonal: no curve re-fitting is attempted.
+//!
+//! [`Geometry`]: crate::Geometry
+
+use num_traits::{Float, Signed};
+
+use crate::cartesian::{Point, Polygon};
+
+/// The maximum amount of recursive subdivisions when flattening a single curve.
+const MAX_SUBDIVISIONS: usize = 32;
+
+/// A single piece of a [`BezierRing`], starting where the previous one ended.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BezierSegment<T> {
+    /// A straight segment to the given endpoint.
+    Line { to: Point<T> },
+    /// A quadratic Bezier curve to the given endpoint.
+    Quadratic { control: Point<T>, to: Point<T> },
+    /// A cubic Bezier curve to the given endpoint.
+    Cubic {
+        control_from: Point<T>,
+        control_to: Point<T>,
+        to: Point<T>,
+    },
+}
+
+/// A closed boundary made of straight segments and Bezier curves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BezierRing<T> {
+    /// The starting point of the ring.
+    pub start: Point<T>,
+    /// The ordered pieces of the ring, implicitly closed back to the start.
+    pub segments: Vec<BezierSegment<T>>,
+}
+
+impl<T> BezierRing<T>
+where
+    T: Signed + Float,
+{
+    /// Returns the polygon approximating this ring, whose vertices deviate from the original
+    /// curves by no more than the given bound, or none if the bound is not positive.
+    pub fn flattened(&self, deviation: T) -> Option<Polygon<T>> {
+        if !deviation.is_positive() {
+            return None;
+        }
+
+        let mut vertices = vec![self.start];
+        for segment in &self.segments {
+            let from = *vertices.last()?;
+            match *segment {
+                BezierSegment::Line { to } => vertices.push(to),
+                BezierSegment::Quadratic { control, to } => {
+                    // A quadratic curve is a cubic one whose control points lie two thirds of
+                    // the way towards the shared control.
+                    let two_thirds = (T::one() + T::one()) / (T::one() + T::one() + T::one());
+                    let control_from = from + (control - from) * two_thirds;
+                    let control_to = to + (control - to) * two_thirds;
+
+                    flatten_cubic(
+                        from,
+                        control_from,
+                        control_to,
+                        to,
+                        deviation,
+                        MAX_SUBDIVISIONS,
+                        &mut vertices,
+                    );
+                }
+                BezierSegment::Cubic {
+                    control_from,
+                    control_to,
+                    to,
+                } => flatten_cubic(
+                    from,
+                    control_from,
+                    control_to,
+                    to,
+                    deviation,
+                    MAX_SUBDIVISIONS,
+                    &mut vertices,
+                ),
+            }
+        }
+
+        // The ring is implicitly closed, so a final piece landing on the start would duplicate it.
+        if vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+
+        Some(Polygon { vertices })
+    }
+}
+
+/// Appends the vertices approximating the given cubic curve, excluding its starting point.
+fn flatten_cubic<T>(
+    from: Point<T>,
+    control_from: Point<T>,
+    control_to: Point<T>,
+    to: Point<T>,
+    deviation: T,
+    depth: usize,
+    vertices: &mut Vec<Point<T>>,
+) where
+    T: Signed + Float,
+{
+    if depth == 0 || is_flat(&from, &control_from, &control_to, &to, deviation) {
+        vertices.push(to);
+        return;
+    }
+
+    let half = T::one() / (T::one() + T::one());
+    let lerp = |a: Point<T>, b: Point<T>| (a + b) * half;
+
+    // De Casteljau subdivision at the curve's midpoint.
+    let ab = lerp(from, control_from);
+    let bc = lerp(control_from, control_to);
+    let cd = lerp(control_to, to);
+    let abc = lerp(ab, bc);
+    let bcd = lerp(bc, cd);
+    let midpoint = lerp(abc, bcd);
+
+    flatten_cubic(from, ab, abc, midpoint, deviation, depth - 1, vertices);
+    flatten_cubic(midpoint, bcd, cd, to, deviation, depth - 1, vertices);
+}
+
+/// Returns true if, and only if, both control points lie within the given deviation of the
+/// chord between the curve's endpoints.
+fn is_flat<T>(
+    from: &Point<T>,
+    control_from: &Point<T>,
+    control_to: &Point<T>,
+    to: &Point<T>,
+    deviation: T,
+) -> bool
+where
+    T: Signed + Float,
+{
+    let chord = *to - *from;
+    let length = (chord.x * chord.x + chord.y * chord.y).sqrt();
+
+    let distance = |point: &Point<T>| {
+        let offset = *point - *from;
+        if length.is_zero() {
+            (offset.x * offset.x + offset.y * offset.y).sqrt()
+        } else {
+            (chord.x * offset.y - chord.y * offset.x).abs() / length
+        }
+    };
+
+    distance(control_from) <= deviation && distance(control_to) <= deviation
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Geometry};
+
+    use super::{BezierRing, BezierSegment};
+
+    #[test]
+    fn flattened_ring_approximates_the_curve() {
+        // The control point offset approximating a quarter circle with a cubic curve.
+        let kappa = 0.552_284_749_830_793_4;
+
+        let ring: BezierRing<f64> = BezierRing {
+            start: [1., 0.].into(),
+            segments: vec![
+                BezierSegment::Cubic {
+                    control_from: [1., kappa].into(),
+                    control_to: [kappa, 1.].into(),
+                    to: [0., 1.].into(),
+                },
+                BezierSegment::Cubic {
+                    control_from: [-kappa, 1.].into(),
+                    control_to: [-1., kappa].into(),
+                    to: [-1., 0.].into(),
+                },
+                BezierSegment::Cubic {
+                    control_from: [-1., -kappa].into(),
+                    control_to: [-kappa, -1.].into(),
+                    to: [0., -1.].into(),
+                },
+                BezierSegment::Cubic {
+                    control_from: [kappa, -1.].into(),
+                    control_to: [1., -kappa].into(),
+                    to: [1., 0.].into(),
+                },
+            ],
+        };
+
+        let polygon = ring.flattened(1e-3).expect("the ring must flatten");
+        let area = polygon.area();
+
+        assert!(
+            (area - std::f64::consts::PI).abs() < 1e-2,
+            "got area = {area}, want roughly pi"
+        );
+    }
+
+    #[test]
+    fn flattened_straight_ring_is_the_polygon_itself() {
+        let ring: BezierRing<f64> = BezierRing {
+            start: [0., 0.].into(),
+            segments: vec![
+                BezierSegment::Line { to: [4., 0.].into() },
+                BezierSegment::Line { to: [2., 4.].into() },
+                BezierSegment::Line { to: [0., 0.].into() },
+            ],
+        };
+
+        let polygon = ring.flattened(1e-6).expect("the ring must flatten");
+        let want: Polygon<f64> = vec![[0., 0.], [4., 0.], [2., 4.]].into();
+
+        assert_eq!(polygon.vertices, want.vertices);
+    }
+}
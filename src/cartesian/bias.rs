@@ -0,0 +1,171 @@
+//! Biased clipping with guaranteed containment of the exact result.
+
+use num_traits::{Float, Signed};
+
+use crate::{batch::Operation, cartesian::Polygon, IsClose, Shape, Tolerance};
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float + IsClose<Tolerance = Tolerance<T>> + 'static,
+{
+    /// Returns a guaranteed superset of the exact result of the given operation between this
+    /// shape and the other, or none if it yields no output.
+    ///
+    /// Every decision the clipper takes is exact except for geometry lying within the
+    /// tolerance of a boundary, which bounds how far the computed result can stray from the
+    /// exact one. Padding the computed result by that worst-case displacement therefore yields
+    /// a region the exact result cannot escape, which is the right bias for keep-out zones and
+    /// other safety buffers where missing area is worse than spurious area.
+    pub fn clip_conservative(
+        &self,
+        other: &Self,
+        operation: Operation,
+        tolerance: Tolerance<T>,
+    ) -> Option<Self> {
+        let output = clipped(self, other, operation, tolerance)?;
+        let margin = margin(self, other, tolerance);
+        if margin.is_zero() {
+            return Some(output);
+        }
+
+        output.minkowski_sum(&structuring(margin), tolerance)
+    }
+
+    /// Like [`Self::clip_conservative`], but returning a guaranteed subset of the exact result
+    /// instead, for consumers where spurious area is worse than missing area.
+    pub fn clip_aggressive(
+        &self,
+        other: &Self,
+        operation: Operation,
+        tolerance: Tolerance<T>,
+    ) -> Option<Self> {
+        let output = clipped(self, other, operation, tolerance)?;
+        let margin = margin(self, other, tolerance);
+        if margin.is_zero() {
+            return Some(output);
+        }
+
+        output.erode(margin, tolerance)
+    }
+}
+
+/// Returns the given operation between the two given shapes, if it yields any output.
+fn clipped<T>(
+    subject: &Shape<Polygon<T>>,
+    clip: &Shape<Polygon<T>>,
+    operation: Operation,
+    tolerance: Tolerance<T>,
+) -> Option<Shape<Polygon<T>>>
+where
+    T: Signed + Float + IsClose<Tolerance = Tolerance<T>> + 'static,
+{
+    match operation {
+        Operation::Union => subject.or_ref(clip, tolerance),
+        Operation::Difference => subject.not_ref(clip, tolerance),
+        Operation::Intersection => subject.and_ref(clip, tolerance),
+    }
+}
+
+/// Returns the worst-case displacement the given tolerance admits over the extent of the given
+/// operands.
+///
+/// The bound is doubled so the padding pass, which works under the same tolerance, cannot eat
+/// into the guarantee it is meant to provide.
+fn margin<T>(
+    subject: &Shape<Polygon<T>>,
+    clip: &Shape<Polygon<T>>,
+    tolerance: Tolerance<T>,
+) -> T
+where
+    T: Signed + Float,
+{
+    let extent = [subject.stats(), clip.stats()]
+        .into_iter()
+        .flatten()
+        .flat_map(|stats| {
+            [
+                stats.min.x.abs(),
+                stats.min.y.abs(),
+                stats.max.x.abs(),
+                stats.max.y.abs(),
+            ]
+        })
+        .fold(T::zero(), T::max);
+
+    let two = T::one() + T::one();
+    (tolerance.absolute.into_inner() + tolerance.relative.into_inner() * extent) * two
+}
+
+/// Returns the structuring square covering every displacement up to the given magnitude.
+fn structuring<T>(margin: T) -> Polygon<T>
+where
+    T: Signed + Float,
+{
+    Polygon::from(vec![
+        [-margin, -margin],
+        [margin, -margin],
+        [margin, margin],
+        [-margin, margin],
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{batch::Operation, cartesian::Polygon, Shape, Tolerance};
+
+    #[test]
+    fn biased_clipping_brackets_the_exact_result() {
+        let subject: Shape<Polygon<f64>> =
+            Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip: Shape<Polygon<f64>> = Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]);
+
+        let tolerance = Tolerance::for_extent(6.);
+
+        let exact = subject
+            .or_ref(&clip, tolerance)
+            .expect("the union must yield an output");
+        let conservative = subject
+            .clip_conservative(&clip, Operation::Union, tolerance)
+            .expect("the conservative union must yield an output");
+        let aggressive = subject
+            .clip_aggressive(&clip, Operation::Union, tolerance)
+            .expect("the aggressive union must yield an output");
+
+        let area = |shape: &Shape<Polygon<f64>>| {
+            shape.stats().expect("the shape must have vertices").area
+        };
+
+        assert!(
+            area(&aggressive) < area(&exact) && area(&exact) < area(&conservative),
+            "the biased results must bracket the exact one"
+        );
+
+        for boundary in &exact.boundaries {
+            for vertex in &boundary.vertices {
+                assert_ne!(
+                    conservative.winding(vertex, &tolerance),
+                    0,
+                    "the conservative result must contain the exact boundary"
+                );
+            }
+        }
+
+        for boundary in &aggressive.boundaries {
+            for vertex in &boundary.vertices {
+                assert_ne!(
+                    exact.winding(vertex, &tolerance),
+                    0,
+                    "the exact result must contain the aggressive boundary"
+                );
+            }
+        }
+
+        assert_eq!(
+            subject
+                .clip_conservative(&clip, Operation::Union, Tolerance::default())
+                .expect("the zero-tolerance union must yield an output"),
+            exact,
+            "a zero tolerance admits no displacement and needs no padding"
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! Change detection between two versions of a shape.
+
+use num_traits::{Float, Signed};
+
+use crate::{
+    cartesian::Polygon,
+    options::ClipError,
+    shape::{NotOperator, ReverseNotOperator},
+    ClipPlan, IsClose, Shape, Tolerance,
+};
+
+/// The outcome of comparing two versions of a [`Shape`].
+#[derive(Debug, Clone)]
+pub struct ChangeReport<T> {
+    /// The region covered by the new version but not the old one, if any.
+    pub added: Option<Shape<Polygon<T>>>,
+    /// The region covered by the old version but not the new one, if any.
+    pub removed: Option<Shape<Polygon<T>>>,
+    /// The area of the added region.
+    pub added_area: T,
+    /// The area of the removed region.
+    pub removed_area: T,
+    /// The area covered by both versions alike.
+    pub unchanged_area: T,
+}
+
+impl<T> ChangeReport<T> {
+    /// Returns true if, and only if, the two versions cover the very same region.
+    pub fn is_unchanged(&self) -> bool {
+        self.added.is_none() && self.removed.is_none()
+    }
+}
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float + IsClose<Tolerance = Tolerance<T>> + 'static,
+{
+    /// Returns the [`ChangeReport`] describing what the other shape adds to and removes from
+    /// this one.
+    ///
+    /// Both directions of the difference and the summary areas come out of a single overlay:
+    /// the intersection graph is built once and traversed once per direction.
+    ///
+    /// Fails if, and only if, the overlay is interrupted before completing.
+    pub fn change_report(
+        &self,
+        other: &Self,
+        tolerance: Tolerance<T>,
+    ) -> Result<ChangeReport<T>, ClipError> {
+        let plan = ClipPlan::new(self.clone(), other.clone(), tolerance)?;
+        let removed = plan.execute::<NotOperator<Polygon<T>>>()?;
+        let added = plan.execute::<ReverseNotOperator<Polygon<T>>>()?;
+
+        let area = |shape: &Option<Self>| {
+            shape
+                .as_ref()
+                .and_then(Self::stats)
+                .map(|stats| stats.area)
+                .unwrap_or_else(T::zero)
+        };
+
+        let own_area = self.stats().map(|stats| stats.area).unwrap_or_else(T::zero);
+        let removed_area = area(&removed);
+
+        Ok(ChangeReport {
+            added_area: area(&added),
+            removed_area,
+            unchanged_area: own_area - removed_area,
+            added,
+            removed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Shape, Tolerance};
+
+    #[test]
+    fn change_report_splits_the_symmetric_difference() {
+        let old: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let new: Shape<Polygon<f64>> = Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]);
+
+        let report = old
+            .change_report(&new, Tolerance::default())
+            .expect("the report must complete");
+
+        assert_eq!(
+            report.removed,
+            old.not_ref(&new, Tolerance::default()),
+            "the removed region must match the direct difference"
+        );
+        assert_eq!(
+            report.added,
+            new.not_ref(&old, Tolerance::default()),
+            "the added region must match the difference with the roles reversed"
+        );
+
+        assert!(
+            (report.removed_area - 12.).abs() < 1e-9,
+            "the removed area must be measured, got {}",
+            report.removed_area
+        );
+        assert!(
+            (report.added_area - 12.).abs() < 1e-9,
+            "the added area must be measured, got {}",
+            report.added_area
+        );
+        assert!(
+            (report.unchanged_area - 4.).abs() < 1e-9,
+            "the unchanged area must be measured, got {}",
+            report.unchanged_area
+        );
+
+        let report = old
+            .change_report(&old.clone(), Tolerance::default())
+            .expect("the identity report must complete");
+
+        assert!(
+            report.is_unchanged(),
+            "identical versions must report no change"
+        );
+    }
+}
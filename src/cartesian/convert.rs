@@ -0,0 +1,99 @@
+//! Conversion between single and double precision cartesian shapes.
+
+use crate::{
+    cartesian::{Point, Polygon},
+    Shape,
+};
+
+impl Shape<Polygon<f32>> {
+    /// Returns this shape with every coordinate promoted to double precision.
+    ///
+    /// Every `f32` is exactly representable as an `f64`, so the promotion is lossless and the
+    /// returned shape describes the very same geometry.
+    pub fn to_f64(&self) -> Shape<Polygon<f64>> {
+        Shape {
+            boundaries: self
+                .boundaries
+                .iter()
+                .map(|boundary| Polygon {
+                    vertices: boundary
+                        .vertices
+                        .iter()
+                        .map(|vertex| Point {
+                            x: f64::from(vertex.x),
+                            y: f64::from(vertex.y),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Shape<Polygon<f64>> {
+    /// Returns this shape with every coordinate demoted to single precision, or none if any
+    /// coordinate does not survive the demotion.
+    ///
+    /// Coordinates within range are rounded to the nearest `f32`, losing precision; those beyond
+    /// the `f32` range, or not finite to begin with, discard the whole conversion instead of
+    /// silently saturating.
+    pub fn to_f32(&self) -> Option<Shape<Polygon<f32>>> {
+        let demote = |value: f64| {
+            let demoted = value as f32;
+            demoted.is_finite().then_some(demoted)
+        };
+
+        Some(Shape {
+            boundaries: self
+                .boundaries
+                .iter()
+                .map(|boundary| {
+                    Some(Polygon {
+                        vertices: boundary
+                            .vertices
+                            .iter()
+                            .map(|vertex| {
+                                Some(Point {
+                                    x: demote(vertex.x)?,
+                                    y: demote(vertex.y)?,
+                                })
+                            })
+                            .collect::<Option<Vec<_>>>()?,
+                    })
+                })
+                .collect::<Option<Vec<_>>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Shape};
+
+    #[test]
+    fn precision_conversions_bridge_safely() {
+        let shape: Shape<Polygon<f32>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+
+        let promoted = shape.to_f64();
+        assert_eq!(
+            promoted,
+            Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+            "the promotion must preserve the geometry"
+        );
+
+        assert_eq!(
+            promoted.to_f32().as_ref(),
+            Some(&shape),
+            "in-range coordinates must round-trip"
+        );
+
+        let overflowing: Shape<Polygon<f64>> =
+            Shape::new(vec![[0., 0.], [1e300, 0.], [1e300, 1e300]]);
+
+        assert_eq!(
+            overflowing.to_f32(),
+            None,
+            "coordinates beyond the f32 range must discard the conversion"
+        );
+    }
+}
@@ -0,0 +1,223 @@
+//! Circular-arc profiles over the cartesian backend.
+//!
+//! A fully curved [`Geometry`] implementation is not possible under the current [`Edge`]
+//! contract: the clipper reconstructs edges from endpoint pairs alone through [`Edge::new`], so
+//! any curvature attached to an edge would not survive the insertion of intersection vertices.
+//! Curved profiles are instead flattened into ordinary polygons within an explicit deviation
+//! bound before taking part in boolean operations.
+//!
+//! [`Geometry`]: crate::Geometry
+//! [`Edge`]: crate::Edge
+//! [`Edge::new`]: crate::Edge::new
+
+use num_traits::{Float, Signed};
+
+use crate::{
+    cartesian::{Point, Polygon},
+    Vertex,
+};
+
+/// A vertex of a [`CurvedPolygon`], bulging the edge towards its successor.
+///
+/// The bulge follows the usual CAD convention: the tangent of a quarter of the included angle of
+/// the arc, zero for a straight edge, positive when the arc sweeps counterclockwise from the
+/// vertex to its successor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CurvedVertex<T> {
+    /// The position of the vertex.
+    pub point: Point<T>,
+    /// The bulge of the edge from this vertex to the next.
+    pub bulge: T,
+}
+
+/// A closed profile whose edges may be straight segments or circular arcs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurvedPolygon<T> {
+    /// The ordered list of vertices describing the profile.
+    pub vertices: Vec<CurvedVertex<T>>,
+}
+
+impl<T, U> From<Vec<(U, T)>> for CurvedPolygon<T>
+where
+    U: Into<Point<T>>,
+{
+    fn from(vertices: Vec<(U, T)>) -> Self {
+        Self {
+            vertices: vertices
+                .into_iter()
+                .map(|(point, bulge)| CurvedVertex {
+                    point: point.into(),
+                    bulge,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<T> CurvedPolygon<T>
+where
+    T: Signed + Float,
+{
+    /// Returns the polygon approximating this profile, whose vertices deviate from the original
+    /// arcs by no more than the given bound, or none if the bound is not positive.
+    pub fn flattened(&self, deviation: T) -> Option<Polygon<T>> {
+        if !deviation.is_positive() {
+            return None;
+        }
+
+        let mut vertices = Vec::with_capacity(self.vertices.len());
+        for (position, vertex) in self.vertices.iter().enumerate() {
+            vertices.push(vertex.point);
+            if vertex.bulge.is_zero() {
+                continue;
+            }
+
+            let next = self.vertices[(position + 1) % self.vertices.len()].point;
+            flatten_arc(vertex, &next, deviation, &mut vertices)?;
+        }
+
+        Some(Polygon { vertices })
+    }
+}
+
+/// Appends the intermediate vertices approximating the arc from the given vertex to the next
+/// point, excluding both endpoints.
+fn flatten_arc<T>(
+    from: &CurvedVertex<T>,
+    to: &Point<T>,
+    deviation: T,
+    vertices: &mut Vec<Point<T>>,
+) -> Option<()>
+where
+    T: Signed + Float,
+{
+    let two = T::one() + T::one();
+    let four = two + two;
+
+    let chord = from.point.distance(to);
+    if chord.is_zero() {
+        return Some(());
+    }
+
+    let bulge = from.bulge;
+    let angle = four * bulge.atan();
+    let radius = chord * (T::one() + bulge * bulge) / (four * bulge.abs());
+
+    let midpoint = (from.point + *to) * (T::one() / two);
+    let normal = Point {
+        x: (from.point.y - to.y) / chord,
+        y: (to.x - from.point.x) / chord,
+    };
+
+    let sagitta = chord / two * bulge;
+    let center = midpoint + normal * (radius * bulge.signum() - sagitta);
+
+    // The deviation of an inscribed chord over an arc of angle delta is radius * (1 - cos(delta / 2)).
+    let ratio = (T::one() - deviation / radius).max(-T::one());
+    let max_step = two * ratio.acos();
+    if !max_step.is_positive() {
+        return None;
+    }
+
+    let steps = (angle.abs() / max_step).ceil().max(T::one());
+
+    let step = angle / steps;
+    let (sin, cos) = (step.sin(), step.cos());
+
+    let mut offset = from.point - center;
+    for _ in 1..steps.to_usize()? {
+        offset = Point {
+            x: offset.x * cos - offset.y * sin,
+            y: offset.x * sin + offset.y * cos,
+        };
+
+        vertices.push(center + offset);
+    }
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Geometry, Shape};
+
+    use super::CurvedPolygon;
+
+    #[test]
+    fn flattened_profile_stays_within_deviation() {
+        struct Test {
+            name: &'static str,
+            profile: CurvedPolygon<f64>,
+            deviation: f64,
+            want_area: f64,
+        }
+
+        vec![
+            Test {
+                name: "circle out of two semicircular arcs",
+                profile: vec![([-1., 0.], 1.), ([1., 0.], 1.)].into(),
+                deviation: 1e-3,
+                want_area: std::f64::consts::PI,
+            },
+            Test {
+                name: "square with a bulged top edge",
+                profile: vec![
+                    ([0., 0.], 0.),
+                    ([4., 0.], 0.),
+                    ([4., 4.], 1.),
+                    ([0., 4.], 0.),
+                ]
+                .into(),
+                deviation: 1e-3,
+                want_area: 16. + std::f64::consts::PI * 2.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let polygon = test
+                .profile
+                .flattened(test.deviation)
+                .expect("the profile must flatten");
+
+            let area = polygon.area();
+            assert!(
+                (area - test.want_area).abs() < 1e-2,
+                "{}: got area = {area}, want {}",
+                test.name,
+                test.want_area
+            );
+        });
+    }
+
+    #[test]
+    fn flattened_straight_profile_is_the_polygon_itself() {
+        let profile: CurvedPolygon<f64> =
+            vec![([0., 0.], 0.), ([4., 0.], 0.), ([2., 4.], 0.)].into();
+
+        let polygon = profile.flattened(1e-6).expect("the profile must flatten");
+        let want: Polygon<f64> = vec![[0., 0.], [4., 0.], [2., 4.]].into();
+
+        assert_eq!(polygon.vertices, want.vertices);
+    }
+
+    #[test]
+    fn flattened_profiles_are_clippable() {
+        let circle: CurvedPolygon<f64> = vec![([-1., 0.], 1.), ([1., 0.], 1.)].into();
+        let circle = Shape::new(
+            circle
+                .flattened(2e-3)
+                .expect("the profile must flatten"),
+        );
+
+        let square: Shape<Polygon<f64>> =
+            Shape::new(vec![[0., -2.], [2., -2.], [2., 2.], [0., 2.]]);
+
+        let half = circle.and_ref(&square, Default::default());
+        let area = half.and_then(|half| half.stats()).map(|stats| stats.area);
+
+        assert!(
+            area.is_some_and(|area| (area - std::f64::consts::FRAC_PI_2).abs() < 1e-2),
+            "half a unit circle must remain, got {area:?}"
+        );
+    }
+}
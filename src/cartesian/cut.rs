@@ -0,0 +1,402 @@
+//! Cutting shapes along open polylines and querying them with segments.
+
+use std::cmp::Ordering;
+
+use num_traits::{Float, Signed};
+
+use crate::{
+    cartesian::{Point, Polygon, Segment},
+    Edge, Either, Geometry, IsClose, Location, Shape, Tolerance, Vertex,
+};
+
+/// The portions of a segment lying inside a [`Shape`], together with its boundary crossings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmentIntersection<T> {
+    /// The endpoints of each maximal portion of the segment inside the shape.
+    ///
+    /// Portions running along a boundary count as inside.
+    pub inside: Vec<[Point<T>; 2]>,
+    /// The points at which the segment meets the boundaries of the shape, ordered from the start
+    /// of the segment.
+    pub crossings: Vec<Point<T>>,
+}
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float,
+{
+    /// Splits this shape into the pieces separated by the given open path.
+    ///
+    /// Every maximal run of the path crossing the interior of the shape becomes a chord
+    /// splitting the piece it traverses in two. Runs that dangle inside the shape, or whose
+    /// endpoints do not land on the same boundary of a piece, leave that piece untouched.
+    pub fn cut(&self, path: &[Point<T>], tolerance: &Tolerance<T>) -> Vec<Self> {
+        let mut pieces = vec![self.clone()];
+        for chord in self.chords(path, tolerance) {
+            for position in 0..pieces.len() {
+                if let Some((left, right)) = pieces[position].split(&chord, tolerance) {
+                    pieces[position] = left;
+                    pieces.push(right);
+                    break;
+                }
+            }
+        }
+
+        pieces
+    }
+
+    /// Returns the [`SegmentIntersection`] between this shape and the given segment.
+    ///
+    /// Unlike the boolean operations, this query never builds a degenerate polygon around the
+    /// segment, making it suited for line-of-sight and routing checks.
+    pub fn intersect_segment(
+        &self,
+        segment: &Segment<'_, T>,
+        tolerance: &Tolerance<T>,
+    ) -> SegmentIntersection<T> {
+        let mut crossings: Vec<Point<T>> = self
+            .edges()
+            .filter_map(|(_, edge)| edge.intersection(segment, tolerance))
+            .flat_map(|intersection| match intersection {
+                Either::Left(point) => vec![point],
+                Either::Right(points) => points.to_vec(),
+            })
+            .collect();
+
+        crossings.sort_by(|a, b| {
+            segment
+                .from
+                .distance(a)
+                .partial_cmp(&segment.from.distance(b))
+                .unwrap_or(Ordering::Equal)
+        });
+        crossings.dedup_by(|a, b| a.is_close(b, tolerance));
+
+        let mut stops = Vec::with_capacity(crossings.len() + 2);
+        stops.push(*segment.from);
+        stops.extend(crossings.iter().copied());
+        stops.push(*segment.to);
+
+        let inside = stops
+            .windows(2)
+            .filter(|pair| !pair[0].is_close(&pair[1], tolerance))
+            .filter(|pair| {
+                let midpoint = Segment::new(&pair[0], &pair[1]).midpoint();
+                self.contains(&midpoint, tolerance)
+            })
+            .map(|pair| [pair[0], pair[1]])
+            .collect();
+
+        SegmentIntersection { inside, crossings }
+    }
+
+    /// Returns each maximal run of the path lying inside this shape, delimited by its crossing
+    /// points with the boundaries.
+    fn chords(&self, path: &[Point<T>], tolerance: &Tolerance<T>) -> Vec<Vec<Point<T>>> {
+        let mut chords = Vec::new();
+        let Some(first) = path.first() else {
+            return chords;
+        };
+
+        let mut inside = self.contains(first, tolerance);
+        let mut chord: Option<Vec<Point<T>>> = None;
+
+        for window in path.windows(2) {
+            let segment = Segment::new(&window[0], &window[1]);
+            let mut crossings: Vec<Point<T>> = self
+                .edges()
+                .filter_map(|(_, edge)| match edge.intersection(&segment, tolerance) {
+                    Some(Either::Left(point)) => Some(point),
+                    _ => None,
+                })
+                .collect();
+
+            crossings.sort_by(|a, b| {
+                window[0]
+                    .distance(a)
+                    .partial_cmp(&window[0].distance(b))
+                    .unwrap_or(Ordering::Equal)
+            });
+            crossings.dedup_by(|a, b| a.is_close(b, tolerance));
+
+            for crossing in crossings {
+                let repeated = chord
+                    .as_ref()
+                    .and_then(|chord| chord.last())
+                    .is_some_and(|last| last.is_close(&crossing, tolerance));
+
+                if repeated {
+                    continue;
+                }
+
+                if inside {
+                    // A run that began outside any crossing dangles and cannot cut.
+                    if let Some(mut chord) = chord.take() {
+                        chord.push(crossing);
+                        chords.push(chord);
+                    }
+                } else {
+                    chord = Some(vec![crossing]);
+                }
+
+                inside = !inside;
+            }
+
+            if inside && let Some(chord) = chord.as_mut() {
+                chord.push(window[1]);
+            }
+        }
+
+        chords
+    }
+
+    /// Splits this shape in two along the given chord, whose endpoints must lie on the same
+    /// boundary and whose interior vertices must lie strictly inside the shape.
+    fn split(&self, chord: &[Point<T>], tolerance: &Tolerance<T>) -> Option<(Self, Self)> {
+        let (entry, exit) = (chord.first()?, chord.last()?);
+        let Location::OnBoundary {
+            boundary,
+            edge: entry_edge,
+        } = self.locate(entry, tolerance)
+        else {
+            return None;
+        };
+
+        let Location::OnBoundary {
+            boundary: exit_boundary,
+            edge: exit_edge,
+        } = self.locate(exit, tolerance)
+        else {
+            return None;
+        };
+
+        let dangling = chord[1..chord.len() - 1]
+            .iter()
+            .any(|vertex| self.locate(vertex, tolerance) != Location::Inside);
+
+        if boundary != exit_boundary || dangling {
+            return None;
+        }
+
+        let vertices = &self.boundaries[boundary].vertices;
+        let len = vertices.len();
+        let along = |edge: usize, point: &Point<T>| vertices[edge].distance(point);
+        let count = |from_edge: usize, to_edge: usize, from: &Point<T>, to: &Point<T>| {
+            if from_edge != to_edge {
+                (to_edge + len - from_edge) % len
+            } else if along(to_edge, to) > along(from_edge, from) {
+                0
+            } else {
+                len
+            }
+        };
+
+        let walk = |from_edge: usize, amount: usize| {
+            (1..=amount).map(move |step| vertices[(from_edge + step) % len])
+        };
+
+        let mut left: Vec<Point<T>> = chord.to_vec();
+        left.extend(walk(exit_edge, count(exit_edge, entry_edge, exit, entry)));
+
+        let mut right: Vec<Point<T>> = chord.iter().rev().copied().collect();
+        right.extend(walk(entry_edge, count(entry_edge, exit_edge, entry, exit)));
+
+        let cleanup = |mut vertices: Vec<Point<T>>| {
+            vertices.dedup_by(|a, b| a.is_close(b, tolerance));
+            if let (Some(first), Some(last)) = (vertices.first(), vertices.last())
+                && first.is_close(last, tolerance)
+            {
+                vertices.pop();
+            }
+
+            vertices
+        };
+
+        let (left, right) = (cleanup(left), cleanup(right));
+        if left.len() < 3 || right.len() < 3 {
+            return None;
+        }
+
+        let mut pieces = (
+            Self {
+                boundaries: vec![Polygon { vertices: left }],
+            },
+            Self {
+                boundaries: vec![Polygon { vertices: right }],
+            },
+        );
+
+        for (position, hole) in self.boundaries.iter().enumerate() {
+            if position == boundary {
+                continue;
+            }
+
+            let Some(vertex) = hole.vertices.first() else {
+                continue;
+            };
+
+            if pieces.0.boundaries[0].contains(vertex, tolerance) {
+                pieces.0.boundaries.push(hole.clone());
+            } else {
+                pieces.1.boundaries.push(hole.clone());
+            }
+        }
+
+        Some(pieces)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cartesian::{Point, Polygon, Segment},
+        Edge, Shape, Tolerance,
+    };
+
+    #[test]
+    fn cut_along_open_path() {
+        struct Test {
+            name: &'static str,
+            shape: Shape<Polygon<f64>>,
+            path: Vec<Point<f64>>,
+            want: Vec<f64>,
+        }
+
+        let square = || Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+
+        vec![
+            Test {
+                name: "path crossing the square vertically",
+                shape: square(),
+                path: vec![[2., -1.].into(), [2., 5.].into()],
+                want: vec![8., 8.],
+            },
+            Test {
+                name: "path bending inside the square",
+                shape: square(),
+                path: vec![[-1., 2.].into(), [2., 2.].into(), [2., 5.].into()],
+                want: vec![4., 12.],
+            },
+            Test {
+                name: "path crossing the square twice",
+                shape: square(),
+                path: vec![
+                    [1., -1.].into(),
+                    [1., 5.].into(),
+                    [3., 5.].into(),
+                    [3., -1.].into(),
+                ],
+                want: vec![4., 4., 8.],
+            },
+            Test {
+                name: "path missing the square",
+                shape: square(),
+                path: vec![[-1., -1.].into(), [-1., 5.].into()],
+                want: vec![16.],
+            },
+            Test {
+                name: "path dangling inside the square",
+                shape: square(),
+                path: vec![[2., -1.].into(), [2., 2.].into()],
+                want: vec![16.],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let mut areas: Vec<f64> = test
+                .shape
+                .cut(&test.path, &Tolerance::default())
+                .iter()
+                .filter_map(|piece| piece.stats())
+                .map(|stats| stats.area)
+                .collect();
+
+            areas.sort_by(|a, b| a.total_cmp(b));
+            let close = areas.len() == test.want.len()
+                && areas
+                    .iter()
+                    .zip(test.want.iter())
+                    .all(|(got, want)| (got - want).abs() < 1e-9);
+
+            assert!(close, "{}: got {:?}, want {:?}", test.name, areas, test.want);
+        });
+    }
+
+    #[test]
+    fn segment_intersection_query() {
+        struct Test {
+            name: &'static str,
+            shape: Shape<Polygon<f64>>,
+            from: Point<f64>,
+            to: Point<f64>,
+            want_inside: Vec<[Point<f64>; 2]>,
+            want_crossings: Vec<Point<f64>>,
+        }
+
+        let square = || Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+
+        vec![
+            Test {
+                name: "segment crossing the square",
+                shape: square(),
+                from: [-1., 2.].into(),
+                to: [5., 2.].into(),
+                want_inside: vec![[[0., 2.].into(), [4., 2.].into()]],
+                want_crossings: vec![[0., 2.].into(), [4., 2.].into()],
+            },
+            Test {
+                name: "segment fully inside the square",
+                shape: square(),
+                from: [1., 2.].into(),
+                to: [3., 2.].into(),
+                want_inside: vec![[[1., 2.].into(), [3., 2.].into()]],
+                want_crossings: Vec::new(),
+            },
+            Test {
+                name: "segment missing the square",
+                shape: square(),
+                from: [-1., -1.].into(),
+                to: [-1., 5.].into(),
+                want_inside: Vec::new(),
+                want_crossings: Vec::new(),
+            },
+            Test {
+                name: "segment leaving the square",
+                shape: square(),
+                from: [2., 2.].into(),
+                to: [6., 2.].into(),
+                want_inside: vec![[[2., 2.].into(), [4., 2.].into()]],
+                want_crossings: vec![[4., 2.].into()],
+            },
+            Test {
+                name: "segment crossing a hole",
+                shape: Shape {
+                    boundaries: vec![
+                        vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                        vec![[2., 2.], [2., 6.], [6., 6.], [6., 2.]].into(),
+                    ],
+                },
+                from: [-1., 4.].into(),
+                to: [9., 4.].into(),
+                want_inside: vec![
+                    [[0., 4.].into(), [2., 4.].into()],
+                    [[6., 4.].into(), [8., 4.].into()],
+                ],
+                want_crossings: vec![
+                    [0., 4.].into(),
+                    [2., 4.].into(),
+                    [6., 4.].into(),
+                    [8., 4.].into(),
+                ],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let segment = Segment::new(&test.from, &test.to);
+            let got = test.shape.intersect_segment(&segment, &Tolerance::default());
+
+            assert_eq!(got.inside, test.want_inside, "{}", test.name);
+            assert_eq!(got.crossings, test.want_crossings, "{}", test.name);
+        });
+    }
+}
@@ -1,7 +1,25 @@
 use std::ops::{Mul, Sub};
 
+use num_traits::{Float, Signed};
+
+use crate::{IsClose, Tolerance};
+
 use super::{Point, Segment};
 
+/// Returns true if, and only if, the three given points are collinear within the given
+/// tolerance.
+///
+/// This evaluates the very same determinant the clipper consults when classifying crossings,
+/// so decisions taken by user code at boundaries agree with those taken during clipping.
+pub fn collinear<T>(a: &Point<T>, b: &Point<T>, c: &Point<T>, tolerance: &Tolerance<T>) -> bool
+where
+    T: Signed + Float,
+{
+    Determinant::from([a, b, c])
+        .into_inner()
+        .is_close(&T::zero(), tolerance)
+}
+
 /// The scalar value representing the determinant of a matrix.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub(crate) struct Determinant<T>(T);
@@ -37,7 +55,49 @@ impl<T> Determinant<T> {
 
 #[cfg(test)]
 mod tests {
-    use crate::cartesian::{determinant::Determinant, point::Point};
+    use crate::{
+        cartesian::{determinant::Determinant, point::Point},
+        Tolerance,
+    };
+
+    use super::collinear;
+
+    #[test]
+    fn collinearity_of_points() {
+        struct Test {
+            name: &'static str,
+            points: [Point<f64>; 3],
+            want: bool,
+        }
+
+        vec![
+            Test {
+                name: "exactly collinear points",
+                points: [[0., 0.].into(), [2., 2.].into(), [4., 4.].into()],
+                want: true,
+            },
+            Test {
+                name: "points off the line beyond the tolerance",
+                points: [[0., 0.].into(), [2., 2.1].into(), [4., 4.].into()],
+                want: false,
+            },
+            Test {
+                name: "points off the line within the tolerance",
+                points: [[0., 0.].into(), [2., 2. + 1e-12].into(), [4., 4.].into()],
+                want: true,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let tolerance = Tolerance {
+                relative: 1e-09.into(),
+                absolute: 1e-09.into(),
+            };
+
+            let [a, b, c] = test.points;
+            assert_eq!(collinear(&a, &b, &c, &tolerance), test.want, "{}", test.name);
+        });
+    }
 
     #[test]
     fn determinant_of_vectors() {
@@ -0,0 +1,226 @@
+//! Edge-bucket grid accelerating repeated winding queries on a single polygon.
+
+use num_traits::{Float, Signed};
+
+use crate::{
+    cartesian::{determinant::Determinant, Point, Polygon, Segment},
+    Edge, Geometry, Shape, Tolerance,
+};
+
+/// A per-polygon index bucketing edges by rows of the plane.
+///
+/// Each row holds the edges whose vertical span overlaps it, so a winding query only visits the
+/// edges that may cross its horizontal ray instead of scanning the whole polygon. The crossing
+/// rule is the same as [`Polygon::winding`], although boundary detection may miss points whose
+/// tolerance exceeds the height of a row.
+///
+/// [`Polygon::winding`]: crate::Geometry::winding
+#[derive(Debug, Clone)]
+pub struct EdgeGrid<T> {
+    /// The lowest y coordinate covered by the grid.
+    floor: T,
+    /// The height of each row.
+    height: T,
+    /// The edges overlapping each row.
+    rows: Vec<Vec<(Point<T>, Point<T>)>>,
+}
+
+impl<T> Polygon<T>
+where
+    T: Signed + Float,
+{
+    /// Returns a grid with the given amount of rows indexing the edges of this polygon, or none
+    /// if the polygon has no vertices or no row is requested.
+    pub fn edge_grid(&self, rows: usize) -> Option<EdgeGrid<T>> {
+        if rows == 0 || self.vertices.is_empty() {
+            return None;
+        }
+
+        let (floor, ceil) = self.vertices.iter().fold(
+            (T::infinity(), T::neg_infinity()),
+            |(floor, ceil), vertex| (floor.min(vertex.y), ceil.max(vertex.y)),
+        );
+
+        // A degenerate vertical span still deserves a single all-encompassing row.
+        let span = ceil - floor;
+        let (rows, height) = if span.is_positive() {
+            (rows, span / T::from(rows)?)
+        } else {
+            (1, T::one())
+        };
+
+        let mut grid = EdgeGrid {
+            floor,
+            height,
+            rows: vec![Vec::new(); rows],
+        };
+
+        for segment in self.edges() {
+            let (bottom, top) = if segment.from.y < segment.to.y {
+                (segment.from.y, segment.to.y)
+            } else {
+                (segment.to.y, segment.from.y)
+            };
+
+            let first = grid.row(bottom).unwrap_or(0).min(rows - 1);
+            let last = grid.row(top).unwrap_or(rows - 1);
+            for row in first..=last.min(rows - 1) {
+                grid.rows[row].push((*segment.from, *segment.to));
+            }
+        }
+
+        Some(grid)
+    }
+}
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float,
+{
+    /// Returns the amount of times this shape winds around each of the given points, in point
+    /// order.
+    ///
+    /// The edges of each boundary are indexed once in an [`EdgeGrid`] shared by every query,
+    /// making this much cheaper than one [`Self::winding`] call per point when the amount of
+    /// queries is comparable to the amount of edges. Boundaries whose grid cannot be built fall
+    /// back to the plain traversal.
+    pub fn winding_many(&self, points: &[Point<T>], tolerance: &Tolerance<T>) -> Vec<isize> {
+        let rows = points.len().max(1).isqrt().max(1);
+        let grids: Vec<_> = self
+            .boundaries
+            .iter()
+            .map(|boundary| (boundary, boundary.edge_grid(rows)))
+            .collect();
+
+        points
+            .iter()
+            .map(|point| {
+                grids
+                    .iter()
+                    .map(|(boundary, grid)| match grid {
+                        Some(grid) => grid.winding(point, tolerance),
+                        None => Geometry::winding(*boundary, point, tolerance),
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+}
+
+impl<T> EdgeGrid<T>
+where
+    T: Signed + Float,
+{
+    /// Returns the amount of times the indexed polygon winds around the given point.
+    pub fn winding(&self, point: &Point<T>, tolerance: &Tolerance<T>) -> isize {
+        let Some(row) = self.row(point.y) else {
+            return 0;
+        };
+
+        let left_of = |segment: &Segment<'_, T>| {
+            Determinant::from([segment.from, segment.to, point])
+                .into_inner()
+                .is_positive()
+        };
+
+        self.rows[row.min(self.rows.len() - 1)]
+            .iter()
+            .fold(0, |wn, (from, to)| {
+                let segment = Segment::new(from, to);
+                let forward = from.y <= point.y && to.y > point.y;
+                let backward = from.y > point.y && to.y <= point.y;
+
+                if segment.contains(point, tolerance) || forward && left_of(&segment) {
+                    wn + 1
+                } else if backward && !left_of(&segment) {
+                    wn - 1
+                } else {
+                    wn
+                }
+            })
+    }
+
+    /// Returns true if, and only if, the indexed polygon contains the given point.
+    pub fn contains(&self, point: &Point<T>, tolerance: &Tolerance<T>) -> bool {
+        self.winding(point, tolerance) != 0
+    }
+
+    /// Returns the row overlapping the given y coordinate, clamped from below, or none if the
+    /// coordinate is not comparable.
+    fn row(&self, y: T) -> Option<usize> {
+        let position = ((y - self.floor) / self.height).floor();
+        if position < T::zero() {
+            return Some(0);
+        }
+
+        position.to_usize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Geometry, Shape, Tolerance};
+
+    #[test]
+    fn edge_grid_agrees_with_winding() {
+        let polygon: Polygon<f64> = vec![
+            [0., 0.],
+            [8., 0.],
+            [8., 8.],
+            [4., 4.],
+            [0., 8.],
+        ]
+        .into();
+
+        let grid = polygon.edge_grid(4).expect("the grid must exist");
+        let tolerance = Tolerance::default();
+
+        for x in -1..=9 {
+            for y in -1..=9 {
+                let point = [x as f64, y as f64].into();
+                assert_eq!(
+                    grid.winding(&point, &tolerance),
+                    polygon.winding(&point, &tolerance),
+                    "winding must agree at ({x}, {y})",
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn winding_many_agrees_with_winding() {
+        let shape: Shape<Polygon<f64>> = Shape {
+            boundaries: vec![
+                vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                vec![[2., 2.], [2., 6.], [6., 6.], [6., 2.]].into(),
+            ],
+        };
+
+        let points: Vec<_> = (-1..=9)
+            .flat_map(|x| (-1..=9).map(move |y| [x as f64, y as f64].into()))
+            .collect();
+
+        let tolerance = Tolerance::default();
+        let got = shape.winding_many(&points, &tolerance);
+
+        assert_eq!(got.len(), points.len());
+        for (point, winding) in points.iter().zip(got) {
+            assert_eq!(
+                winding,
+                shape.winding(point, &tolerance),
+                "winding must agree at ({}, {})",
+                point.x,
+                point.y,
+            );
+        }
+    }
+
+    #[test]
+    fn edge_grid_requires_rows_and_vertices() {
+        let polygon: Polygon<f64> = vec![[0., 0.], [8., 0.], [4., 4.]].into();
+        assert!(polygon.edge_grid(0).is_none(), "no rows must yield no grid");
+
+        let empty = Polygon::<f64> { vertices: Vec::new() };
+        assert!(empty.edge_grid(4).is_none(), "no vertices must yield no grid");
+    }
+}
@@ -0,0 +1,149 @@
+//! Hatch pattern generation for cartesian shapes.
+
+use num_traits::{Float, Signed};
+
+use crate::{
+    cartesian::{Point, Polygon, Segment},
+    Edge, Shape, Tolerance,
+};
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float,
+{
+    /// Returns the hatch lines filling this shape, clipped to its interior.
+    ///
+    /// The lines run along the given angle, measured counterclockwise from the x axis, and are
+    /// evenly separated by the given spacing, starting half a spacing away from the edge of the
+    /// shape. Holes interrupt the lines, making the output directly usable as a plotter or CNC
+    /// toolpath. A non-positive spacing or an empty shape yields no lines.
+    pub fn hatch(&self, angle: T, spacing: T, tolerance: &Tolerance<T>) -> Vec<[Point<T>; 2]> {
+        if !spacing.is_positive() {
+            return Vec::new();
+        }
+
+        let direction = Point {
+            x: angle.cos(),
+            y: angle.sin(),
+        };
+
+        let normal = Point {
+            x: -angle.sin(),
+            y: angle.cos(),
+        };
+
+        let (min_d, max_d, min_n, max_n) = self
+            .boundaries
+            .iter()
+            .flat_map(|boundary| boundary.vertices.iter())
+            .fold(
+                (
+                    T::infinity(),
+                    T::neg_infinity(),
+                    T::infinity(),
+                    T::neg_infinity(),
+                ),
+                |(min_d, max_d, min_n, max_n), point| {
+                    let d = point.x * direction.x + point.y * direction.y;
+                    let n = point.x * normal.x + point.y * normal.y;
+
+                    (min_d.min(d), max_d.max(d), min_n.min(n), max_n.max(n))
+                },
+            );
+
+        if min_n > max_n {
+            return Vec::new();
+        }
+
+        let half = spacing / (T::one() + T::one());
+        let mut hatches = Vec::new();
+        let mut offset = min_n + half;
+        while offset < max_n {
+            // Overshooting the extent keeps the endpoints clear of the boundaries.
+            let from = direction * (min_d - spacing) + normal * offset;
+            let to = direction * (max_d + spacing) + normal * offset;
+
+            let segment = Segment::new(&from, &to);
+            hatches.extend(self.intersect_segment(&segment, tolerance).inside);
+
+            offset = offset + spacing;
+        }
+
+        hatches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use crate::{cartesian::Polygon, Shape, Tolerance};
+
+    #[test]
+    fn hatching_fills_the_interior() {
+        struct Test {
+            name: &'static str,
+            shape: Shape<Polygon<f64>>,
+            angle: f64,
+            spacing: f64,
+            want: usize,
+        }
+
+        vec![
+            Test {
+                name: "horizontal hatching of a square",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                angle: 0.,
+                spacing: 1.,
+                want: 4,
+            },
+            Test {
+                name: "vertical hatching of a square",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                angle: FRAC_PI_2,
+                spacing: 1.,
+                want: 4,
+            },
+            Test {
+                name: "holes interrupt the lines",
+                shape: Shape {
+                    boundaries: vec![
+                        vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                        vec![[2., 2.], [2., 6.], [6., 6.], [6., 2.]].into(),
+                    ],
+                },
+                angle: 0.,
+                spacing: 2.,
+                want: 6,
+            },
+            Test {
+                name: "non-positive spacing yields no lines",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                angle: 0.,
+                spacing: 0.,
+                want: 0,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = test
+                .shape
+                .hatch(test.angle, test.spacing, &Tolerance::default());
+
+            assert_eq!(got.len(), test.want, "{}", test.name);
+
+            let tolerance = Tolerance {
+                relative: 1e-9.into(),
+                absolute: 1e-9.into(),
+            };
+
+            assert!(
+                got.iter()
+                    .flatten()
+                    .all(|point| test.shape.winding(point, &tolerance) != 0),
+                "{}: every endpoint must lie in the shape",
+                test.name
+            );
+        });
+    }
+}
@@ -0,0 +1,216 @@
+//! Convex hull based metrics for cartesian shapes.
+
+use std::cmp::Ordering;
+
+use num_traits::{Float, Signed};
+
+use crate::{
+    cartesian::{Point, Polygon},
+    Shape, Vertex,
+};
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float,
+{
+    /// Returns the smallest-area rectangle enclosing this shape, or none if the shape does not
+    /// span two dimensions.
+    ///
+    /// The rectangle is found through rotating calipers on the convex hull of the shape: the
+    /// minimum-area enclosing rectangle always has one side collinear with a hull edge.
+    pub fn min_bounding_rectangle(&self) -> Option<Polygon<T>> {
+        let hull = self.convex_hull();
+        if hull.len() < 3 {
+            return None;
+        }
+
+        let mut best: Option<(T, Polygon<T>)> = None;
+        for (index, &from) in hull.iter().enumerate() {
+            let to = hull[(index + 1) % hull.len()];
+            let length = from.distance(&to);
+            if length.is_zero() {
+                continue;
+            }
+
+            let edge = to - from;
+            let direction = Point {
+                x: edge.x / length,
+                y: edge.y / length,
+            };
+            let normal = Point {
+                x: -direction.y,
+                y: direction.x,
+            };
+
+            let (min_d, max_d, min_n, max_n) = extents(&hull, &from, &direction, &normal);
+            let area = (max_d - min_d) * (max_n - min_n);
+            if best.as_ref().is_none_or(|(smallest, _)| area < *smallest) {
+                let corner = |d: T, n: T| from + direction * d + normal * n;
+                let rectangle = vec![
+                    corner(min_d, min_n),
+                    corner(max_d, min_n),
+                    corner(max_d, max_n),
+                    corner(min_d, max_n),
+                ]
+                .into();
+
+                best = Some((area, rectangle));
+            }
+        }
+
+        best.map(|(_, rectangle)| rectangle)
+    }
+
+    /// Returns the width of the narrowest band of parallel lines enclosing this shape, or none
+    /// if the shape does not span two dimensions.
+    pub fn min_width(&self) -> Option<T> {
+        let hull = self.convex_hull();
+        if hull.len() < 3 {
+            return None;
+        }
+
+        let mut width: Option<T> = None;
+        for (index, &from) in hull.iter().enumerate() {
+            let to = hull[(index + 1) % hull.len()];
+            let length = from.distance(&to);
+            if length.is_zero() {
+                continue;
+            }
+
+            let edge = to - from;
+            let direction = Point {
+                x: edge.x / length,
+                y: edge.y / length,
+            };
+            let normal = Point {
+                x: -direction.y,
+                y: direction.x,
+            };
+
+            let (_, _, min_n, max_n) = extents(&hull, &from, &direction, &normal);
+            let candidate = max_n - min_n;
+            if width.is_none_or(|narrowest| candidate < narrowest) {
+                width = Some(candidate);
+            }
+        }
+
+        width
+    }
+
+    /// Returns the counterclockwise convex hull of all the vertices in this shape.
+    fn convex_hull(&self) -> Vec<Point<T>> {
+        let mut points: Vec<Point<T>> = self
+            .boundaries
+            .iter()
+            .flat_map(|boundary| boundary.vertices.iter().copied())
+            .collect();
+
+        points.sort_by(|a, b| {
+            a.x.partial_cmp(&b.x)
+                .unwrap_or(Ordering::Equal)
+                .then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+        });
+        points.dedup();
+
+        if points.len() < 3 {
+            return points;
+        }
+
+        let cross = |origin: &Point<T>, a: &Point<T>, b: &Point<T>| {
+            (a.x - origin.x) * (b.y - origin.y) - (a.y - origin.y) * (b.x - origin.x)
+        };
+
+        let chain = |points: &mut dyn Iterator<Item = Point<T>>| {
+            let mut hull: Vec<Point<T>> = Vec::new();
+            for point in points {
+                while hull.len() >= 2
+                    && !cross(&hull[hull.len() - 2], &hull[hull.len() - 1], &point).is_positive()
+                {
+                    hull.pop();
+                }
+
+                hull.push(point);
+            }
+
+            hull.pop();
+            hull
+        };
+
+        let mut lower = chain(&mut points.iter().copied());
+        let upper = chain(&mut points.iter().rev().copied());
+
+        lower.extend(upper);
+        lower
+    }
+}
+
+/// Returns the extents of the given points along the given direction and normal, relative to the
+/// given origin.
+fn extents<T>(
+    points: &[Point<T>],
+    origin: &Point<T>,
+    direction: &Point<T>,
+    normal: &Point<T>,
+) -> (T, T, T, T)
+where
+    T: Signed + Float,
+{
+    points.iter().fold(
+        (
+            T::infinity(),
+            T::neg_infinity(),
+            T::infinity(),
+            T::neg_infinity(),
+        ),
+        |(min_d, max_d, min_n, max_n), &point| {
+            let relative = point - *origin;
+            let d = relative.x * direction.x + relative.y * direction.y;
+            let n = relative.x * normal.x + relative.y * normal.y;
+
+            (min_d.min(d), max_d.max(d), min_n.min(n), max_n.max(n))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Shape};
+
+    #[test]
+    fn minimum_bounding_rectangle() {
+        let shape: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+
+        let want: Polygon<f64> = vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]].into();
+        assert_eq!(shape.min_bounding_rectangle(), Some(want));
+
+        let degenerate: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [2., 2.], [4., 4.]]);
+        assert_eq!(degenerate.min_bounding_rectangle(), None);
+    }
+
+    #[test]
+    fn minimum_width() {
+        struct Test {
+            name: &'static str,
+            shape: Shape<Polygon<f64>>,
+            want: f64,
+        }
+
+        vec![
+            Test {
+                name: "axis-aligned square",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                want: 4.,
+            },
+            Test {
+                name: "rotated square",
+                shape: Shape::new(vec![[2., 0.], [4., 2.], [2., 4.], [0., 2.]]),
+                want: 8f64.sqrt(),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = test.shape.min_width().expect(test.name);
+            assert!((got - test.want).abs() < 1e-9, "{}", test.name);
+        });
+    }
+}
@@ -0,0 +1,162 @@
+//! Slab decomposition for batch point-in-shape queries.
+
+use std::cmp::Ordering;
+
+use num_traits::{Float, Signed};
+
+use crate::{
+    cartesian::{Point, Polygon},
+    Geometry, Shape,
+};
+
+/// A prebuilt structure answering point-in-shape queries in logarithmic time.
+///
+/// The plane is partitioned into vertical slabs delimited by the x coordinates of the shape's
+/// vertices, and each slab records the edges spanning it. A query binary-searches its slab and
+/// accumulates the winding of the edges below the point, so repeated queries amortize the cost
+/// of walking every edge of the shape.
+///
+/// Queries are performed in exact arithmetic: points lying on the boundary follow the same
+/// half-open crossing rule as [`Polygon::winding_with_ray`], with no tolerance involved.
+#[derive(Debug, Clone)]
+pub struct PointLocator<T> {
+    /// The sorted x coordinates delimiting the slabs.
+    bounds: Vec<T>,
+    /// The edges spanning each slab, one entry per consecutive pair of bounds.
+    slabs: Vec<Vec<(Point<T>, Point<T>)>>,
+}
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float,
+{
+    /// Returns a prebuilt locator answering containment queries against this shape.
+    pub fn point_locator(&self) -> PointLocator<T> {
+        let mut bounds: Vec<T> = self
+            .boundaries
+            .iter()
+            .flat_map(|boundary| boundary.vertices())
+            .map(|vertex| vertex.x)
+            .collect();
+
+        bounds.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        bounds.dedup();
+
+        let mut slabs = vec![Vec::new(); bounds.len().saturating_sub(1)];
+        for boundary in &self.boundaries {
+            for segment in boundary.edges() {
+                let (from, to) = (*segment.from, *segment.to);
+                if from.x == to.x {
+                    continue;
+                }
+
+                let (left, right) = if from.x < to.x {
+                    (from.x, to.x)
+                } else {
+                    (to.x, from.x)
+                };
+
+                let start = bounds.partition_point(|&bound| bound < left);
+                for slab in start..slabs.len() {
+                    if bounds[slab + 1] > right {
+                        break;
+                    }
+
+                    slabs[slab].push((from, to));
+                }
+            }
+        }
+
+        PointLocator { bounds, slabs }
+    }
+}
+
+impl<T> PointLocator<T>
+where
+    T: Signed + Float,
+{
+    /// Returns the amount of times the underlying shape winds around the given point.
+    pub fn winding(&self, point: &Point<T>) -> isize {
+        let slab = self.bounds.partition_point(|&bound| bound <= point.x);
+        let Some(edges) = slab.checked_sub(1).and_then(|slab| self.slabs.get(slab)) else {
+            return 0;
+        };
+
+        edges
+            .iter()
+            .map(|(from, to)| {
+                let height = from.y + (to.y - from.y) * (point.x - from.x) / (to.x - from.x);
+                match (height < point.y, from.x < to.x) {
+                    (true, true) => 1,
+                    (true, false) => -1,
+                    (false, _) => 0,
+                }
+            })
+            .sum()
+    }
+
+    /// Returns true if, and only if, the underlying shape contains the given point.
+    pub fn contains(&self, point: &Point<T>) -> bool {
+        self.winding(point) != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Shape};
+
+    #[test]
+    fn point_locator_agrees_with_winding() {
+        struct Test {
+            name: &'static str,
+            shape: Shape<Polygon<f64>>,
+            point: [f64; 2],
+            want: bool,
+        }
+
+        let with_hole = || Shape {
+            boundaries: vec![
+                vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                vec![[2., 2.], [2., 6.], [6., 6.], [6., 2.]].into(),
+            ],
+        };
+
+        vec![
+            Test {
+                name: "point inside the shape",
+                shape: with_hole(),
+                point: [1., 1.],
+                want: true,
+            },
+            Test {
+                name: "point inside the hole",
+                shape: with_hole(),
+                point: [4., 4.],
+                want: false,
+            },
+            Test {
+                name: "point outside the shape",
+                shape: with_hole(),
+                point: [9., 4.],
+                want: false,
+            },
+            Test {
+                name: "point aligned with a vertex",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [2., 4.]]),
+                point: [2., 1.],
+                want: true,
+            },
+            Test {
+                name: "point left of every slab",
+                shape: with_hole(),
+                point: [-1., 4.],
+                want: false,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let locator = test.shape.point_locator();
+            assert_eq!(locator.contains(&test.point.into()), test.want, "{}", test.name);
+        });
+    }
+}
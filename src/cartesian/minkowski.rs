@@ -0,0 +1,321 @@
+//! Minkowski sum and difference of cartesian shapes.
+
+use std::cmp::Ordering;
+
+use num_traits::{Float, Signed};
+
+use crate::{
+    cartesian::{offset::split_at_crossings, Point, Polygon},
+    Geometry, IsClose, RightHanded, Shape, Tolerance,
+};
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float + IsClose<Tolerance = Tolerance<T>> + 'static,
+{
+    /// Returns the Minkowski sum of this shape and the given structuring polygon, or none if
+    /// either operand degenerates.
+    ///
+    /// The sum contains every translation of the structuring polygon anchored at a point of this
+    /// shape, dilating filled regions and shrinking holes alike. The structuring polygon is
+    /// expected to be convex and counterclockwise; a concave one dilates as its convex hull.
+    pub fn minkowski_sum(&self, structuring: &Polygon<T>, tolerance: Tolerance<T>) -> Option<Self> {
+        if structuring.vertices.is_empty() {
+            return None;
+        }
+
+        let boundaries = self
+            .boundaries
+            .iter()
+            .filter_map(|boundary| {
+                Some((boundary.is_clockwise(), convolution(boundary, structuring, &tolerance)?))
+            })
+            .flat_map(|(clockwise, convolution)| {
+                // As with erosion, reflex vertices fold the convolution over itself: splitting
+                // it at its self-crossings and keeping the lobes preserving the original
+                // winding recovers the boundary of the swept region.
+                split_at_crossings(convolution, &tolerance)
+                    .into_iter()
+                    .filter(move |lobe| lobe.is_clockwise() == clockwise)
+            })
+            .collect::<Vec<_>>();
+
+        (!boundaries.is_empty()).then_some(Shape { boundaries })
+    }
+
+    /// Returns the Minkowski difference of this shape by the given structuring polygon, if
+    /// anything remains of it.
+    ///
+    /// The difference keeps the anchors at which the structuring polygon, read as displacements
+    /// from the origin of its own coordinates, fits entirely inside this shape, eroding filled
+    /// regions and growing holes alike. It is computed through the complement identity: eroding
+    /// by the structuring polygon equals removing from this shape the dilation of its complement
+    /// by the structuring polygon reflected through the origin.
+    pub fn minkowski_difference(
+        &self,
+        structuring: &Polygon<T>,
+        tolerance: Tolerance<T>,
+    ) -> Option<Self> {
+        let stats = self.stats()?;
+
+        // The frame standing in for the unbounded complement must exceed the reach of the
+        // structuring polygon, so its own border never erodes the interior of this shape.
+        let reach = structuring.vertices.iter().fold(T::zero(), |reach, vertex| {
+            reach.max(vertex.x.abs()).max(vertex.y.abs())
+        });
+        let pad = reach + T::one();
+
+        let min = stats.min - Point { x: pad, y: pad };
+        let max = stats.max + Point { x: pad, y: pad };
+        let frame = Shape::new(vec![
+            min,
+            Point { x: max.x, y: min.y },
+            max,
+            Point { x: min.x, y: max.y },
+        ]);
+
+        let reflected = structuring.clone().map(|vertex| Point {
+            x: -vertex.x,
+            y: -vertex.y,
+        });
+
+        let complement = frame.not_ref(self, tolerance)?;
+        let dilated = complement.minkowski_sum(&reflected, tolerance)?;
+
+        self.not_ref(&dilated, tolerance)
+    }
+}
+
+/// Returns the convolution of the given ring with the structuring polygon, or none if the ring
+/// degenerates below three vertices.
+///
+/// Each edge of the ring is displaced by the structuring vertex furthest along its right-hand
+/// normal, and consecutive displaced edges are joined by the walk of structuring vertices
+/// between their supports, anchored at the shared ring vertex.
+fn convolution<T>(
+    ring: &Polygon<T>,
+    structuring: &Polygon<T>,
+    tolerance: &Tolerance<T>,
+) -> Option<Polygon<T>>
+where
+    T: Signed + Float + IsClose<Tolerance = Tolerance<T>>,
+{
+    let ring = ring.clone().deduped(tolerance);
+    let len = ring.vertices.len();
+    if len < 3 {
+        return None;
+    }
+
+    let directions = (0..len)
+        .map(|position| ring.vertices[(position + 1) % len] - ring.vertices[position])
+        .collect::<Vec<_>>();
+
+    let supports = directions
+        .iter()
+        .map(|&direction| {
+            // Ties along the normal are broken towards the edge direction, so the displaced
+            // edge ends where the joining walk at its head vertex begins.
+            let normal = Point {
+                x: direction.y,
+                y: -direction.x,
+            };
+            let key = |vertex: &Point<T>| {
+                (
+                    vertex.x * normal.x + vertex.y * normal.y,
+                    vertex.x * direction.x + vertex.y * direction.y,
+                )
+            };
+
+            structuring
+                .vertices
+                .iter()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| key(a).partial_cmp(&key(b)).unwrap_or(Ordering::Equal))
+                .map(|(position, _)| position)
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    let total = structuring.vertices.len();
+    let mut vertices = Vec::new();
+    for position in 0..len {
+        let previous = (position + len - 1) % len;
+        let turn = directions[previous].x * directions[position].y
+            - directions[previous].y * directions[position].x;
+
+        // The support rotates with the edge normal: counterclockwise at convex vertices and
+        // clockwise at reflex ones.
+        let mut support = supports[previous];
+        vertices.push(ring.vertices[position] + structuring.vertices[support]);
+        while support != supports[position] {
+            support = if turn.is_negative() {
+                (support + total - 1) % total
+            } else {
+                (support + 1) % total
+            };
+
+            vertices.push(ring.vertices[position] + structuring.vertices[support]);
+        }
+    }
+
+    // Inverted joining walks leave zero-width spikes behind, which carry no area yet defeat the
+    // crossing-based split below.
+    loop {
+        let len = vertices.len();
+        if len < 3 {
+            return None;
+        }
+
+        let Some(position) = (0..len).position(|position| {
+            let incoming = vertices[position] - vertices[(position + len - 1) % len];
+            let outgoing = vertices[(position + 1) % len] - vertices[position];
+
+            (incoming.x * outgoing.y - incoming.y * outgoing.x).is_close(&T::zero(), tolerance)
+                && (incoming.x * outgoing.x + incoming.y * outgoing.y).is_negative()
+        }) else {
+            break;
+        };
+
+        vertices.remove(position);
+    }
+
+    Some(Polygon { vertices })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cartesian::{Point, Polygon},
+        Shape, Tolerance,
+    };
+
+    #[test]
+    fn minkowski_sum_dilates_the_shape() {
+        struct Test {
+            name: &'static str,
+            shape: Shape<Polygon<f64>>,
+            structuring: Polygon<f64>,
+            want_area: f64,
+            inside: Vec<Point<f64>>,
+            outside: Vec<Point<f64>>,
+        }
+
+        vec![
+            Test {
+                name: "square dilated by a centered unit square",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                structuring: vec![[-1., -1.], [1., -1.], [1., 1.], [-1., 1.]].into(),
+                want_area: 36.,
+                inside: vec![[-0.5, -0.5].into(), [4.5, 4.5].into()],
+                outside: vec![[-1.5, 0.].into(), [5.5, 5.5].into()],
+            },
+            Test {
+                name: "dilation shrinks holes",
+                shape: Shape {
+                    boundaries: vec![
+                        vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                        vec![[2., 2.], [2., 6.], [6., 6.], [6., 2.]].into(),
+                    ],
+                },
+                structuring: vec![[-1., -1.], [1., -1.], [1., 1.], [-1., 1.]].into(),
+                want_area: 96.,
+                inside: vec![[2.5, 4.].into(), [5.5, 4.].into()],
+                outside: vec![[4., 4.].into()],
+            },
+            Test {
+                name: "reflex vertex folds the convolution over itself",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 2.], [2., 2.], [2., 4.], [0., 4.]]),
+                structuring: vec![[-1., -1.], [1., -1.], [1., 1.], [-1., 1.]].into(),
+                want_area: 32.,
+                inside: vec![[2.5, 2.5].into(), [4.5, 2.5].into(), [-0.5, -0.5].into()],
+                outside: vec![[4.5, 3.5].into(), [5.5, 0.].into()],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let tolerance = Tolerance::default();
+            let got = test
+                .shape
+                .minkowski_sum(&test.structuring, tolerance)
+                .expect("the dilation of a non-empty shape must not be empty");
+
+            let area = got.stats().expect("the dilation must have vertices").area;
+            assert!(
+                (area - test.want_area).abs() < 1e-9,
+                "{}: got area {}, want {}",
+                test.name,
+                area,
+                test.want_area
+            );
+
+            test.inside.into_iter().for_each(|point| {
+                assert_ne!(
+                    got.winding(&point, &tolerance),
+                    0,
+                    "{}: point {point:?} must be inside",
+                    test.name
+                );
+            });
+
+            test.outside.into_iter().for_each(|point| {
+                assert_eq!(
+                    got.winding(&point, &tolerance),
+                    0,
+                    "{}: point {point:?} must be outside",
+                    test.name
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn minkowski_difference_erodes_the_shape() {
+        struct Test {
+            name: &'static str,
+            shape: Shape<Polygon<f64>>,
+            structuring: Polygon<f64>,
+            want: Option<f64>,
+        }
+
+        vec![
+            Test {
+                name: "square eroded by a centered unit square",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                structuring: vec![[-1., -1.], [1., -1.], [1., 1.], [-1., 1.]].into(),
+                want: Some(4.),
+            },
+            Test {
+                name: "erosion consuming the whole shape",
+                shape: Shape::new(vec![[0., 0.], [2., 0.], [2., 2.], [0., 2.]]),
+                structuring: vec![[-2., -2.], [2., -2.], [2., 2.], [-2., 2.]].into(),
+                want: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = test
+                .shape
+                .minkowski_difference(&test.structuring, Tolerance::default());
+
+            match (got, test.want) {
+                (Some(got), Some(want_area)) => {
+                    let area = got.stats().expect("the erosion must have vertices").area;
+                    assert!(
+                        (area - want_area).abs() < 1e-9,
+                        "{}: got area {}, want {}",
+                        test.name,
+                        area,
+                        want_area
+                    );
+                }
+                (None, None) => (),
+                (got, want) => panic!(
+                    "{}: got {:?}, want an erosion of area {:?}",
+                    test.name,
+                    got.map(|shape| shape.stats().map(|stats| stats.area)),
+                    want
+                ),
+            }
+        });
+    }
+}
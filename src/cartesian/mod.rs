@@ -1,15 +1,58 @@
+mod align;
+mod bezier;
+mod bias;
+mod change;
+mod convert;
+mod curve;
+mod cut;
 mod determinant;
+mod grid;
+mod hatch;
+mod hull;
+mod locator;
+mod minkowski;
+mod monotone;
+mod offset;
+mod pinch;
 mod point;
+mod pool;
 mod polygon;
+mod quality;
+mod raster;
+mod rings;
+mod sector;
 mod segment;
+mod simplify;
+mod stats;
+mod stitch;
+mod sweep;
+mod tile;
+mod transform;
 
+pub use self::align::Alignment;
+pub use self::bezier::{BezierRing, BezierSegment};
+pub use self::change::ChangeReport;
+pub use self::curve::{CurvedPolygon, CurvedVertex};
+pub use self::cut::SegmentIntersection;
+pub use self::determinant::collinear;
+pub use self::grid::EdgeGrid;
+pub use self::locator::PointLocator;
 pub use self::point::Point;
-pub use self::polygon::Polygon;
+pub use self::polygon::{Polygon, RayDirection};
+pub use self::pool::{PooledShape, VertexPool};
+pub use self::quality::BoundaryQuality;
+pub use self::raster::WindingGrid;
 pub use self::segment::Segment;
+pub use self::simplify::simplify_collection;
+pub use self::stats::Stats;
 
 #[cfg(test)]
 mod tests {
-    use crate::{cartesian::Polygon, Shape};
+    use crate::{
+        cartesian::{Point, Polygon},
+        ClipOptions, Direction, Geometry, IntersectionKind, IsClose, Location, Node,
+        Operands, Operator, Orientation, Shape, Touch,
+    };
 
     #[test]
     fn union() {
@@ -60,21 +103,21 @@ mod tests {
                     [0., 4.],
                 ])),
             },
-            // Test {
-            //     name: "horizontal overlapping squares",
-            //     subject: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
-            //     clip: Shape::new(vec![[2., 0.], [6., 0.], [6., 4.], [2., 4.]]),
-            //     want: Some(Shape::new(vec![
-            //         [0., 0.],
-            //         [2., 0.],
-            //         [4., 0.],
-            //         [6., 0.],
-            //         [6., 4.],
-            //         [4., 4.],
-            //         [2., 4.],
-            //         [0., 4.],
-            //     ]),
-            // },
+            Test {
+                name: "horizontal overlapping squares",
+                subject: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                clip: Shape::new(vec![[2., 0.], [6., 0.], [6., 4.], [2., 4.]]),
+                want: Some(Shape::new(vec![
+                    [0., 0.],
+                    [2., 0.],
+                    [4., 0.],
+                    [6., 0.],
+                    [6., 4.],
+                    [4., 4.],
+                    [2., 4.],
+                    [0., 4.],
+                ])),
+            },
             Test {
                 name: "diagonal overlapping squares",
                 subject: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
@@ -285,17 +328,12 @@ mod tests {
                 clip: Shape::new(vec![[2., 4.], [6., 4.], [6., 8.], [2., 8.]]),
                 want: Some(Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [2., 4.], [0., 4.]])),
             },
-            // Test {
-            //     name: "horizontal overlapping squares",
-            //     subject: Shape::new(vec!([0., 0.], [4., 0.], [4., 4.], [0., 4.])),
-            //     clip: Shape::new(vec!([2., 0.], [6., 0.], [6., 4.], [2., 4.])),
-            //     want: Some(Shape::new(vec!(
-            //         [0., 0.],
-            //         [2., 0.],
-            //         [2., 4.],
-            //         [0., 4.]
-            //     ))),
-            // },
+            Test {
+                name: "horizontal overlapping squares",
+                subject: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                clip: Shape::new(vec![[2., 0.], [6., 0.], [6., 4.], [2., 4.]]),
+                want: Some(Shape::new(vec![[0., 0.], [2., 0.], [2., 4.], [0., 4.]])),
+            },
             Test {
                 name: "diagonal overlapping squares",
                 subject: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
@@ -450,12 +488,12 @@ mod tests {
                 clip: Shape::new(vec![[2., 4.], [6., 4.], [6., 8.], [2., 8.]]),
                 want: Some(Shape::new(vec![[4., 4.], [2., 4.]])),
             },
-            // Test {
-            //     name: "horizontal overlapping squares",
-            //     subject: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
-            //     clip: Shape::new(vec![[2., 0.], [6., 0.], [6., 4.], [2., 4.]]),
-            //     want: Some(Shape::new(vec![[2., 0.], [4., 0.], [4., 4.], [2., 4.]])),
-            // },
+            Test {
+                name: "horizontal overlapping squares",
+                subject: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                clip: Shape::new(vec![[2., 0.], [6., 0.], [6., 4.], [2., 4.]]),
+                want: Some(Shape::new(vec![[2., 0.], [4., 0.], [4., 4.], [2., 4.]])),
+            },
             Test {
                 name: "diagonal overlapping squares",
                 subject: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
@@ -539,31 +577,31 @@ mod tests {
                     [3., 4.],
                 ])),
             },
-            // Test {
-            //     name: "clipping clockwise side from self-crossing subject",
-            //     subject: Shape::new(vec!(
-            //         [-1., 0.],
-            //         [-1., -1.],
-            //         [1., -1.],
-            //         [1., 0.],
-            //         [-1., 0.],
-            //         [-1., 1.],
-            //         [1., 1.],
-            //         [1., 0.]
-            //     )),
-            //     clip: Shape::new(vec!(
-            //         [0.75, 0.75],
-            //         [0.75, 1.25],
-            //         [-0.75, 1.25],
-            //         [-0.75, 0.75]
-            //     )),
-            //     want: Some(Shape::new(vec![
-            //         [-0.75, 0.75],
-            //         [0.75, 0.75],
-            //         [0.75, 1.],
-            //         [-0.75, 1.],
-            //     ])),
-            // },
+            Test {
+                name: "clipping clockwise side from self-crossing subject",
+                subject: Shape::new(vec![
+                    [-1., 0.],
+                    [-1., -1.],
+                    [1., -1.],
+                    [1., 0.],
+                    [-1., 0.],
+                    [-1., 1.],
+                    [1., 1.],
+                    [1., 0.],
+                ]),
+                clip: Shape::new(vec![
+                    [0.75, 0.75],
+                    [0.75, 1.25],
+                    [-0.75, 1.25],
+                    [-0.75, 0.75],
+                ]),
+                want: Some(Shape::new(vec![
+                    [-0.75, 0.75],
+                    [0.75, 0.75],
+                    [0.75, 1.],
+                    [-0.75, 1.],
+                ])),
+            },
         ]
         .into_iter()
         .for_each(|test| {
@@ -571,4 +609,579 @@ mod tests {
             assert_eq!(got, test.want, "{}", test.name);
         });
     }
+
+    #[test]
+    fn symmetric_difference() {
+        struct Test {
+            name: &'static str,
+            subject: Shape<Polygon<f64>>,
+            clip: Shape<Polygon<f64>>,
+            want: Option<Shape<Polygon<f64>>>,
+        }
+
+        vec![
+            Test {
+                name: "same geometry",
+                subject: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                clip: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                want: None,
+            },
+            Test {
+                name: "horizontally aligned squares",
+                subject: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                clip: Shape::new(vec![[4., 0.], [8., 0.], [8., 4.], [4., 4.]]),
+                want: Some(Shape {
+                    boundaries: vec![
+                        vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]].into(),
+                        vec![[4., 0.], [8., 0.], [8., 4.], [4., 4.]].into(),
+                    ],
+                }),
+            },
+            Test {
+                name: "diagonal overlapping squares",
+                subject: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                clip: Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]),
+                want: Some(Shape {
+                    boundaries: vec![
+                        vec![[0., 0.], [4., 0.], [4., 2.], [2., 2.], [2., 4.], [0., 4.]].into(),
+                        vec![[4., 2.], [6., 2.], [6., 6.], [2., 6.], [2., 4.], [4., 4.]].into(),
+                    ],
+                }),
+            },
+            Test {
+                name: "subject enclosing clip",
+                subject: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                clip: Shape::new(vec![[1., 1.], [3., 1.], [3., 3.], [1., 3.]]),
+                want: Some(Shape {
+                    boundaries: vec![
+                        vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]].into(),
+                        vec![[1., 3.], [3., 3.], [3., 1.], [1., 1.]].into(),
+                    ],
+                }),
+            },
+            Test {
+                name: "subject with hole enclosing clip",
+                subject: Shape {
+                    boundaries: vec![
+                        vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]].into(),
+                        vec![[1.5, 2.5], [2.5, 2.5], [2.5, 1.5], [1.5, 1.5]].into(),
+                    ],
+                },
+                clip: Shape::new(vec![[1., 1.], [3., 1.], [3., 3.], [1., 3.]]),
+                want: Some(Shape {
+                    boundaries: vec![
+                        vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]].into(),
+                        vec![[1., 1.], [1., 3.], [3., 3.], [3., 1.]].into(),
+                        vec![[1.5, 1.5], [2.5, 1.5], [2.5, 2.5], [1.5, 2.5]].into(),
+                    ],
+                }),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = test.subject.xor(test.clip, Default::default());
+            assert_eq!(got, test.want, "{}", test.name);
+        });
+    }
+
+    #[test]
+    fn shape_containment() {
+        struct Test {
+            name: &'static str,
+            shape: Shape<Polygon<f64>>,
+            other: Shape<Polygon<f64>>,
+            want: bool,
+        }
+
+        vec![
+            Test {
+                name: "same geometry",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                other: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                want: true,
+            },
+            Test {
+                name: "smaller shape inside",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                other: Shape::new(vec![[1., 1.], [3., 1.], [3., 3.], [1., 3.]]),
+                want: true,
+            },
+            Test {
+                name: "overlapping shapes",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                other: Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]),
+                want: false,
+            },
+            Test {
+                name: "disjoint shapes",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                other: Shape::new(vec![[6., 6.], [8., 6.], [8., 8.], [6., 8.]]),
+                want: false,
+            },
+            Test {
+                name: "shape inside a hole",
+                shape: Shape {
+                    boundaries: vec![
+                        vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                        vec![[2., 2.], [2., 6.], [6., 6.], [6., 2.]].into(),
+                    ],
+                },
+                other: Shape::new(vec![[3., 3.], [5., 3.], [5., 5.], [3., 5.]]),
+                want: false,
+            },
+            Test {
+                name: "shape covering a hole",
+                shape: Shape {
+                    boundaries: vec![
+                        vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                        vec![[2., 2.], [2., 6.], [6., 6.], [6., 2.]].into(),
+                    ],
+                },
+                other: Shape::new(vec![[1., 1.], [7., 1.], [7., 7.], [1., 7.]]),
+                want: false,
+            },
+            Test {
+                name: "shape between the hole and the outer boundary",
+                shape: Shape {
+                    boundaries: vec![
+                        vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                        vec![[2., 2.], [2., 6.], [6., 6.], [6., 2.]].into(),
+                    ],
+                },
+                other: Shape::new(vec![[0.5, 0.5], [1.5, 0.5], [1.5, 1.5], [0.5, 1.5]]),
+                want: true,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = test.shape.contains_shape(&test.other, &Default::default());
+            assert_eq!(got, test.want, "{}", test.name);
+        });
+    }
+
+    #[test]
+    fn boundary_classification_previews_the_operation() {
+        use crate::BoundaryClass;
+
+        let subject: Shape<Polygon<f64>> = Shape {
+            boundaries: vec![
+                vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]].into(),
+                vec![[4.5, 4.5], [5.5, 4.5], [5.5, 5.5], [4.5, 5.5]].into(),
+                vec![[10., 0.], [12., 0.], [12., 2.], [10., 2.]].into(),
+            ],
+        };
+
+        let clip: Shape<Polygon<f64>> = Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]);
+
+        assert_eq!(
+            subject.classify_boundaries(&clip, &Default::default()),
+            vec![
+                BoundaryClass::Crossing,
+                BoundaryClass::Inside,
+                BoundaryClass::Outside,
+            ],
+            "each boundary must be classified on its own"
+        );
+    }
+
+    #[test]
+    fn shape_intersection_predicate() {
+        struct Test {
+            name: &'static str,
+            shape: Shape<Polygon<f64>>,
+            other: Shape<Polygon<f64>>,
+            want: bool,
+        }
+
+        vec![
+            Test {
+                name: "overlapping shapes",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                other: Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]),
+                want: true,
+            },
+            Test {
+                name: "disjoint shapes",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                other: Shape::new(vec![[6., 6.], [8., 6.], [8., 8.], [6., 8.]]),
+                want: false,
+            },
+            Test {
+                name: "shape enclosing the other",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                other: Shape::new(vec![[1., 1.], [3., 1.], [3., 3.], [1., 3.]]),
+                want: true,
+            },
+            Test {
+                name: "shape enclosed by the other",
+                shape: Shape::new(vec![[1., 1.], [3., 1.], [3., 3.], [1., 3.]]),
+                other: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                want: true,
+            },
+            Test {
+                name: "shape inside a hole of the other",
+                shape: Shape::new(vec![[3., 3.], [5., 3.], [5., 5.], [3., 5.]]),
+                other: Shape {
+                    boundaries: vec![
+                        vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                        vec![[2., 2.], [2., 6.], [6., 6.], [6., 2.]].into(),
+                    ],
+                },
+                want: false,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = test.shape.intersects(&test.other, &Default::default());
+            assert_eq!(got, test.want, "{}", test.name);
+        });
+    }
+
+    #[test]
+    fn shape_orientation_and_reversal() {
+        let shape: Shape<Polygon<f64>> = Shape {
+            boundaries: vec![
+                vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                vec![[2., 2.], [2., 6.], [6., 6.], [6., 2.]].into(),
+            ],
+        };
+
+        assert_eq!(shape.orientation(0), Some(Orientation::Counterclockwise));
+        assert_eq!(shape.orientation(1), Some(Orientation::Clockwise));
+        assert_eq!(shape.orientation(2), None);
+
+        let reversed = shape.clone().reversed();
+        assert_eq!(reversed.orientation(0), Some(Orientation::Clockwise));
+        assert_eq!(reversed.orientation(1), Some(Orientation::Counterclockwise));
+
+        let fixed = shape.reverse_boundary(1);
+        assert_eq!(fixed.orientation(0), Some(Orientation::Counterclockwise));
+        assert_eq!(fixed.orientation(1), Some(Orientation::Counterclockwise));
+    }
+
+    #[test]
+    fn shape_edges_carry_boundary_indices() {
+        let shape: Shape<Polygon<f64>> = Shape {
+            boundaries: vec![
+                vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                vec![[2., 2.], [2., 6.], [6., 6.], [6., 2.]].into(),
+            ],
+        };
+
+        let got: Vec<usize> = shape.edges().map(|(boundary, _)| boundary).collect();
+        assert_eq!(got, vec![0, 0, 0, 0, 1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn vertex_location() {
+        struct Test {
+            name: &'static str,
+            shape: Shape<Polygon<f64>>,
+            vertex: Point<f64>,
+            want: Location,
+        }
+
+        vec![
+            Test {
+                name: "vertex inside the shape",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                vertex: [2., 2.].into(),
+                want: Location::Inside,
+            },
+            Test {
+                name: "vertex outside the shape",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                vertex: [6., 6.].into(),
+                want: Location::Outside,
+            },
+            Test {
+                name: "vertex on the first edge",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                vertex: [2., 0.].into(),
+                want: Location::OnBoundary {
+                    boundary: 0,
+                    edge: 0,
+                },
+            },
+            Test {
+                name: "vertex on the boundary of a hole",
+                shape: Shape {
+                    boundaries: vec![
+                        vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                        vec![[2., 2.], [2., 6.], [6., 6.], [6., 2.]].into(),
+                    ],
+                },
+                vertex: [2., 4.].into(),
+                want: Location::OnBoundary {
+                    boundary: 1,
+                    edge: 0,
+                },
+            },
+            Test {
+                name: "vertex inside a hole",
+                shape: Shape {
+                    boundaries: vec![
+                        vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                        vec![[2., 2.], [2., 6.], [6., 6.], [6., 2.]].into(),
+                    ],
+                },
+                vertex: [4., 4.].into(),
+                want: Location::Outside,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = test.shape.locate(&test.vertex, &Default::default());
+            assert_eq!(got, test.want, "{}", test.name);
+        });
+    }
+
+    #[test]
+    fn tangential_touch_reporting() {
+        struct Test {
+            name: &'static str,
+            shape: Shape<Polygon<f64>>,
+            other: Shape<Polygon<f64>>,
+            want: Vec<Touch<Point<f64>>>,
+        }
+
+        vec![
+            Test {
+                name: "corner-touching shapes",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                other: Shape::new(vec![[4., 4.], [8., 4.], [8., 8.], [4., 8.]]),
+                want: vec![Touch {
+                    vertex: [4., 4.].into(),
+                    multiplicity: 2,
+                }],
+            },
+            Test {
+                name: "overlapping shapes",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                other: Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]),
+                want: Vec::new(),
+            },
+            Test {
+                name: "disjoint shapes",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                other: Shape::new(vec![[6., 6.], [8., 6.], [8., 8.], [6., 8.]]),
+                want: Vec::new(),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = test.shape.touches(&test.other, &Default::default());
+            assert_eq!(got, test.want, "{}", test.name);
+        });
+    }
+
+    #[test]
+    fn custom_operator_must_match_builtin_union() {
+        /// An [`Operator`] reimplementing the union on top of the public API.
+        struct CustomOr;
+
+        impl<T> Operator<T> for CustomOr
+        where
+            T: Geometry,
+        {
+            fn is_output<'a>(
+                ops: Operands<'a, T>,
+                node: &'a Node<T>,
+                tolerance: &<T::Vertex as IsClose>::Tolerance,
+                _: &ClipOptions,
+            ) -> bool {
+                let oposite = if node.is_subject() {
+                    ops.clip
+                } else {
+                    ops.subject
+                };
+
+                !matches!(oposite.locate(node.vertex(), tolerance), Location::Inside)
+            }
+
+            fn direction(node: &Node<T>) -> Direction {
+                match node.intersection_kind() {
+                    Some(IntersectionKind::Entry) => Direction::Backward,
+                    Some(IntersectionKind::Exit) | None => Direction::Forward,
+                }
+            }
+        }
+
+        let subject: Shape<Polygon<f64>> =
+            Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip: Shape<Polygon<f64>> = Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]);
+
+        let got = subject.clip_with::<CustomOr>(&clip, Default::default());
+        let want = subject.or_ref(&clip, Default::default());
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn subtract_all_must_match_chained_differences() {
+        let clips: Vec<Shape<Polygon<f64>>> = vec![
+            Shape::new(vec![[1., 1.], [3., 1.], [3., 3.], [1., 3.]]),
+            Shape::new(vec![[5., 5.], [7., 5.], [7., 7.], [5., 7.]]),
+        ];
+
+        let subject: Shape<Polygon<f64>> =
+            Shape::new(vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]]);
+
+        let want = clips.iter().try_fold(subject.clone(), |subject, clip| {
+            subject.not_ref(clip, Default::default())
+        });
+
+        let got = subject.subtract_all(clips, Default::default());
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn union_in_place_must_match_union() {
+        let additions: Vec<Shape<Polygon<f64>>> = vec![
+            Shape::new(vec![[2., 0.], [6., 0.], [6., 4.], [2., 4.]]),
+            Shape::new(vec![[4., 0.], [8., 0.], [8., 4.], [4., 4.]]),
+        ];
+
+        let mut accumulator: Shape<Polygon<f64>> =
+            Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+
+        let mut want = Some(accumulator.clone());
+        for addition in additions {
+            want = want.and_then(|shape| shape.or_ref(&addition, Default::default()));
+            accumulator.union_in_place(&addition, Default::default());
+        }
+
+        assert_eq!(Some(accumulator), want);
+    }
+
+    #[test]
+    fn default_tolerance_ops_match_the_explicit_default() {
+        let subject = || Shape::<Polygon<f64>>::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip = || Shape::<Polygon<f64>>::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]);
+
+        assert_eq!(
+            subject().or_default_tol(clip()),
+            subject().or(clip(), Default::default()),
+            "the cartesian default tolerance must be the exact comparison"
+        );
+
+        assert_eq!(
+            subject().and_default_tol(clip()),
+            subject().and(clip(), Default::default()),
+        );
+
+        assert_eq!(
+            subject().not_default_tol(clip()),
+            subject().not(clip(), Default::default()),
+        );
+    }
+
+    #[test]
+    fn graph_size_estimate_bounds_the_workload() {
+        let subject: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip: Shape<Polygon<f64>> = Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]);
+
+        let estimate = subject.estimated_graph_size(&clip);
+        assert_eq!(
+            estimate.edge_pairs, 16,
+            "every edge pair of the two squares must be visited"
+        );
+        assert_eq!(
+            estimate.intersections, 32,
+            "each pair may register up to two intersections"
+        );
+        assert_eq!(
+            estimate.nodes,
+            subject.total_vertices() + clip.total_vertices() + estimate.intersections * 2,
+            "each intersection inserts a node in both operands"
+        );
+    }
+
+    #[test]
+    fn close_shapes_compare_within_tolerance() {
+        use crate::Tolerance;
+
+        let tolerance = Tolerance::for_extent(4.);
+
+        // The deviation is rounding noise, and neither the starting vertex nor the order of the
+        // boundaries has to match exactly.
+        let got: Shape<Polygon<f64>> =
+            Shape::new(vec![[0., 0.], [4., 0.], [4., 4. + 1e-14], [0., 4.]]);
+        let want: Shape<Polygon<f64>> = Shape::new(vec![[4., 0.], [4., 4.], [0., 4.], [0., 0.]]);
+
+        crate::assert_shapes_close!(got, want, tolerance);
+        assert!(got.is_close(&want, &tolerance));
+
+        let different: Shape<Polygon<f64>> =
+            Shape::new(vec![[0., 0.], [5., 0.], [5., 5.], [0., 5.]]);
+
+        assert!(!got.is_close(&different, &tolerance));
+        assert_eq!(
+            got.closeness_mismatches(&different, &tolerance).len(),
+            2,
+            "both unmatched boundaries must be reported"
+        );
+    }
+
+    #[test]
+    fn area_and_winding_conventions() {
+        use crate::AreaConvention;
+
+        let shape: Shape<Polygon<f64>> = Shape {
+            boundaries: vec![
+                vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                vec![[2., 2.], [2., 6.], [6., 6.], [6., 2.]].into(),
+            ],
+        };
+
+        assert_eq!(
+            shape.area_with(AreaConvention::Signed),
+            48.,
+            "the hole must subtract from the signed area"
+        );
+        assert_eq!(
+            shape.area_with(AreaConvention::Absolute),
+            80.,
+            "every ring must add to the absolute area"
+        );
+
+        let inside_hole: Point<f64> = [4., 4.].into();
+        assert_eq!(
+            shape.winding_with(&inside_hole, &Default::default(), AreaConvention::Signed),
+            0,
+            "the hole must cancel the winding of its shell"
+        );
+        assert_eq!(
+            shape.winding_with(&inside_hole, &Default::default(), AreaConvention::Absolute),
+            2,
+            "every ring must add to the absolute winding"
+        );
+    }
+
+    #[test]
+    fn geometries_are_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<Point<f64>>();
+        assert_send_sync::<Polygon<f64>>();
+        assert_send_sync::<Shape<Polygon<f64>>>();
+        assert_send_sync::<crate::Tolerance<f64>>();
+    }
+
+    #[test]
+    fn clipping_from_multiple_threads_is_consistent() {
+        let subject: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip: Shape<Polygon<f64>> = Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]);
+
+        let want = subject.and_ref(&clip, Default::default());
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| scope.spawn(|| subject.and_ref(&clip, Default::default())))
+                .collect();
+
+            for handle in handles {
+                let got = handle.join().expect("the clipping thread must not panic");
+                assert_eq!(got, want, "every thread must produce the same result");
+            }
+        });
+    }
 }
@@ -0,0 +1,412 @@
+//! Partitioning of cartesian shapes into y-monotone pieces.
+
+use num_traits::{Float, Signed};
+
+use crate::{
+    cartesian::{Point, Polygon},
+    Geometry, IsClose, Shape, Tolerance,
+};
+
+/// The role a vertex plays in the sweep over the shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// Both neighbors lie below and the interior angle is convex.
+    Start,
+    /// Both neighbors lie below and the interior angle is reflex.
+    Split,
+    /// Both neighbors lie above and the interior angle is convex.
+    End,
+    /// Both neighbors lie above and the interior angle is reflex.
+    Merge,
+    /// One neighbor lies above and the other below.
+    Regular,
+}
+
+/// The boundaries of a shape flattened into a single vertex arena.
+struct Rings<T> {
+    vertices: Vec<Point<T>>,
+    next: Vec<usize>,
+    prev: Vec<usize>,
+}
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float + IsClose<Tolerance = Tolerance<T>>,
+{
+    /// Returns this shape partitioned into y-monotone polygons.
+    ///
+    /// A polygon is y-monotone when every horizontal line crosses its interior in a single
+    /// segment, which makes it triangulable in linear time and cheap to point-locate. The
+    /// partition sweeps the boundaries from top to bottom, connecting each split and merge
+    /// vertex to a visible vertex by a diagonal, so holes end up absorbed into the pieces
+    /// surrounding them. Boundaries are expected to keep the filled region on their left-hand
+    /// side, as produced by the clipping operations.
+    pub fn monotone_partition(&self, tolerance: &Tolerance<T>) -> Vec<Polygon<T>> {
+        let rings = self.rings(tolerance);
+        if rings.vertices.is_empty() {
+            return Vec::new();
+        }
+
+        let diagonals = rings.diagonals();
+        rings.faces(&diagonals)
+    }
+
+    /// Returns the boundaries of this shape flattened into a single vertex arena, linking each
+    /// vertex to its neighbors within its own ring.
+    fn rings(&self, tolerance: &Tolerance<T>) -> Rings<T> {
+        let mut rings = Rings {
+            vertices: Vec::new(),
+            next: Vec::new(),
+            prev: Vec::new(),
+        };
+
+        for boundary in &self.boundaries {
+            let boundary = boundary.clone().deduped(tolerance);
+            let len = boundary.vertices.len();
+            if len < 3 {
+                continue;
+            }
+
+            let offset = rings.vertices.len();
+            for (position, vertex) in boundary.vertices.iter().enumerate() {
+                rings.vertices.push(*vertex);
+                rings.next.push(offset + (position + 1) % len);
+                rings.prev.push(offset + (position + len - 1) % len);
+            }
+        }
+
+        rings
+    }
+}
+
+impl<T> Rings<T>
+where
+    T: Signed + Float,
+{
+    /// Returns true if, and only if, the former vertex comes after the latter in the sweep,
+    /// breaking ties between vertices at the same height from left to right.
+    fn is_below(&self, vertex: usize, other: usize) -> bool {
+        let (a, b) = (&self.vertices[vertex], &self.vertices[other]);
+        a.y < b.y || (a.y == b.y && a.x > b.x)
+    }
+
+    /// Returns the role the given vertex plays in the sweep.
+    fn kind(&self, vertex: usize) -> Kind {
+        let prev_below = self.is_below(self.prev[vertex], vertex);
+        let next_below = self.is_below(self.next[vertex], vertex);
+
+        let p = self.vertices[self.prev[vertex]];
+        let v = self.vertices[vertex];
+        let n = self.vertices[self.next[vertex]];
+        let convex = (v.x - p.x) * (n.y - v.y) - (v.y - p.y) * (n.x - v.x) > T::zero();
+
+        match (prev_below, next_below, convex) {
+            (true, true, true) => Kind::Start,
+            (true, true, false) => Kind::Split,
+            (false, false, true) => Kind::End,
+            (false, false, false) => Kind::Merge,
+            _ => Kind::Regular,
+        }
+    }
+
+    /// Returns the abscissa at which the edge leaving the given vertex crosses the horizontal
+    /// line at the given height.
+    fn abscissa(&self, edge: usize, y: T) -> T {
+        let (from, to) = (self.vertices[edge], self.vertices[self.next[edge]]);
+        let dy = to.y - from.y;
+        if dy.is_zero() {
+            return from.x.min(to.x);
+        }
+
+        let t = ((y - from.y) / dy).max(T::zero()).min(T::one());
+        from.x + (to.x - from.x) * t
+    }
+
+    /// Returns the position in the status of the edge directly left of the given vertex.
+    fn left_of(&self, status: &[(usize, usize)], vertex: usize) -> Option<usize> {
+        let point = self.vertices[vertex];
+        status
+            .iter()
+            .enumerate()
+            .filter_map(|(position, &(edge, _))| {
+                let x = self.abscissa(edge, point.y);
+                (x <= point.x).then_some((position, x))
+            })
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(position, _)| position)
+    }
+
+    /// Returns the diagonals resolving every split and merge vertex of the rings.
+    ///
+    /// This is the classic plane sweep over the vertices from top to bottom, keeping for each
+    /// left-bounding edge the highest vertex from which a diagonal downwards may be drawn.
+    fn diagonals(&self) -> Vec<(usize, usize)> {
+        let mut events: Vec<usize> = (0..self.vertices.len()).collect();
+        events.sort_by(|&a, &b| {
+            if self.is_below(a, b) {
+                std::cmp::Ordering::Greater
+            } else if self.is_below(b, a) {
+                std::cmp::Ordering::Less
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        });
+
+        // Each entry pairs a left-bounding edge, identified by its upper vertex, with its
+        // current helper.
+        let mut status: Vec<(usize, usize)> = Vec::new();
+        let mut diagonals = Vec::new();
+
+        let close = |status: &mut Vec<(usize, usize)>,
+                         diagonals: &mut Vec<(usize, usize)>,
+                         edge: usize,
+                         vertex: usize| {
+            let Some(position) = status.iter().position(|&(candidate, _)| candidate == edge)
+            else {
+                return;
+            };
+
+            let (_, helper) = status.remove(position);
+            if self.kind(helper) == Kind::Merge {
+                diagonals.push((vertex, helper));
+            }
+        };
+
+        for vertex in events {
+            match self.kind(vertex) {
+                Kind::Start => status.push((vertex, vertex)),
+                Kind::End => close(&mut status, &mut diagonals, self.prev[vertex], vertex),
+                Kind::Split => {
+                    if let Some(position) = self.left_of(&status, vertex) {
+                        diagonals.push((vertex, status[position].1));
+                        status[position].1 = vertex;
+                    }
+                    status.push((vertex, vertex));
+                }
+                Kind::Merge => {
+                    close(&mut status, &mut diagonals, self.prev[vertex], vertex);
+                    if let Some(position) = self.left_of(&status, vertex) {
+                        if self.kind(status[position].1) == Kind::Merge {
+                            diagonals.push((vertex, status[position].1));
+                        }
+                        status[position].1 = vertex;
+                    }
+                }
+                Kind::Regular => {
+                    if self.is_below(self.next[vertex], vertex) {
+                        // The interior lies right of the vertex: the chain descends through it.
+                        close(&mut status, &mut diagonals, self.prev[vertex], vertex);
+                        status.push((vertex, vertex));
+                    } else if let Some(position) = self.left_of(&status, vertex) {
+                        if self.kind(status[position].1) == Kind::Merge {
+                            diagonals.push((vertex, status[position].1));
+                        }
+                        status[position].1 = vertex;
+                    }
+                }
+            }
+        }
+
+        diagonals
+    }
+
+    /// Returns the interior faces of the rings once split by the given diagonals.
+    ///
+    /// Ring edges keep the interior on their left, so walking each unvisited edge and always
+    /// taking the clockwise-most continuation traces exactly the interior faces. Diagonals are
+    /// traversable in both directions, as they border two faces.
+    fn faces(&self, diagonals: &[(usize, usize)]) -> Vec<Polygon<T>> {
+        let mut edges: Vec<(usize, usize)> = (0..self.vertices.len())
+            .map(|vertex| (vertex, self.next[vertex]))
+            .collect();
+
+        for &(a, b) in diagonals {
+            edges.push((a, b));
+            edges.push((b, a));
+        }
+
+        let mut outgoing: Vec<Vec<usize>> = vec![Vec::new(); self.vertices.len()];
+        for (edge, &(from, _)) in edges.iter().enumerate() {
+            outgoing[from].push(edge);
+        }
+
+        let angle = |from: usize, to: usize| {
+            let (a, b) = (self.vertices[from], self.vertices[to]);
+            (b.y - a.y).atan2(b.x - a.x)
+        };
+
+        for (vertex, edges_at) in outgoing.iter_mut().enumerate() {
+            edges_at.sort_by(|&a, &b| {
+                angle(vertex, edges[a].1)
+                    .partial_cmp(&angle(vertex, edges[b].1))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+        }
+
+        // The continuation of an edge is the clockwise-most outgoing edge at its head, starting
+        // from the direction the edge came from.
+        let continuation = |from: usize, to: usize| {
+            let back = angle(to, from);
+            outgoing[to]
+                .iter()
+                .copied()
+                .filter(|&edge| edges[edge].1 != from || angle(to, edges[edge].1) != back)
+                .max_by(|&a, &b| {
+                    let a = angle(to, edges[a].1);
+                    let b = angle(to, edges[b].1);
+                    let a_key = if a < back { (1, a) } else { (0, a) };
+                    let b_key = if b < back { (1, b) } else { (0, b) };
+                    a_key.partial_cmp(&b_key).unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .or_else(|| outgoing[to].first().copied())
+        };
+
+        let mut visited = vec![false; edges.len()];
+        let mut faces = Vec::new();
+
+        for first in 0..edges.len() {
+            if visited[first] {
+                continue;
+            }
+
+            let mut face = Vec::new();
+            let mut edge = first;
+            while !visited[edge] {
+                visited[edge] = true;
+                let (from, to) = edges[edge];
+                face.push(self.vertices[from]);
+
+                let Some(next) = continuation(from, to) else {
+                    break;
+                };
+                edge = next;
+            }
+
+            if face.len() >= 3 {
+                faces.push(Polygon { vertices: face });
+            }
+        }
+
+        faces
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Geometry, RightHanded, Shape};
+
+    /// Returns true if, and only if, the given polygon is y-monotone.
+    fn is_y_monotone(polygon: &Polygon<f64>) -> bool {
+        let below = |a: &[f64; 2], b: &[f64; 2]| a[1] < b[1] || (a[1] == b[1] && a[0] > b[0]);
+
+        let points: Vec<[f64; 2]> = polygon
+            .vertices
+            .iter()
+            .map(|vertex| [vertex.x, vertex.y])
+            .collect();
+
+        let len = points.len();
+        let mut maxima = 0;
+        let mut minima = 0;
+        for position in 0..len {
+            let prev = &points[(position + len - 1) % len];
+            let vertex = &points[position];
+            let next = &points[(position + 1) % len];
+
+            if below(prev, vertex) && below(next, vertex) {
+                maxima += 1;
+            }
+            if below(vertex, prev) && below(vertex, next) {
+                minima += 1;
+            }
+        }
+
+        maxima == 1 && minima == 1
+    }
+
+    #[test]
+    fn monotone_partition_must_preserve_the_shape() {
+        struct Test {
+            name: &'static str,
+            shape: Shape<Polygon<f64>>,
+            want_pieces: usize,
+        }
+
+        vec![
+            Test {
+                name: "convex square is a single piece",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                want_pieces: 1,
+            },
+            Test {
+                name: "square with a hole splits around it",
+                shape: Shape {
+                    boundaries: vec![
+                        vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                        vec![[2., 2.], [2., 6.], [6., 6.], [6., 2.]].into(),
+                    ],
+                },
+                want_pieces: 2,
+            },
+            Test {
+                name: "disjoint squares stay separate",
+                shape: Shape {
+                    boundaries: vec![
+                        vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]].into(),
+                        vec![[6., 6.], [10., 6.], [10., 10.], [6., 10.]].into(),
+                    ],
+                },
+                want_pieces: 2,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let pieces = test.shape.monotone_partition(&Default::default());
+
+            assert_eq!(
+                pieces.len(),
+                test.want_pieces,
+                "{}: amount of pieces",
+                test.name
+            );
+
+            let want_area = test.shape.stats().expect("the stats must exist").area;
+            let area: f64 = pieces.iter().map(Geometry::area).sum();
+            assert!(
+                (area - want_area).abs() < 1e-9,
+                "{}: got area {area}, want {want_area}",
+                test.name
+            );
+
+            for piece in &pieces {
+                assert!(
+                    !piece.is_clockwise(),
+                    "{}: every piece must keep the interior on its left",
+                    test.name
+                );
+                assert!(
+                    is_y_monotone(piece),
+                    "{}: piece {piece:?} must be y-monotone",
+                    test.name
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn monotone_partition_resolves_split_and_merge_vertices() {
+        // An "M" shaped polygon with a merge vertex at the notch between its peaks.
+        let shape: Shape<Polygon<f64>> =
+            Shape::new(vec![[0., 0.], [8., 0.], [8., 4.], [4., 2.], [0., 4.]]);
+
+        let pieces = shape.monotone_partition(&Default::default());
+        assert_eq!(pieces.len(), 2, "the merge vertex must produce two pieces");
+
+        for piece in &pieces {
+            assert!(
+                is_y_monotone(piece),
+                "piece {piece:?} must be y-monotone"
+            );
+        }
+    }
+}
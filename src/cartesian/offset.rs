@@ -0,0 +1,184 @@
+//! Inward offsetting of cartesian shapes.
+
+use num_traits::{Float, Signed};
+
+use crate::{
+    cartesian::{Point, Polygon, Segment},
+    Edge, Either, Geometry, IsClose, RightHanded, Shape, Tolerance,
+};
+
+impl<T> Polygon<T>
+where
+    T: Signed + Float,
+{
+    /// Returns this polygon with each edge displaced by the given distance towards its left-hand
+    /// side, or none if the polygon degenerates.
+    fn offset_left(&self, distance: T) -> Option<Self> {
+        if self.vertices.len() < 3 {
+            return None;
+        }
+
+        let displaced = self
+            .edges()
+            .map(|segment| {
+                let direction = *segment.to - *segment.from;
+                let length = direction.x.hypot(direction.y);
+                if length.is_zero() {
+                    return None;
+                }
+
+                let normal = Point {
+                    x: -direction.y / length,
+                    y: direction.x / length,
+                };
+
+                Some((
+                    *segment.from + normal * distance,
+                    *segment.to + normal * distance,
+                ))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let len = displaced.len();
+        let vertices = (0..len)
+            .map(|position| {
+                // The vertex between two displaced edges is the crossing of their infinite
+                // lines, falling back to the displaced endpoint when they are parallel.
+                let (a, b) = displaced[(position + len - 1) % len];
+                let (c, d) = displaced[position];
+
+                let denominator = (b.x - a.x) * (d.y - c.y) - (b.y - a.y) * (d.x - c.x);
+                if denominator.abs() < T::epsilon() {
+                    return c;
+                }
+
+                let t = ((c.x - a.x) * (d.y - c.y) - (c.y - a.y) * (d.x - c.x)) / denominator;
+                a + (b - a) * t
+            })
+            .collect();
+
+        Some(Self { vertices })
+    }
+}
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float + IsClose<Tolerance = Tolerance<T>>,
+{
+    /// Returns this shape shrunk inwards by the given distance, if anything remains of it.
+    ///
+    /// Boundaries keep the filled region on their left-hand side, so displacing every edge
+    /// towards the left shrinks outer boundaries and grows holes alike. Rings that collapse
+    /// under the displacement reverse most of their edges and are dropped, while rings that
+    /// pinch apart are split at the self-crossings of the displaced boundary, keeping the lobes
+    /// whose orientation survives the displacement.
+    pub fn erode(self, distance: T, tolerance: Tolerance<T>) -> Option<Self> {
+        let boundaries = self
+            .boundaries
+            .iter()
+            .filter_map(|boundary| {
+                let offset = boundary.offset_left(distance)?;
+
+                // A fully collapsed ring may keep its winding, yet traverses every edge against
+                // its original direction.
+                let alignment = boundary.edges().zip(offset.edges()).fold(
+                    T::zero(),
+                    |alignment, (original, displaced)| {
+                        let u = *original.to - *original.from;
+                        let v = *displaced.to - *displaced.from;
+                        alignment + u.x * v.x + u.y * v.y
+                    },
+                );
+
+                (alignment.is_positive() && offset.is_clockwise() == boundary.is_clockwise())
+                    .then_some((boundary.is_clockwise(), offset))
+            })
+            .flat_map(|(clockwise, offset)| {
+                split_at_crossings(offset, &tolerance)
+                    .into_iter()
+                    .filter(move |lobe| lobe.is_clockwise() == clockwise)
+            })
+            .collect::<Vec<_>>();
+
+        (!boundaries.is_empty()).then_some(Shape { boundaries })
+    }
+}
+
+/// Splits the given ring at its self-crossings, returning the resulting simple rings.
+///
+/// Pieces degenerating below three vertices are discarded.
+pub(crate) fn split_at_crossings<T>(ring: Polygon<T>, tolerance: &Tolerance<T>) -> Vec<Polygon<T>>
+where
+    T: Signed + Float + IsClose<Tolerance = Tolerance<T>>,
+{
+    let ring = ring.deduped(tolerance);
+    let len = ring.vertices.len();
+    if len < 3 {
+        return Vec::new();
+    }
+
+    for position in 0..len {
+        for other in position + 1..len {
+            let adjacent = other == position + 1 || (position == 0 && other == len - 1);
+            if adjacent {
+                continue;
+            }
+
+            let segment = Segment::new(&ring.vertices[position], &ring.vertices[(position + 1) % len]);
+            let candidate = Segment::new(&ring.vertices[other], &ring.vertices[(other + 1) % len]);
+            let Some(Either::Left(crossing)) = segment.intersection(&candidate, tolerance) else {
+                continue;
+            };
+
+            // Exchanging the continuations at the crossing separates the ring into two, each
+            // strictly shorter than the original.
+            let mut first = ring.vertices[..=position].to_vec();
+            first.push(crossing);
+            first.extend_from_slice(&ring.vertices[other + 1..]);
+
+            let mut second = vec![crossing];
+            second.extend_from_slice(&ring.vertices[position + 1..=other]);
+
+            let mut rings = split_at_crossings(Polygon { vertices: first }, tolerance);
+            rings.extend(split_at_crossings(Polygon { vertices: second }, tolerance));
+            return rings;
+        }
+    }
+
+    vec![ring]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Shape};
+
+    #[test]
+    fn erosion_must_shrink_shapes() {
+        struct Test {
+            name: &'static str,
+            shape: Shape<Polygon<f64>>,
+            distance: f64,
+            want: Option<Shape<Polygon<f64>>>,
+        }
+
+        vec![
+            Test {
+                name: "square shrinks by the distance",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                distance: 1.,
+                want: Some(Shape::new(vec![[1., 1.], [3., 1.], [3., 3.], [1., 3.]])),
+            },
+            Test {
+                name: "square collapsing under the distance vanishes",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                distance: 3.,
+                want: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = test.shape.erode(test.distance, Default::default());
+            assert_eq!(got, test.want, "{}", test.name);
+        });
+    }
+}
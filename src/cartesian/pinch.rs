@@ -0,0 +1,150 @@
+//! Normalization of holes touching their exterior shell.
+
+use num_traits::{Float, Signed};
+
+use crate::{cartesian::Polygon, IsClose, RightHanded, Shape, Tolerance};
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float,
+{
+    /// Returns this shape with every hole touching an outer boundary merged into a single
+    /// pinched ring.
+    ///
+    /// A hole sharing a vertex with its shell is valid under OGC semantics, yet the shared
+    /// vertex confuses the classification of intersections during clipping. Splicing the hole
+    /// into its shell at the shared vertex yields an equivalent boundary visiting that vertex
+    /// twice, which clips reliably. Holes touching no outer boundary are left untouched, while
+    /// holes sharing a whole edge are pinched at its first shared vertex.
+    pub fn pinched(&self, tolerance: &Tolerance<T>) -> Self {
+        let (mut shells, holes): (Vec<_>, Vec<_>) = self
+            .boundaries
+            .iter()
+            .cloned()
+            .partition(|boundary| !boundary.is_clockwise());
+
+        let mut orphans = Vec::new();
+        for hole in holes {
+            let contact = shells.iter().enumerate().find_map(|(shell, boundary)| {
+                boundary.vertices.iter().enumerate().find_map(|(at, vertex)| {
+                    hole.vertices
+                        .iter()
+                        .position(|candidate| candidate.is_close(vertex, tolerance))
+                        .map(|from| (shell, at, from))
+                })
+            });
+
+            match contact {
+                Some((shell, at, from)) => splice(&mut shells[shell], &hole, at, from),
+                None => orphans.push(hole),
+            }
+        }
+
+        shells.extend(orphans);
+        Self { boundaries: shells }
+    }
+}
+
+/// Splices the given hole into the shell, entering and leaving the latter at the vertex pair
+/// shared by both boundaries.
+fn splice<T: Clone>(shell: &mut Polygon<T>, hole: &Polygon<T>, at: usize, from: usize) {
+    let mut vertices = Vec::with_capacity(shell.vertices.len() + hole.vertices.len() + 1);
+    vertices.extend_from_slice(&shell.vertices[..=at]);
+    vertices.extend(
+        hole.vertices[from + 1..]
+            .iter()
+            .chain(&hole.vertices[..=from])
+            .cloned(),
+    );
+    vertices.extend_from_slice(&shell.vertices[at + 1..]);
+
+    shell.vertices = vertices;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Shape, Tolerance};
+
+    #[test]
+    fn pinching_merges_touching_holes() {
+        struct Test {
+            name: &'static str,
+            boundaries: Vec<Polygon<f64>>,
+            want_boundaries: usize,
+            want_area: f64,
+        }
+
+        vec![
+            Test {
+                name: "hole sharing a vertex with its shell",
+                boundaries: vec![
+                    vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                    vec![[0., 0.], [1., 3.], [3., 1.]].into(),
+                ],
+                want_boundaries: 1,
+                want_area: 60.,
+            },
+            Test {
+                name: "hole detached from its shell",
+                boundaries: vec![
+                    vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                    vec![[2., 2.], [2., 6.], [6., 6.], [6., 2.]].into(),
+                ],
+                want_boundaries: 2,
+                want_area: 48.,
+            },
+            Test {
+                name: "hole touching no shell at all",
+                boundaries: vec![vec![[0., 0.], [1., 3.], [3., 1.]].into()],
+                want_boundaries: 1,
+                want_area: -4.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let shape = Shape {
+                boundaries: test.boundaries,
+            };
+
+            let pinched = shape.pinched(&Tolerance::default());
+            assert_eq!(
+                pinched.boundaries.len(),
+                test.want_boundaries,
+                "{}: amount of boundaries",
+                test.name
+            );
+
+            let area = pinched.stats().expect("the stats must exist").area;
+            assert!(
+                (area - test.want_area).abs() < 1e-9,
+                "{}: got area {}, want {}",
+                test.name,
+                area,
+                test.want_area
+            );
+        });
+    }
+
+    #[test]
+    fn pinched_shapes_clip_correctly() {
+        let shape = Shape {
+            boundaries: vec![
+                vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                vec![[0., 0.], [1., 3.], [3., 1.]].into(),
+            ],
+        }
+        .pinched(&Tolerance::default());
+
+        let mask: Shape<Polygon<f64>> = Shape::new(vec![[3., 3.], [10., 3.], [10., 10.], [3., 10.]]);
+
+        let clipped = shape
+            .and_ref(&mask, Tolerance::default())
+            .expect("the intersection must not be empty");
+
+        let area = clipped.stats().expect("the stats must exist").area;
+        assert!(
+            (area - 25.).abs() < 1e-9,
+            "the pinch must not affect clipping away from it, got area {area}",
+        );
+    }
+}
@@ -70,6 +70,15 @@ where
     fn distance(&self, other: &Self) -> Self::Scalar {
         ((self.x - other.x).powi(2) + (self.y - other.y).powi(2)).sqrt()
     }
+
+    fn rounded(self, decimals: u32) -> Self {
+        let factor = T::from(10).unwrap_or_else(T::one).powi(decimals as i32);
+
+        Self {
+            x: (self.x * factor).round() / factor,
+            y: (self.y * factor).round() / factor,
+        }
+    }
 }
 
 impl<T> IsClose for Point<T>
@@ -81,4 +90,11 @@ where
     fn is_close(&self, other: &Self, tolerance: &Self::Tolerance) -> bool {
         self.x.is_close(&other.x, tolerance) && self.y.is_close(&other.y, tolerance)
     }
+
+    fn scaled_tolerance(tolerance: &Self::Tolerance, factor: f64) -> Self::Tolerance
+    where
+        Self::Tolerance: Clone,
+    {
+        T::scaled_tolerance(tolerance, factor)
+    }
 }
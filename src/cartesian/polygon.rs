@@ -5,9 +5,19 @@ use num_traits::{Float, Signed};
 use crate::{
     cartesian::{determinant::Determinant, Point, Segment},
     clipper::Operands,
-    Edge, Geometry, RightHanded, Tolerance,
+    Edge, Geometry, IsClose, RightHanded, Tolerance,
 };
 
+/// The direction of the ray casted by [`Polygon::winding_with_ray`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RayDirection {
+    /// The ray is casted parallel to the x axis.
+    #[default]
+    Horizontal,
+    /// The ray is casted parallel to the y axis.
+    Vertical,
+}
+
 /// A polygon in the plain.
 #[derive(Debug, Clone)]
 pub struct Polygon<T> {
@@ -49,6 +59,28 @@ where
     }
 }
 
+impl<T> IsClose for Polygon<T>
+where
+    T: IsClose<Tolerance = Tolerance<T>>,
+{
+    type Tolerance = Tolerance<T>;
+
+    /// Two polygons are close if, and only if, their vertices describe the same boundary within
+    /// the tolerance, regardless of which vertex each one starts at.
+    fn is_close(&self, other: &Self, tolerance: &Self::Tolerance) -> bool {
+        let len = self.vertices.len();
+        if len != other.vertices.len() {
+            return false;
+        }
+
+        (0..len).any(|padding| {
+            self.vertices.iter().enumerate().all(|(position, vertex)| {
+                vertex.is_close(&other.vertices[(position + padding) % len], tolerance)
+            })
+        })
+    }
+}
+
 impl<T> RightHanded for Polygon<T>
 where
     T: Signed + Float,
@@ -109,7 +141,57 @@ where
         self
     }
 
+    fn area(&self) -> T {
+        let double = self.edges().fold(T::zero(), |sum, segment| {
+            sum + segment.from.x * segment.to.y - segment.to.x * segment.from.y
+        });
+
+        (double / (T::one() + T::one())).abs()
+    }
+
+    fn map(mut self, f: impl FnMut(Point<T>) -> Point<T>) -> Self {
+        self.vertices = self.vertices.into_iter().map(f).collect();
+        self
+    }
+
+    fn deduped(mut self, tolerance: &Tolerance<T>) -> Self {
+        self.vertices
+            .dedup_by(|vertex, kept| vertex.is_close(kept, tolerance));
+
+        let closed = self
+            .vertices
+            .first()
+            .zip(self.vertices.last())
+            .is_some_and(|(first, last)| first.is_close(last, tolerance));
+
+        if self.vertices.len() > 1 && closed {
+            self.vertices.pop();
+        }
+
+        self
+    }
+
     fn winding(&self, point: &Point<T>, tolerance: &Tolerance<T>) -> isize {
+        self.winding_with_ray(point, tolerance, RayDirection::default())
+    }
+}
+
+impl<T> Polygon<T>
+where
+    T: Signed + Float,
+{
+    /// Returns the amount of times this polygon winds around the given point, counting crossings
+    /// along a ray casted in the given direction.
+    ///
+    /// The [`Geometry::winding`] implementation casts a horizontal ray, whose crossing rule may
+    /// be brittle when many vertices lie exactly at the query point's y coordinate. Grid-aligned
+    /// data is often better served by a [`RayDirection::Vertical`] ray.
+    pub fn winding_with_ray(
+        &self,
+        point: &Point<T>,
+        tolerance: &Tolerance<T>,
+        ray: RayDirection,
+    ) -> isize {
         // Returns true if, and only if, the point is on the left of the infinite line containing
         // the given segment.
         let left_of = |segment: &Segment<'_, T>| {
@@ -118,18 +200,63 @@ where
                 .is_positive()
         };
 
+        // Returns true if, and only if, the segment crosses the ray's supporting line in the
+        // forward (resp. backward) direction.
+        type Crossing<T> = fn(&Segment<'_, T>, &Point<T>) -> bool;
+        let (forward, backward): (Crossing<T>, Crossing<T>) = match ray {
+            RayDirection::Horizontal => (
+                |segment, point| segment.from.y <= point.y && segment.to.y > point.y,
+                |segment: &Segment<'_, T>, point: &Point<T>| {
+                    segment.from.y > point.y && segment.to.y <= point.y
+                },
+            ),
+            RayDirection::Vertical => (
+                |segment, point| segment.from.x <= point.x && segment.to.x > point.x,
+                |segment: &Segment<'_, T>, point: &Point<T>| {
+                    segment.from.x > point.x && segment.to.x <= point.x
+                },
+            ),
+        };
+
         self.edges().fold(0, |wn, segment| {
-            if segment.contains(point, tolerance)
-                || segment.from.y <= point.y && segment.to.y > point.y && left_of(&segment)
-            {
+            if segment.contains(point, tolerance) || forward(&segment, point) && left_of(&segment) {
                 wn + 1
-            } else if segment.from.y > point.y && segment.to.y <= point.y && !left_of(&segment) {
+            } else if backward(&segment, point) && !left_of(&segment) {
                 wn - 1
             } else {
                 wn
             }
         })
     }
+
+    /// Returns this polygon oriented counterclockwise and rotated to start at its
+    /// lexicographically smallest vertex.
+    ///
+    /// Any two polygons describing the same boundary share the same normalized form, so their
+    /// vertex lists can be compared, hashed or snapshotted directly, without the rotation-aware
+    /// comparison performed by `PartialEq`.
+    pub fn normalized(self) -> Self {
+        let mut polygon = if self.is_clockwise() {
+            self.reversed()
+        } else {
+            self
+        };
+
+        let start = polygon
+            .vertices
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                (a.x, a.y)
+                    .partial_cmp(&(b.x, b.y))
+                    .unwrap_or(Ordering::Equal)
+            })
+            .map(|(position, _)| position)
+            .unwrap_or_default();
+
+        polygon.vertices.rotate_left(start);
+        polygon
+    }
 }
 
 impl<T> IntoIterator for Polygon<T> {
@@ -146,16 +273,31 @@ impl<T> Polygon<T> {
     ///
     /// By definition, a polygon is a closed shape, hence the latest point of the iterator equals
     /// the very first.
-    fn vertices(&self) -> impl Iterator<Item = &Point<T>> {
+    pub fn vertices(&self) -> impl Iterator<Item = &Point<T>> {
         self.vertices.iter().chain(self.vertices.first())
     }
+
+    /// Returns the vertex of the polygon at the given position, if any.
+    pub fn vertex(&self, position: usize) -> Option<&Point<T>> {
+        self.vertices.get(position)
+    }
+
+    /// Returns the amount of vertices in the polygon, not counting the implicit closing one.
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Returns true if, and only if, the polygon has no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        cartesian::{point::Point, Polygon},
-        Geometry, RightHanded,
+        cartesian::{point::Point, polygon::RayDirection, Polygon},
+        Geometry, RightHanded, Tolerance,
     };
 
     #[test]
@@ -292,6 +434,51 @@ mod tests {
         });
     }
 
+    #[test]
+    fn polygon_winding_number_with_vertical_ray() {
+        struct Test {
+            name: &'static str,
+            polygon: Polygon<f64>,
+            point: Point<f64>,
+            want: isize,
+        }
+
+        vec![
+            Test {
+                name: "center of a counterclockwise polygon",
+                polygon: vec![[4., 0.], [4., 4.], [0., 4.], [0., 0.]].into(),
+                point: [2., 2.].into(),
+                want: 1,
+            },
+            Test {
+                name: "center of a clockwise polygon",
+                polygon: vec![[0., 0.], [0., 4.], [4., 4.], [4., 0.]].into(),
+                point: [2., 2.].into(),
+                want: -1,
+            },
+            Test {
+                name: "outside the polygon",
+                polygon: vec![[4., 0.], [4., 4.], [0., 4.], [0., 0.]].into(),
+                point: [6., 6.].into(),
+                want: 0,
+            },
+            Test {
+                name: "vertex-aligned point inside a diamond",
+                polygon: vec![[2., 0.], [4., 2.], [2., 4.], [0., 2.]].into(),
+                point: [2., 2.].into(),
+                want: 1,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got =
+                test.polygon
+                    .winding_with_ray(&test.point, &Default::default(), RayDirection::Vertical);
+
+            assert_eq!(got, test.want, "{}", test.name);
+        });
+    }
+
     #[test]
     fn polygon_clockwise_orientation() {
         struct Test {
@@ -392,4 +579,127 @@ mod tests {
             assert_eq!(got, test.want, "{}", test.name);
         });
     }
+
+    #[test]
+    fn polygon_dedup() {
+        struct Test {
+            name: &'static str,
+            polygon: Polygon<f64>,
+            tolerance: Tolerance<f64>,
+            want: Polygon<f64>,
+        }
+
+        vec![
+            Test {
+                name: "polygon without zero-length edges",
+                polygon: vec![[4., 0.], [4., 4.], [0., 4.], [0., 0.]].into(),
+                tolerance: Default::default(),
+                want: vec![[4., 0.], [4., 4.], [0., 4.], [0., 0.]].into(),
+            },
+            Test {
+                name: "polygon with a repeated consecutive vertex",
+                polygon: vec![[4., 0.], [4., 4.], [4., 4.], [0., 4.], [0., 0.]].into(),
+                tolerance: Default::default(),
+                want: vec![[4., 0.], [4., 4.], [0., 4.], [0., 0.]].into(),
+            },
+            Test {
+                name: "polygon with an explicit closing vertex",
+                polygon: vec![[4., 0.], [4., 4.], [0., 4.], [0., 0.], [4., 0.]].into(),
+                tolerance: Default::default(),
+                want: vec![[4., 0.], [4., 4.], [0., 4.], [0., 0.]].into(),
+            },
+            Test {
+                name: "polygon with a nearly repeated vertex",
+                polygon: vec![[4., 0.], [4., 4.], [4. + 1e-12, 4.], [0., 4.], [0., 0.]].into(),
+                tolerance: Tolerance {
+                    relative: 1e-9.into(),
+                    absolute: 1e-9.into(),
+                },
+                want: vec![[4., 0.], [4., 4.], [0., 4.], [0., 0.]].into(),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = test.polygon.deduped(&test.tolerance);
+            assert_eq!(got, test.want, "{}", test.name);
+        });
+    }
+
+    #[test]
+    fn polygon_normalization() {
+        struct Test {
+            name: &'static str,
+            polygon: Polygon<f64>,
+        }
+
+        vec![
+            Test {
+                name: "already normalized polygon",
+                polygon: vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]].into(),
+            },
+            Test {
+                name: "polygon starting at another vertex",
+                polygon: vec![[4., 0.], [4., 4.], [0., 4.], [0., 0.]].into(),
+            },
+            Test {
+                name: "clockwise polygon",
+                polygon: vec![[0., 0.], [0., 4.], [4., 4.], [4., 0.]].into(),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let want: Polygon<f64> = vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]].into();
+            let got = test.polygon.normalized();
+
+            assert_eq!(got.vertices, want.vertices, "{}", test.name);
+        });
+    }
+
+    #[test]
+    fn polygon_enclosure() {
+        struct Test {
+            name: &'static str,
+            polygon: Polygon<f64>,
+            other: Polygon<f64>,
+            want: bool,
+        }
+
+        vec![
+            Test {
+                name: "polygon strictly inside",
+                polygon: vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]].into(),
+                other: vec![[1., 1.], [3., 1.], [3., 3.], [1., 3.]].into(),
+                want: true,
+            },
+            Test {
+                name: "overlapping polygons",
+                polygon: vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]].into(),
+                other: vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]].into(),
+                want: false,
+            },
+            Test {
+                name: "disjoint polygons",
+                polygon: vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]].into(),
+                other: vec![[6., 6.], [8., 6.], [8., 8.], [6., 8.]].into(),
+                want: false,
+            },
+            Test {
+                name: "polygon touching from the inside",
+                polygon: vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]].into(),
+                other: vec![[0., 0.], [2., 1.], [1., 2.]].into(),
+                want: false,
+            },
+            Test {
+                name: "polygon enclosing instead of enclosed",
+                polygon: vec![[1., 1.], [3., 1.], [3., 3.], [1., 3.]].into(),
+                other: vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]].into(),
+                want: false,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = test.polygon.encloses(&test.other, &Tolerance::default());
+            assert_eq!(got, test.want, "{}", test.name);
+        });
+    }
 }
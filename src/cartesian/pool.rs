@@ -0,0 +1,164 @@
+//! Interned vertex storage shared across shapes.
+
+use std::cmp::Ordering;
+
+use num_traits::Float;
+
+use crate::{
+    cartesian::{Point, Polygon},
+    Shape,
+};
+
+/// A pool interning the vertices shared by many shapes.
+///
+/// Tiled workloads hold thousands of shapes whose boundaries repeat the very same coordinates,
+/// most notably along tile borders. Storing each distinct vertex once and keeping shapes as
+/// lists of positions into the pool trades the duplicated coordinates for integer indices,
+/// reducing memory several-fold.
+///
+/// Interning compares coordinates exactly: vertices shared by construction, as in tiled data,
+/// are bit-identical and collapse into a single entry, while nearly-equal vertices remain
+/// distinct.
+#[derive(Debug, Default, Clone)]
+pub struct VertexPool<T> {
+    /// The interned vertices, in insertion order.
+    vertices: Vec<Point<T>>,
+    /// The positions of the interned vertices, sorted by coordinates.
+    index: Vec<usize>,
+}
+
+/// A shape whose vertices live in a [`VertexPool`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PooledShape {
+    /// The position in the pool of each vertex of each boundary.
+    boundaries: Vec<Vec<usize>>,
+}
+
+impl<T> VertexPool<T>
+where
+    T: Float,
+{
+    /// Returns an empty pool.
+    pub fn new() -> Self {
+        Self {
+            vertices: Vec::new(),
+            index: Vec::new(),
+        }
+    }
+
+    /// Returns the amount of distinct vertices in the pool.
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Returns true if, and only if, the pool holds no vertex at all.
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    /// Returns the vertex at the given position in the pool, if any.
+    pub fn get(&self, position: usize) -> Option<&Point<T>> {
+        self.vertices.get(position)
+    }
+
+    /// Returns the position in the pool of the given vertex, interning it on its first
+    /// appearance.
+    pub fn intern(&mut self, point: Point<T>) -> usize {
+        let vertices = &self.vertices;
+        let slot = self.index.binary_search_by(|&position| {
+            let existing = &vertices[position];
+            (existing.x, existing.y)
+                .partial_cmp(&(point.x, point.y))
+                .unwrap_or(Ordering::Equal)
+        });
+
+        match slot {
+            Ok(slot) => self.index[slot],
+            Err(slot) => {
+                let position = self.vertices.len();
+                self.vertices.push(point);
+                self.index.insert(slot, position);
+                position
+            }
+        }
+    }
+
+    /// Returns the given shape as lists of positions into this pool, interning every vertex not
+    /// yet present.
+    pub fn store(&mut self, shape: &Shape<Polygon<T>>) -> PooledShape {
+        PooledShape {
+            boundaries: shape
+                .boundaries
+                .iter()
+                .map(|boundary| {
+                    boundary
+                        .vertices
+                        .iter()
+                        .map(|&vertex| self.intern(vertex))
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the given pooled shape with its vertices resolved against this pool, or none if
+    /// any of its positions is not interned here.
+    pub fn hydrate(&self, shape: &PooledShape) -> Option<Shape<Polygon<T>>> {
+        let boundaries = shape
+            .boundaries
+            .iter()
+            .map(|boundary| {
+                boundary
+                    .iter()
+                    .map(|&position| self.get(position).copied())
+                    .collect::<Option<Vec<_>>>()
+                    .map(|vertices| Polygon { vertices })
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Shape { boundaries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Shape};
+
+    use super::VertexPool;
+
+    #[test]
+    fn pool_shares_vertices_across_shapes() {
+        let left: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let right: Shape<Polygon<f64>> = Shape::new(vec![[4., 0.], [8., 0.], [8., 4.], [4., 4.]]);
+
+        let mut pool = VertexPool::new();
+        let pooled_left = pool.store(&left);
+        let pooled_right = pool.store(&right);
+
+        assert_eq!(
+            pool.len(),
+            6,
+            "the vertices along the shared border must be interned once"
+        );
+
+        let hydrated_left = pool.hydrate(&pooled_left).expect("the pool must resolve");
+        let hydrated_right = pool.hydrate(&pooled_right).expect("the pool must resolve");
+
+        assert_eq!(hydrated_left, left, "the left shape must round-trip");
+        assert_eq!(hydrated_right, right, "the right shape must round-trip");
+    }
+
+    #[test]
+    fn hydration_requires_interned_positions() {
+        let shape: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.]]);
+
+        let mut pool = VertexPool::new();
+        let pooled = pool.store(&shape);
+
+        let empty = VertexPool::<f64>::new();
+        assert!(
+            empty.hydrate(&pooled).is_none(),
+            "a foreign pool must not resolve the shape"
+        );
+    }
+}
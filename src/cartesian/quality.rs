@@ -0,0 +1,145 @@
+//! Mesh-quality metrics for cartesian shapes.
+
+use num_traits::{Float, FloatConst, Signed};
+
+use crate::{
+    cartesian::Polygon,
+    Geometry, RightHanded, Shape, Vertex,
+};
+
+/// The quality metrics of a single boundary of a [`Shape`].
+///
+/// These are the figures simulation meshes are usually gated on: near-zero edges, near-zero
+/// angles and sliver-like proportions all degrade into ill-conditioned elements once meshed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundaryQuality<T> {
+    /// The length of the shortest edge of the boundary.
+    pub min_edge: T,
+    /// The length of the longest edge of the boundary.
+    pub max_edge: T,
+    /// The smallest interior angle of the boundary, in radians.
+    pub min_angle: T,
+    /// The isoperimetric quotient of the boundary: one for a circle, approaching zero for a
+    /// sliver.
+    pub sliver_score: T,
+}
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float + FloatConst,
+{
+    /// Returns the [`BoundaryQuality`] of each boundary of this shape, in boundary order.
+    ///
+    /// Holes are measured like any other boundary, with their interior angles taken on the
+    /// filled side of the shape.
+    pub fn quality_report(&self) -> Vec<BoundaryQuality<T>> {
+        self.boundaries
+            .iter()
+            .map(|boundary| {
+                let (min_edge, max_edge) = boundary.edges().fold(
+                    (T::infinity(), T::neg_infinity()),
+                    |(min, max), segment| {
+                        let length = segment.from.distance(segment.to);
+                        (min.min(length), max.max(length))
+                    },
+                );
+
+                let len = boundary.vertices.len();
+                let min_angle = (0..len).fold(T::infinity(), |min, index| {
+                    let previous = boundary.vertices[(index + len - 1) % len];
+                    let vertex = boundary.vertices[index];
+                    let next = boundary.vertices[(index + 1) % len];
+
+                    let incoming = vertex - previous;
+                    let outgoing = next - vertex;
+                    let cross = incoming.x * outgoing.y - incoming.y * outgoing.x;
+                    let dot = incoming.x * outgoing.x + incoming.y * outgoing.y;
+
+                    let turn = if boundary.is_clockwise() {
+                        -cross.atan2(dot)
+                    } else {
+                        cross.atan2(dot)
+                    };
+
+                    min.min(T::PI() - turn)
+                });
+
+                let perimeter = boundary
+                    .edges()
+                    .fold(T::zero(), |sum, segment| sum + segment.from.distance(segment.to));
+
+                let four = T::one() + T::one() + T::one() + T::one();
+                let sliver_score = if perimeter == T::zero() {
+                    T::zero()
+                } else {
+                    four * T::PI() * boundary.area() / (perimeter * perimeter)
+                };
+
+                BoundaryQuality {
+                    min_edge,
+                    max_edge,
+                    min_angle,
+                    sliver_score,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use crate::{cartesian::Polygon, Shape};
+
+    #[test]
+    fn quality_report_measures_each_boundary() {
+        let shape: Shape<Polygon<f64>> = Shape {
+            boundaries: vec![
+                vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]].into(),
+                vec![[1., 1.], [1., 3.], [3., 3.], [3., 1.]].into(),
+            ],
+        };
+
+        let report = shape.quality_report();
+        assert_eq!(report.len(), 2, "every boundary must be measured");
+
+        let outer = &report[0];
+        assert!((outer.min_edge - 4.).abs() < 1e-9);
+        assert!((outer.max_edge - 4.).abs() < 1e-9);
+        assert!(
+            (outer.min_angle - FRAC_PI_2).abs() < 1e-9,
+            "the corners of a square are right angles, got {}",
+            outer.min_angle
+        );
+        assert!(
+            (outer.sliver_score - PI / 4.).abs() < 1e-9,
+            "a square scores pi over four, got {}",
+            outer.sliver_score
+        );
+
+        let hole = &report[1];
+        assert!(
+            (hole.min_angle - FRAC_PI_2).abs() < 1e-9,
+            "angles of a hole are measured on the filled side, got {}",
+            hole.min_angle
+        );
+    }
+
+    #[test]
+    fn quality_report_flags_slivers() {
+        let sliver: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [10., 0.], [10., 0.01]]);
+        let report = sliver.quality_report();
+
+        assert!(
+            report[0].sliver_score < 0.01,
+            "a degenerate triangle must score near zero, got {}",
+            report[0].sliver_score
+        );
+        assert!(
+            report[0].min_angle < FRAC_PI_4,
+            "a degenerate triangle holds a near-zero angle, got {}",
+            report[0].min_angle
+        );
+    }
+}
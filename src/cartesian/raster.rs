@@ -0,0 +1,205 @@
+//! Winding-number raster sampling for cartesian shapes.
+
+use num_traits::{Float, Signed};
+
+use crate::{
+    cartesian::{Point, Polygon},
+    Shape, Tolerance,
+};
+
+/// A raster of winding numbers sampled over a rectangular window of the plane.
+///
+/// Unlike a containment query, each cell keeps the full winding number of its center, exposing
+/// the multiplicity of self-overlapping regions and making the effect of each
+/// [`FillRule`](crate::FillRule) directly observable.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindingGrid<T> {
+    /// The lower-left corner of the sampled window.
+    min: Point<T>,
+    /// The horizontal and vertical size of each cell.
+    step: Point<T>,
+    /// The amount of cells per row.
+    columns: usize,
+    /// The winding number at the center of each cell, in row-major order from the lower-left.
+    windings: Vec<isize>,
+}
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float,
+{
+    /// Returns a [`WindingGrid`] sampling this shape over the window delimited by the given
+    /// corners, with the given amount of columns and rows, or none if the window or the
+    /// resolution is degenerate.
+    pub fn winding_grid(
+        &self,
+        min: impl Into<Point<T>>,
+        max: impl Into<Point<T>>,
+        resolution: [usize; 2],
+        tolerance: &Tolerance<T>,
+    ) -> Option<WindingGrid<T>> {
+        let (min, max) = (min.into(), max.into());
+        let [columns, rows] = resolution;
+        if columns == 0 || rows == 0 || min.x >= max.x || min.y >= max.y {
+            return None;
+        }
+
+        let step = Point {
+            x: (max.x - min.x) / T::from(columns)?,
+            y: (max.y - min.y) / T::from(rows)?,
+        };
+
+        let grids = self
+            .boundaries
+            .iter()
+            .map(|boundary| boundary.edge_grid(rows))
+            .collect::<Option<Vec<_>>>()?;
+
+        let half = T::from(0.5)?;
+        let mut windings = Vec::with_capacity(columns * rows);
+        for row in 0..rows {
+            for column in 0..columns {
+                let center = Point {
+                    x: min.x + step.x * (T::from(column)? + half),
+                    y: min.y + step.y * (T::from(row)? + half),
+                };
+
+                windings.push(
+                    grids
+                        .iter()
+                        .map(|grid| grid.winding(&center, tolerance))
+                        .sum(),
+                );
+            }
+        }
+
+        Some(WindingGrid {
+            min,
+            step,
+            columns,
+            windings,
+        })
+    }
+}
+
+impl<T> WindingGrid<T>
+where
+    T: Float,
+{
+    /// Returns the amount of cells per row.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the amount of rows in the grid.
+    pub fn rows(&self) -> usize {
+        self.windings.len() / self.columns
+    }
+
+    /// Returns the winding number sampled at the cell in the given column and row, counted from
+    /// the lower-left corner, or none if the cell is out of bounds.
+    pub fn winding(&self, column: usize, row: usize) -> Option<isize> {
+        if column >= self.columns {
+            return None;
+        }
+
+        self.windings.get(row * self.columns + column).copied()
+    }
+
+    /// Returns the point at which the cell in the given column and row was sampled, or none if
+    /// the cell is out of bounds.
+    pub fn center(&self, column: usize, row: usize) -> Option<Point<T>> {
+        if column >= self.columns || row >= self.rows() {
+            return None;
+        }
+
+        let half = T::from(0.5)?;
+        Some(Point {
+            x: self.min.x + self.step.x * (T::from(column)? + half),
+            y: self.min.y + self.step.y * (T::from(row)? + half),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Shape, Tolerance};
+
+    #[test]
+    fn winding_grid_exposes_overlap_multiplicity() {
+        struct Test {
+            name: &'static str,
+            column: usize,
+            row: usize,
+            want: isize,
+        }
+
+        let shape: Shape<Polygon<f64>> = Shape {
+            boundaries: vec![
+                vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]].into(),
+                vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]].into(),
+            ],
+        };
+
+        let grid = shape
+            .winding_grid([0., 0.], [6., 6.], [6, 6], &Tolerance::default())
+            .expect("the grid must exist");
+
+        assert_eq!(grid.columns(), 6);
+        assert_eq!(grid.rows(), 6);
+
+        vec![
+            Test {
+                name: "region covered by a single boundary",
+                column: 0,
+                row: 0,
+                want: 1,
+            },
+            Test {
+                name: "region covered by both boundaries",
+                column: 3,
+                row: 3,
+                want: 2,
+            },
+            Test {
+                name: "region covered by no boundary",
+                column: 5,
+                row: 0,
+                want: 0,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = grid.winding(test.column, test.row);
+            assert_eq!(got, Some(test.want), "{}", test.name);
+        });
+
+        assert_eq!(
+            grid.center(3, 3),
+            Some([3.5, 3.5].into()),
+            "cells must be sampled at their center"
+        );
+
+        assert_eq!(grid.winding(6, 0), None, "out of bounds must yield none");
+    }
+
+    #[test]
+    fn winding_grid_requires_a_window_and_a_resolution() {
+        let shape: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let tolerance = Tolerance::default();
+
+        assert!(
+            shape
+                .winding_grid([0., 0.], [4., 4.], [0, 4], &tolerance)
+                .is_none(),
+            "an empty resolution must yield no grid"
+        );
+
+        assert!(
+            shape
+                .winding_grid([4., 4.], [0., 0.], [4, 4], &tolerance)
+                .is_none(),
+            "an inverted window must yield no grid"
+        );
+    }
+}
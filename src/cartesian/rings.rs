@@ -0,0 +1,113 @@
+//! Shape assembly from unorganized ring soups.
+
+use num_traits::{Float, Signed};
+
+use crate::{
+    cartesian::{Point, Polygon},
+    Geometry, RightHanded, Shape, Tolerance,
+};
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float,
+{
+    /// Returns the shape assembled from the given unorganized rings, or none if no ring holds
+    /// at least three vertices.
+    ///
+    /// Data sources often provide rings with no nesting information and arbitrary orientations.
+    /// This constructor determines which rings are holes of which by containment: a ring inside
+    /// an even amount of others is a shell and one inside an odd amount is a hole, and each is
+    /// reoriented accordingly. The rings must not cross each other, and the first vertex of
+    /// each must not lie on another ring, since containment is decided by that vertex alone.
+    /// Rings with fewer than three vertices are discarded.
+    pub fn from_rings<P>(rings: Vec<Vec<P>>, tolerance: &Tolerance<T>) -> Option<Self>
+    where
+        P: Into<Point<T>>,
+    {
+        let rings: Vec<Polygon<T>> = rings
+            .into_iter()
+            .map(Polygon::from)
+            .filter(|ring| ring.vertices.len() > 2)
+            .collect();
+
+        let depths: Vec<usize> = (0..rings.len())
+            .map(|position| {
+                rings
+                    .iter()
+                    .enumerate()
+                    .filter(|(other_position, other)| {
+                        *other_position != position
+                            && other.contains(&rings[position].vertices[0], tolerance)
+                    })
+                    .count()
+            })
+            .collect();
+
+        let boundaries: Vec<_> = rings
+            .into_iter()
+            .zip(depths)
+            .map(|(ring, depth)| {
+                if (depth % 2 == 0) == ring.is_clockwise() {
+                    ring.reversed()
+                } else {
+                    ring
+                }
+            })
+            .collect();
+
+        (!boundaries.is_empty()).then_some(Self { boundaries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Shape, Tolerance};
+
+    #[test]
+    fn ring_soup_assembly_fixes_nesting_and_orientation() {
+        let got = Shape::<Polygon<f64>>::from_rings(
+            vec![
+                // A hole given counterclockwise.
+                vec![[1., 1.], [7., 1.], [7., 7.], [1., 7.]],
+                // An island inside the hole, given clockwise.
+                vec![[3., 3.], [3., 5.], [5., 5.], [5., 3.]],
+                // The shell, given counterclockwise.
+                vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]],
+            ],
+            &Tolerance::default(),
+        );
+
+        let want = Shape {
+            boundaries: vec![
+                vec![[1., 1.], [1., 7.], [7., 7.], [7., 1.]].into(),
+                vec![[3., 3.], [5., 3.], [5., 5.], [3., 5.]].into(),
+                vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+            ],
+        };
+
+        assert_eq!(got, Some(want), "nesting must decide each orientation");
+    }
+
+    #[test]
+    fn ring_soup_assembly_discards_degenerate_rings() {
+        let got = Shape::<Polygon<f64>>::from_rings(
+            vec![
+                vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]],
+                vec![[10., 10.], [12., 12.]],
+            ],
+            &Tolerance::default(),
+        );
+
+        assert_eq!(
+            got,
+            Some(Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]])),
+            "two-vertex rings must be discarded"
+        );
+
+        assert!(
+            Shape::<Polygon<f64>>::from_rings(vec![vec![[0., 0.], [4., 0.]]], &Tolerance::default())
+                .is_none(),
+            "a soup with no valid ring must yield no shape"
+        );
+    }
+}
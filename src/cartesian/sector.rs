@@ -0,0 +1,154 @@
+//! Sector clipping for cartesian shapes.
+
+use num_traits::{Float, FloatConst, Signed};
+
+use crate::{
+    cartesian::{Point, Polygon},
+    Shape, Tolerance,
+};
+
+/// The amount of straight segments approximating a full circular arc.
+const SECTOR_RESOLUTION: usize = 64;
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float + FloatConst + 'static,
+{
+    /// Returns the circular sector centered at the given point, swept counterclockwise from the
+    /// start angle to the end one, or none if the radius is not positive.
+    ///
+    /// The arc is approximated by straight segments at a fixed angular resolution. A span of
+    /// zero is interpreted as the full circle.
+    pub fn sector(center: Point<T>, start_angle: T, end_angle: T, radius: T) -> Option<Self> {
+        if !radius.is_positive() {
+            return None;
+        }
+
+        let tau = T::TAU();
+        let mut span = (end_angle - start_angle) % tau;
+        if span < T::zero() {
+            span = span + tau;
+        }
+        if span.is_zero() {
+            span = tau;
+        }
+
+        let amount = (span / tau * T::from(SECTOR_RESOLUTION)?).ceil().to_usize()?.max(2);
+        let steps = T::from(amount)?;
+        let full_turn = span == tau;
+
+        let mut vertices = Vec::with_capacity(amount + 2);
+        if !full_turn {
+            vertices.push(center);
+        }
+
+        // A full turn must not repeat its closing vertex.
+        let positions = if full_turn { amount } else { amount + 1 };
+        for position in 0..positions {
+            let angle = start_angle + span * T::from(position)? / steps;
+            let direction = Point {
+                x: angle.cos(),
+                y: angle.sin(),
+            };
+
+            vertices.push(center + direction * radius);
+        }
+
+        Some(Self {
+            boundaries: vec![Polygon { vertices }],
+        })
+    }
+
+    /// Returns the intersection of this shape with the circular sector described by the given
+    /// center, angles and radius.
+    pub fn clip_sector(
+        &self,
+        center: Point<T>,
+        start_angle: T,
+        end_angle: T,
+        radius: T,
+        tolerance: Tolerance<T>,
+    ) -> Option<Self> {
+        let sector = Self::sector(center, start_angle, end_angle, radius)?;
+        self.and_ref(&sector, tolerance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use crate::{cartesian::Polygon, Shape, Tolerance};
+
+    #[test]
+    fn clip_sector_masks_the_shape() {
+        struct Test {
+            name: &'static str,
+            center: [f64; 2],
+            start_angle: f64,
+            end_angle: f64,
+            radius: f64,
+            want: Option<f64>,
+        }
+
+        vec![
+            Test {
+                name: "sector covering the upper right corner",
+                center: [1., 1.],
+                start_angle: 0.,
+                end_angle: FRAC_PI_2,
+                radius: 10.,
+                want: Some(9.),
+            },
+            Test {
+                name: "sector inside the shape",
+                center: [1., 1.],
+                start_angle: 0.,
+                end_angle: FRAC_PI_2,
+                radius: 2.,
+                want: Some(PI),
+            },
+            Test {
+                name: "sector missing the shape",
+                center: [-2., -2.],
+                start_angle: PI,
+                end_angle: PI + FRAC_PI_2,
+                radius: 1.,
+                want: None,
+            },
+            Test {
+                name: "degenerate radius",
+                center: [1., 1.],
+                start_angle: 0.,
+                end_angle: FRAC_PI_2,
+                radius: 0.,
+                want: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let shape: Shape<Polygon<f64>> =
+                Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+
+            let got = shape
+                .clip_sector(
+                    test.center.into(),
+                    test.start_angle,
+                    test.end_angle,
+                    test.radius,
+                    Tolerance::default(),
+                )
+                .and_then(|clipped| clipped.stats())
+                .map(|stats| stats.area);
+
+            match (got, test.want) {
+                (Some(got), Some(want)) => assert!(
+                    (got - want).abs() < 0.01,
+                    "{}: got area = {got}, want {want}",
+                    test.name
+                ),
+                (got, want) => assert_eq!(got, want, "{}", test.name),
+            }
+        });
+    }
+}
@@ -164,6 +164,60 @@ mod tests {
         Edge,
     };
 
+    #[test]
+    fn segment_overlap() {
+        struct Test<'a> {
+            name: &'a str,
+            segment: Segment<'a, f64>,
+            other: Segment<'a, f64>,
+            want: Option<[Point<f64>; 2]>,
+        }
+
+        vec![
+            Test {
+                name: "collinear segments sharing a portion",
+                segment: Segment {
+                    from: &[0., 0.].into(),
+                    to: &[4., 4.].into(),
+                },
+                other: Segment {
+                    from: &[2., 2.].into(),
+                    to: &[8., 8.].into(),
+                },
+                want: Some([[2., 2.].into(), [4., 4.].into()]),
+            },
+            Test {
+                name: "crossing segments share a point, not a portion",
+                segment: Segment {
+                    from: &[0., 0.].into(),
+                    to: &[4., 4.].into(),
+                },
+                other: Segment {
+                    from: &[0., 4.].into(),
+                    to: &[4., 0.].into(),
+                },
+                want: None,
+            },
+            Test {
+                name: "collinear but disjoint segments",
+                segment: Segment {
+                    from: &[0., 0.].into(),
+                    to: &[2., 2.].into(),
+                },
+                other: Segment {
+                    from: &[4., 4.].into(),
+                    to: &[8., 8.].into(),
+                },
+                want: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = test.segment.overlap(&test.other, &Default::default());
+            assert_eq!(got, test.want, "{}", test.name);
+        });
+    }
+
     #[test]
     fn segment_intersection() {
         struct Test<'a> {
@@ -0,0 +1,456 @@
+//! Topology-aware simplification of shape collections.
+
+use std::{cmp::Ordering, collections::HashMap};
+
+use num_traits::{Float, Signed};
+
+use crate::{
+    cartesian::{Point, Polygon, VertexPool},
+    Shape, Vertex,
+};
+
+/// Simplifies every shape of the given collection, keeping shared borders identical.
+///
+/// Simplifying each shape on its own breaks tilings: the border between two adjacent shapes is
+/// simplified twice, against different surrounding context, leaving gaps and overlaps behind.
+/// This routine interns every vertex first, splits each boundary into chains at the vertices
+/// where the set of shapes sharing it changes, and simplifies each chain exactly once, so both
+/// sides of a shared border receive the very same simplified polyline.
+///
+/// Chains are simplified with the Ramer-Douglas-Peucker algorithm under the given deviation.
+/// Boundaries that would degenerate below three vertices are left untouched. As with
+/// [`VertexPool`], shared vertices must be bit-identical to be recognized as such.
+pub fn simplify_collection<T>(shapes: &[Shape<Polygon<T>>], deviation: T) -> Vec<Shape<Polygon<T>>>
+where
+    T: Signed + Float,
+{
+    let mut pool = VertexPool::new();
+    let ids: Vec<Vec<Vec<usize>>> = shapes
+        .iter()
+        .map(|shape| {
+            shape
+                .boundaries
+                .iter()
+                .map(|boundary| {
+                    boundary
+                        .vertices
+                        .iter()
+                        .map(|&vertex| pool.intern(vertex))
+                        .collect()
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut owners: Vec<Vec<usize>> = vec![Vec::new(); pool.len()];
+    for (shape, boundaries) in ids.iter().enumerate() {
+        for boundary in boundaries {
+            for &id in boundary {
+                let entry = &mut owners[id];
+                if entry.last() != Some(&shape) {
+                    entry.push(shape);
+                }
+            }
+        }
+    }
+
+    // Each chain is simplified once, in a canonical orientation, and reused by every boundary
+    // traversing it in either direction.
+    let mut cache: HashMap<Vec<usize>, Vec<usize>> = HashMap::new();
+    let mut simplify_chain = |chain: Vec<usize>| -> Vec<usize> {
+        let reversed: Vec<usize> = chain.iter().rev().copied().collect();
+        let (canonical, forward) = if chain <= reversed {
+            (chain, true)
+        } else {
+            (reversed, false)
+        };
+
+        let simplified = cache
+            .entry(canonical.clone())
+            .or_insert_with(|| {
+                let points: Vec<Point<T>> = canonical
+                    .iter()
+                    .filter_map(|&id| pool.get(id).copied())
+                    .collect();
+
+                kept_positions(&points, deviation)
+                    .into_iter()
+                    .map(|position| canonical[position])
+                    .collect()
+            })
+            .clone();
+
+        if forward {
+            simplified
+        } else {
+            simplified.into_iter().rev().collect()
+        }
+    };
+
+    let mut output = Vec::with_capacity(shapes.len());
+    for (shape, boundaries) in shapes.iter().zip(&ids) {
+        let mut simplified_boundaries = Vec::with_capacity(boundaries.len());
+        for (boundary, ring) in shape.boundaries.iter().zip(boundaries) {
+            let len = ring.len();
+            if len < 4 {
+                simplified_boundaries.push(boundary.clone());
+                continue;
+            }
+
+            let is_junction = |position: usize| {
+                let previous = ring[(position + len - 1) % len];
+                let next = ring[(position + 1) % len];
+
+                owners[ring[position]] != owners[previous] || owners[ring[position]] != owners[next]
+            };
+
+            let junctions: Vec<usize> = (0..len).filter(|&position| is_junction(position)).collect();
+
+            let simplified = if junctions.is_empty() {
+                // An unshared ring has no mandatory vertices: simplify it as a single closed
+                // chain anchored at its smallest vertex, so the outcome does not depend on
+                // where the ring happens to start.
+                let start = ring
+                    .iter()
+                    .enumerate()
+                    .min_by(|&(_, &a), &(_, &b)| {
+                        pool.get(a)
+                            .zip(pool.get(b))
+                            .map(|(a, b)| {
+                                (a.x, a.y)
+                                    .partial_cmp(&(b.x, b.y))
+                                    .unwrap_or(Ordering::Equal)
+                            })
+                            .unwrap_or(Ordering::Equal)
+                    })
+                    .map(|(position, _)| position)
+                    .unwrap_or_default();
+
+                let chain = (0..=len).map(|step| ring[(start + step) % len]).collect();
+                let mut simplified = simplify_chain(chain);
+                simplified.pop();
+                simplified
+            } else {
+                let mut simplified = Vec::with_capacity(len);
+                for (position, &junction) in junctions.iter().enumerate() {
+                    let next = junctions[(position + 1) % junctions.len()];
+                    let span = match (next + len - junction) % len {
+                        0 => len,
+                        span => span,
+                    };
+
+                    let chain = (0..=span).map(|step| ring[(junction + step) % len]).collect();
+                    let mut kept = simplify_chain(chain);
+                    kept.pop();
+                    simplified.extend(kept);
+                }
+
+                simplified
+            };
+
+            if simplified.len() < 3 {
+                simplified_boundaries.push(boundary.clone());
+                continue;
+            }
+
+            simplified_boundaries.push(Polygon {
+                vertices: simplified
+                    .iter()
+                    .filter_map(|&id| pool.get(id).copied())
+                    .collect(),
+            });
+        }
+
+        output.push(Shape {
+            boundaries: simplified_boundaries,
+        });
+    }
+
+    output
+}
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float,
+{
+    /// Returns this shape simplified at each of the given deviations, one level per entry.
+    ///
+    /// All levels share a single simplification pass: each vertex is ranked once by the
+    /// deviation below which the Ramer-Douglas-Peucker algorithm would keep it, and every level
+    /// merely filters that ranking. Besides being cheaper than simplifying from scratch per
+    /// zoom, this makes the levels nested: every vertex of a coarser level also belongs to
+    /// every finer one, so transitions between zooms do not flicker.
+    ///
+    /// Boundaries that would degenerate below three vertices at some level are left untouched
+    /// at that level, as in [`simplify_collection`].
+    pub fn lod_pyramid(&self, deviations: &[T]) -> Vec<Self> {
+        let rankings: Vec<Vec<T>> = self
+            .boundaries
+            .iter()
+            .map(|boundary| {
+                let vertices = &boundary.vertices;
+                let len = vertices.len();
+                if len < 4 {
+                    return vec![T::infinity(); len];
+                }
+
+                // As with lone rings in [`simplify_collection`], the chain is anchored at the
+                // smallest vertex so the outcome does not depend on where the ring starts.
+                let start = vertices
+                    .iter()
+                    .enumerate()
+                    .min_by(|&(_, a), &(_, b)| {
+                        (a.x, a.y)
+                            .partial_cmp(&(b.x, b.y))
+                            .unwrap_or(Ordering::Equal)
+                    })
+                    .map(|(position, _)| position)
+                    .unwrap_or_default();
+
+                let chain: Vec<Point<T>> = (0..=len)
+                    .map(|step| vertices[(start + step) % len])
+                    .collect();
+
+                let ranked = significances(&chain);
+                (0..len)
+                    .map(|position| ranked[(position + len - start) % len])
+                    .collect()
+            })
+            .collect();
+
+        deviations
+            .iter()
+            .map(|&deviation| Self {
+                boundaries: self
+                    .boundaries
+                    .iter()
+                    .zip(&rankings)
+                    .map(|(boundary, ranking)| {
+                        let vertices: Vec<Point<T>> = boundary
+                            .vertices
+                            .iter()
+                            .zip(ranking)
+                            .filter_map(|(&vertex, &significance)| {
+                                (significance > deviation).then_some(vertex)
+                            })
+                            .collect();
+
+                        if vertices.len() < 3 {
+                            boundary.clone()
+                        } else {
+                            Polygon { vertices }
+                        }
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+/// Returns, for each point of the given chain, the deviation below which the
+/// Ramer-Douglas-Peucker algorithm keeps it, endpoints ranking unconditionally.
+///
+/// Each rank is capped by its parent's, so filtering by any deviation yields a valid
+/// simplification and higher deviations yield subsets of lower ones.
+fn significances<T>(points: &[Point<T>]) -> Vec<T>
+where
+    T: Float,
+{
+    let mut significance = vec![T::infinity(); points.len()];
+    if points.len() < 3 {
+        return significance;
+    }
+
+    let mut pending = vec![(0, points.len() - 1, T::infinity())];
+    while let Some((start, end, cap)) = pending.pop() {
+        if end <= start + 1 {
+            continue;
+        }
+
+        let mut farthest = start;
+        let mut max = T::zero();
+        for position in start + 1..end {
+            let distance = distance_to_segment(&points[position], &points[start], &points[end]);
+            if distance > max {
+                max = distance;
+                farthest = position;
+            }
+        }
+
+        let rank = max.min(cap);
+        significance[farthest] = rank;
+        pending.push((start, farthest, rank));
+        pending.push((farthest, end, rank));
+    }
+
+    significance
+}
+
+/// Returns the positions of the points kept by the Ramer-Douglas-Peucker algorithm, endpoints
+/// included.
+fn kept_positions<T>(points: &[Point<T>], deviation: T) -> Vec<usize>
+where
+    T: Float,
+{
+    if points.len() < 3 {
+        return (0..points.len()).collect();
+    }
+
+    let mut kept = vec![false; points.len()];
+    kept[0] = true;
+    kept[points.len() - 1] = true;
+
+    let mut pending = vec![(0, points.len() - 1)];
+    while let Some((start, end)) = pending.pop() {
+        if end <= start + 1 {
+            continue;
+        }
+
+        let mut farthest = start;
+        let mut max = T::zero();
+        for position in start + 1..end {
+            let distance = distance_to_segment(&points[position], &points[start], &points[end]);
+            if distance > max {
+                max = distance;
+                farthest = position;
+            }
+        }
+
+        if max > deviation {
+            kept[farthest] = true;
+            pending.push((start, farthest));
+            pending.push((farthest, end));
+        }
+    }
+
+    kept.iter()
+        .enumerate()
+        .filter_map(|(position, &keep)| keep.then_some(position))
+        .collect()
+}
+
+/// Returns the distance from the point to the segment between the given endpoints.
+fn distance_to_segment<T>(point: &Point<T>, from: &Point<T>, to: &Point<T>) -> T
+where
+    T: Float,
+{
+    let dx = to.x - from.x;
+    let dy = to.y - from.y;
+
+    let length_squared = dx * dx + dy * dy;
+    if length_squared.is_zero() {
+        return point.distance(from);
+    }
+
+    let along = ((point.x - from.x) * dx + (point.y - from.y) * dy) / length_squared;
+    let along = along.max(T::zero()).min(T::one());
+
+    point.distance(&Point {
+        x: from.x + dx * along,
+        y: from.y + dy * along,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Shape};
+
+    use super::simplify_collection;
+
+    #[test]
+    fn shared_borders_simplify_identically() {
+        struct Test {
+            name: &'static str,
+            deviation: f64,
+            want: Vec<Shape<Polygon<f64>>>,
+        }
+
+        let left: Shape<Polygon<f64>> =
+            Shape::new(vec![[0., 0.], [4., 0.], [4.05, 2.], [4., 4.], [0., 4.]]);
+        let right: Shape<Polygon<f64>> =
+            Shape::new(vec![[4., 0.], [8., 0.], [8., 4.], [4., 4.], [4.05, 2.]]);
+
+        vec![
+            Test {
+                name: "deviation above the border bump straightens both sides",
+                deviation: 0.1,
+                want: vec![
+                    Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                    Shape::new(vec![[4., 0.], [8., 0.], [8., 4.], [4., 4.]]),
+                ],
+            },
+            Test {
+                name: "deviation below the border bump keeps both sides",
+                deviation: 0.01,
+                want: vec![left.clone(), right.clone()],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = simplify_collection(&[left.clone(), right.clone()], test.deviation);
+            assert_eq!(got, test.want, "{}", test.name);
+        });
+    }
+
+    #[test]
+    fn lod_pyramid_levels_are_nested() {
+        let shape: Shape<Polygon<f64>> = Shape::new(vec![
+            [0., 0.],
+            [2., 0.05],
+            [4., 0.],
+            [4., 2.],
+            [4.5, 2.],
+            [4., 2.5],
+            [4., 4.],
+            [0., 4.],
+        ]);
+
+        let levels = shape.lod_pyramid(&[0.01, 0.1, 1.]);
+
+        assert_eq!(
+            levels[0], shape,
+            "a deviation below every detail must keep the shape as is"
+        );
+        assert_eq!(
+            levels[1],
+            Shape::new(vec![[0., 0.], [4., 0.], [4., 2.], [4.5, 2.], [4., 2.5], [4., 4.], [0., 4.]]),
+            "an intermediate deviation must drop the bump but keep the spur"
+        );
+        assert_eq!(
+            levels[2],
+            Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+            "a coarse deviation must drop every detail"
+        );
+
+        for (fine, coarse) in levels.iter().zip(levels.iter().skip(1)) {
+            for (fine, coarse) in fine.boundaries.iter().zip(&coarse.boundaries) {
+                assert!(
+                    coarse.vertices.iter().all(|vertex| fine.vertices.contains(vertex)),
+                    "every vertex of a coarser level must also belong to the finer one"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn lone_shapes_simplify_deterministically() {
+        let shape: Shape<Polygon<f64>> =
+            Shape::new(vec![[0., 0.], [2., 0.05], [4., 0.], [4., 4.], [0., 4.]]);
+        let rotated: Shape<Polygon<f64>> =
+            Shape::new(vec![[4., 4.], [0., 4.], [0., 0.], [2., 0.05], [4., 0.]]);
+
+        let got = simplify_collection(&[shape], 0.1);
+        let from_rotated = simplify_collection(&[rotated], 0.1);
+
+        assert_eq!(
+            got, from_rotated,
+            "the outcome must not depend on where the ring starts"
+        );
+
+        assert_eq!(
+            got[0],
+            Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+            "the bump must be simplified away"
+        );
+    }
+}
@@ -0,0 +1,113 @@
+//! Summary statistics for cartesian shapes.
+
+use num_traits::{Float, Signed};
+
+use crate::{
+    cartesian::{Point, Polygon},
+    Geometry, RightHanded, Shape, Vertex,
+};
+
+/// A summary of the measurable properties of a [`Shape`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats<T> {
+    /// The total amount of vertices in the shape.
+    pub vertices: usize,
+    /// The total amount of boundaries in the shape.
+    pub boundaries: usize,
+    /// The amount of boundaries delimiting a hole.
+    pub holes: usize,
+    /// The area of the filled region of the shape: holes subtract from it.
+    pub area: T,
+    /// The total length of all the boundaries in the shape.
+    pub perimeter: T,
+    /// The lower-left corner of the bounding box of the shape.
+    pub min: Point<T>,
+    /// The upper-right corner of the bounding box of the shape.
+    pub max: Point<T>,
+}
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float,
+{
+    /// Returns the summary [`Stats`] of this shape, or none if the shape has no vertices.
+    pub fn stats(&self) -> Option<Stats<T>> {
+        let mut vertices = self
+            .boundaries
+            .iter()
+            .flat_map(|boundary| boundary.vertices.iter());
+
+        let &first = vertices.next()?;
+        let (min, max) = vertices.fold((first, first), |(min, max), point| {
+            (
+                Point {
+                    x: min.x.min(point.x),
+                    y: min.y.min(point.y),
+                },
+                Point {
+                    x: max.x.max(point.x),
+                    y: max.y.max(point.y),
+                },
+            )
+        });
+
+        let (area, perimeter) =
+            self.boundaries
+                .iter()
+                .fold((T::zero(), T::zero()), |(area, perimeter), boundary| {
+                    let signed = if boundary.is_clockwise() {
+                        -boundary.area()
+                    } else {
+                        boundary.area()
+                    };
+
+                    let length = boundary
+                        .edges()
+                        .fold(T::zero(), |sum, segment| sum + segment.from.distance(segment.to));
+
+                    (area + signed, perimeter + length)
+                });
+
+        Some(Stats {
+            vertices: self.total_vertices(),
+            boundaries: self.boundaries.len(),
+            holes: self
+                .boundaries
+                .iter()
+                .filter(|boundary| boundary.is_clockwise())
+                .count(),
+            area,
+            perimeter,
+            min,
+            max,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Shape};
+
+    #[test]
+    fn shape_statistics() {
+        let shape: Shape<Polygon<f64>> = Shape {
+            boundaries: vec![
+                vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                vec![[2., 2.], [2., 6.], [6., 6.], [6., 2.]].into(),
+            ],
+        };
+
+        let stats = shape.stats().expect("shape with vertices must have stats");
+
+        assert_eq!(stats.vertices, 8);
+        assert_eq!(stats.boundaries, 2);
+        assert_eq!(stats.holes, 1);
+        assert_eq!(stats.area, 48.);
+        assert_eq!(stats.perimeter, 48.);
+        assert_eq!(stats.min, [0., 0.].into());
+        assert_eq!(stats.max, [8., 8.].into());
+
+        let empty: Shape<Polygon<f64>> = Shape { boundaries: vec![] };
+        assert_eq!(empty.stats(), None);
+    }
+}
@@ -0,0 +1,194 @@
+//! Seam welding for cartesian shapes sharing exact borders.
+
+use num_traits::{Float, Signed};
+
+use crate::{
+    cartesian::{Point, Polygon},
+    Geometry, IsClose, Shape, Tolerance,
+};
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float,
+{
+    /// Returns this shape and the other merged by welding their matching edge chains.
+    ///
+    /// Stitching assumes both shapes share their borders exactly, as the pieces of a tiled
+    /// operation do: wherever an edge of one shape runs opposite to a coincident edge of the
+    /// other, both edges are removed and their boundaries spliced into one. This is far cheaper
+    /// than a general union and introduces no tolerance artifacts along the seam, but it does
+    /// not resolve overlapping interiors; shapes that properly overlap must go through
+    /// [`Self::or`] instead. Boundaries sharing no edge are kept side by side, and boundaries
+    /// welded away entirely are dropped; none remaining yields no shape.
+    pub fn stitch(&self, other: &Self, tolerance: &Tolerance<T>) -> Option<Self> {
+        let mut boundaries: Vec<Polygon<T>> = self
+            .boundaries
+            .iter()
+            .chain(other.boundaries.iter())
+            .cloned()
+            .collect();
+
+        while let Some((first, second, i, j)) = shared_edge(&boundaries, tolerance) {
+            let second = boundaries.swap_remove(second);
+            let first = &mut boundaries[first];
+            *first = weld(first, &second, i, j);
+        }
+
+        let boundaries: Vec<_> = boundaries
+            .into_iter()
+            .filter_map(|boundary| despiked(boundary, tolerance))
+            .collect();
+
+        (!boundaries.is_empty()).then_some(Self { boundaries })
+    }
+}
+
+/// Returns the positions of two distinct boundaries holding a pair of coincident opposite
+/// edges, along with the indices of those edges, if any.
+fn shared_edge<T>(
+    boundaries: &[Polygon<T>],
+    tolerance: &Tolerance<T>,
+) -> Option<(usize, usize, usize, usize)>
+where
+    T: Signed + Float,
+{
+    boundaries.iter().enumerate().find_map(|(first, a)| {
+        boundaries
+            .iter()
+            .enumerate()
+            .skip(first + 1)
+            .find_map(|(second, b)| {
+                let n = a.vertices.len();
+                let m = b.vertices.len();
+                (0..n).find_map(|i| {
+                    (0..m)
+                        .find(|&j| {
+                            a.vertices[i].is_close(&b.vertices[(j + 1) % m], tolerance)
+                                && a.vertices[(i + 1) % n].is_close(&b.vertices[j], tolerance)
+                        })
+                        .map(|j| (first, second, i, j))
+                })
+            })
+    })
+}
+
+/// Returns the boundary traversing the first one up to the shared edge, the second one all the
+/// way around it, and the first one back, dropping the shared edge itself.
+fn weld<T>(a: &Polygon<T>, b: &Polygon<T>, i: usize, j: usize) -> Polygon<T>
+where
+    T: Copy,
+{
+    let m = b.vertices.len();
+    let mut vertices: Vec<Point<T>> = Vec::with_capacity(a.vertices.len() + m - 2);
+    vertices.extend_from_slice(&a.vertices[..=i]);
+    vertices.extend((0..m - 2).map(|k| b.vertices[(j + 2 + k) % m]));
+    vertices.extend_from_slice(&a.vertices[i + 1..]);
+
+    Polygon { vertices }
+}
+
+/// Returns the boundary with every zero-width spike left behind by welding a longer shared
+/// chain collapsed, or none if fewer than three vertices survive.
+fn despiked<T>(mut polygon: Polygon<T>, tolerance: &Tolerance<T>) -> Option<Polygon<T>>
+where
+    T: Signed + Float,
+{
+    loop {
+        let len = polygon.vertices.len();
+        if len < 3 {
+            return None;
+        }
+
+        let Some(spike) = (0..len).find(|&index| {
+            polygon.vertices[(index + len - 1) % len]
+                .is_close(&polygon.vertices[(index + 1) % len], tolerance)
+        }) else {
+            break;
+        };
+
+        polygon.vertices.remove(spike);
+    }
+
+    let polygon = polygon.deduped(tolerance);
+    (polygon.vertices.len() > 2).then_some(polygon)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Shape, Tolerance};
+
+    #[test]
+    fn stitching_welds_shared_borders() {
+        struct Test {
+            name: &'static str,
+            subject: Shape<Polygon<f64>>,
+            other: Shape<Polygon<f64>>,
+            want_boundaries: usize,
+            want_area: f64,
+        }
+
+        vec![
+            Test {
+                name: "squares sharing a single edge",
+                subject: Shape::new(vec![[0., 0.], [2., 0.], [2., 2.], [0., 2.]]),
+                other: Shape::new(vec![[2., 0.], [4., 0.], [4., 2.], [2., 2.]]),
+                want_boundaries: 1,
+                want_area: 8.,
+            },
+            Test {
+                name: "rectangles sharing a subdivided border",
+                subject: Shape::new(vec![[0., 0.], [4., 0.], [4., 2.], [2., 2.], [0., 2.]]),
+                other: Shape::new(vec![[0., 2.], [2., 2.], [4., 2.], [4., 4.], [0., 4.]]),
+                want_boundaries: 1,
+                want_area: 16.,
+            },
+            Test {
+                name: "disjoint shapes are kept side by side",
+                subject: Shape::new(vec![[0., 0.], [2., 0.], [2., 2.], [0., 2.]]),
+                other: Shape::new(vec![[4., 0.], [6., 0.], [6., 2.], [4., 2.]]),
+                want_boundaries: 2,
+                want_area: 8.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = test
+                .subject
+                .stitch(&test.other, &Tolerance::default())
+                .expect("the stitched shape must exist");
+
+            let stats = got.stats().expect("the stitched shape must have vertices");
+            assert_eq!(stats.boundaries, test.want_boundaries, "{}", test.name);
+            assert!(
+                (stats.area - test.want_area).abs() < 1e-9,
+                "{}: expected area {}, got {}",
+                test.name,
+                test.want_area,
+                stats.area
+            );
+        });
+    }
+
+    #[test]
+    fn stitching_matches_the_union_of_tangential_shapes() {
+        let subject: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [2., 0.], [2., 2.], [0., 2.]]);
+        let other: Shape<Polygon<f64>> = Shape::new(vec![[2., 0.], [4., 0.], [4., 2.], [2., 2.]]);
+
+        let got = subject
+            .stitch(&other, &Tolerance::default())
+            .expect("the stitched shape must exist");
+
+        assert_eq!(
+            got,
+            Shape::new(vec![
+                [0., 0.],
+                [2., 0.],
+                [4., 0.],
+                [4., 2.],
+                [2., 2.],
+                [0., 2.]
+            ]),
+            "the weld must keep the seam endpoints as collinear vertices"
+        );
+    }
+}
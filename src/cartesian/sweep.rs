@@ -0,0 +1,138 @@
+//! Sweep-based self-intersection detection for cartesian polygons.
+
+use std::cmp::Ordering;
+
+use num_traits::{Float, Signed};
+
+use crate::{
+    cartesian::{Point, Polygon, Segment},
+    Edge, Either, IsClose, Tolerance,
+};
+
+impl<T> Polygon<T>
+where
+    T: Signed + Float,
+{
+    /// Returns every point where this polygon crosses itself.
+    ///
+    /// Edges are swept along the x axis, so only pairs overlapping horizontally are tested
+    /// instead of every pair. Consecutive edges are not reported, since sharing an endpoint is
+    /// how a boundary closes; any other pair contact is, including revisited vertices and
+    /// coincident edge runs, whose endpoints locate the run. Each crossing point is reported
+    /// once.
+    pub fn self_intersections(&self, tolerance: &Tolerance<T>) -> Vec<Point<T>> {
+        let len = self.vertices.len();
+        let edges: Vec<(Point<T>, Point<T>)> = (0..len)
+            .map(|position| (self.vertices[position], self.vertices[(position + 1) % len]))
+            .collect();
+
+        let mut order: Vec<usize> = (0..edges.len()).collect();
+        let min_x = |position: usize| edges[position].0.x.min(edges[position].1.x);
+        let max_x = |position: usize| edges[position].0.x.max(edges[position].1.x);
+        order.sort_by(|&a, &b| {
+            min_x(a)
+                .partial_cmp(&min_x(b))
+                .unwrap_or(Ordering::Equal)
+        });
+
+        let mut crossings: Vec<Point<T>> = Vec::new();
+        let mut register = |point: Point<T>| {
+            if !crossings
+                .iter()
+                .any(|crossing| crossing.is_close(&point, tolerance))
+            {
+                crossings.push(point);
+            }
+        };
+
+        for (sweep, &position) in order.iter().enumerate() {
+            for &other_position in &order[sweep + 1..] {
+                if min_x(other_position) > max_x(position) {
+                    break;
+                }
+
+                let adjacent = (position + 1) % len == other_position
+                    || (other_position + 1) % len == position;
+                if adjacent {
+                    continue;
+                }
+
+                let (from, to) = &edges[position];
+                let (other_from, other_to) = &edges[other_position];
+                let intersection = Segment::new(from, to)
+                    .intersection(&Segment::new(other_from, other_to), tolerance);
+
+                match intersection {
+                    Some(Either::Left(point)) => register(point),
+                    Some(Either::Right([start, end])) => {
+                        register(start);
+                        register(end);
+                    }
+                    None => (),
+                }
+            }
+        }
+
+        crossings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cartesian::{Point, Polygon},
+        Tolerance,
+    };
+
+    #[test]
+    fn self_intersections_locate_every_crossing() {
+        struct Test {
+            name: &'static str,
+            polygon: Polygon<f64>,
+            want: Vec<Point<f64>>,
+        }
+
+        vec![
+            Test {
+                name: "a simple square does not cross itself",
+                polygon: vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]].into(),
+                want: Vec::new(),
+            },
+            Test {
+                name: "a bowtie crosses itself once",
+                polygon: vec![[0., 0.], [4., 4.], [4., 0.], [0., 4.]].into(),
+                want: vec![[2., 2.].into()],
+            },
+            Test {
+                name: "revisited vertices are reported",
+                polygon: vec![
+                    [-1., 0.],
+                    [-1., -1.],
+                    [1., -1.],
+                    [1., 0.],
+                    [-1., 0.],
+                    [-1., 1.],
+                    [1., 1.],
+                    [1., 0.],
+                ]
+                .into(),
+                want: vec![[-1., 0.].into(), [1., 0.].into()],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = test.polygon.self_intersections(&Tolerance::default());
+
+            assert_eq!(got.len(), test.want.len(), "{}", test.name);
+            for point in &test.want {
+                assert!(
+                    got.contains(point),
+                    "{}: the crossing at ({}, {}) must be reported",
+                    test.name,
+                    point.x,
+                    point.y,
+                );
+            }
+        });
+    }
+}
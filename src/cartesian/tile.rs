@@ -0,0 +1,162 @@
+//! Tiled clipping for cartesian shapes.
+
+use num_traits::{Float, Signed};
+
+use crate::{
+    cartesian::{Point, Polygon},
+    Shape, Tolerance,
+};
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float + 'static,
+{
+    /// Returns the union of this shape and the other, computed tile by tile.
+    ///
+    /// The bounding box of both operands is split into the given amount of columns and rows,
+    /// each operand is clipped to every tile, and the per-tile unions are collected into a
+    /// single shape. Peak memory is bounded by the largest tile instead of by the whole
+    /// operands, at the cost of the tile borders remaining as seams: boundaries of adjacent
+    /// tiles share edges without being merged, since dissolving them would require clipping
+    /// coincident collinear edges. The filled region, and hence any winding or area query, is
+    /// nonetheless the same as that of [`Self::or`]. Operand edges running exactly along a tile
+    /// border degrade like any other coincident pair; the outermost borders are padded clear of
+    /// the operands. A zero resolution yields no shape.
+    ///
+    /// Each operand is clipped against a slightly different enlargement of the tile before the
+    /// in-tile union, so the pieces never share edges along the tile border, which would
+    /// otherwise be a coincident collinear pair. The union is trimmed back to the exact tile
+    /// afterwards.
+    pub fn or_tiled(
+        &self,
+        other: &Self,
+        resolution: [usize; 2],
+        tolerance: &Tolerance<T>,
+    ) -> Option<Self> {
+        let [columns, rows] = resolution;
+        if columns == 0 || rows == 0 {
+            return None;
+        }
+
+        let (self_stats, other_stats) = (self.stats()?, other.stats()?);
+        let (min, max) = (
+            Point {
+                x: self_stats.min.x.min(other_stats.min.x),
+                y: self_stats.min.y.min(other_stats.min.y),
+            },
+            Point {
+                x: self_stats.max.x.max(other_stats.max.x),
+                y: self_stats.max.y.max(other_stats.max.y),
+            },
+        );
+
+        // Padding the box keeps the outermost tile borders clear of the operands, whose edges
+        // would otherwise coincide with them.
+        let padding = (max - min) * T::from(1e-3)?;
+        let (min, max) = (min - padding, max + padding);
+        let step = Point {
+            x: (max.x - min.x) / T::from(columns)?,
+            y: (max.y - min.y) / T::from(rows)?,
+        };
+
+        let mut boundaries = Vec::new();
+        for row in 0..rows {
+            for column in 0..columns {
+                let corner = Point {
+                    x: min.x + step.x * T::from(column)?,
+                    y: min.y + step.y * T::from(row)?,
+                };
+
+                let tile = rectangle(corner, step, T::zero());
+                let enlarged = [
+                    rectangle(corner, step, T::from(0.125)?),
+                    rectangle(corner, step, T::from(0.25)?),
+                ];
+
+                let piece = match (
+                    self.and_ref(&enlarged[0], *tolerance),
+                    other.and_ref(&enlarged[1], *tolerance),
+                ) {
+                    (Some(subject), Some(clip)) => subject.or(clip, *tolerance),
+                    (piece, None) | (None, piece) => piece,
+                };
+
+                if let Some(piece) = piece.and_then(|piece| piece.and_ref(&tile, *tolerance)) {
+                    boundaries.extend(piece.boundaries);
+                }
+            }
+        }
+
+        (!boundaries.is_empty()).then_some(Self { boundaries })
+    }
+}
+
+/// Returns the axis-aligned rectangle at the given corner, enlarged on every side by the given
+/// fraction of the step.
+fn rectangle<T>(corner: Point<T>, step: Point<T>, margin: T) -> Shape<Polygon<T>>
+where
+    T: Signed + Float,
+{
+    let margin = Point {
+        x: step.x * margin,
+        y: step.y * margin,
+    };
+
+    let (min, max) = (corner - margin, corner + step + margin);
+    Shape::new(vec![
+        min,
+        Point { x: max.x, y: min.y },
+        max,
+        Point { x: min.x, y: max.y },
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Shape, Tolerance};
+
+    #[test]
+    fn tiled_union_covers_the_same_region() {
+        let subject: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip: Shape<Polygon<f64>> = Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]);
+
+        let tolerance = Tolerance::default();
+        let got = subject
+            .or_tiled(&clip, [2, 2], &tolerance)
+            .expect("the tiled union must exist");
+
+        let stats = got.stats().expect("the tiled union must have vertices");
+        assert!(
+            (stats.area - 28.).abs() < 1e-9,
+            "the per-tile pieces must add up to the area of the union, got {}",
+            stats.area
+        );
+
+        assert_ne!(
+            got.winding(&[1., 1.].into(), &tolerance),
+            0,
+            "points of the subject must remain covered"
+        );
+        assert_ne!(
+            got.winding(&[5., 5.].into(), &tolerance),
+            0,
+            "points of the clip must remain covered"
+        );
+        assert_eq!(
+            got.winding(&[5., 1.].into(), &tolerance),
+            0,
+            "points outside both operands must remain uncovered"
+        );
+    }
+
+    #[test]
+    fn tiled_union_requires_a_resolution() {
+        let subject: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip = subject.clone();
+
+        assert!(
+            subject.or_tiled(&clip, [0, 2], &Tolerance::default()).is_none(),
+            "an empty resolution must yield no shape"
+        );
+    }
+}
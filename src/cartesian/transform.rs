@@ -0,0 +1,200 @@
+//! Scalar convenience transforms for cartesian shapes.
+
+use num_traits::{Float, Signed};
+
+use crate::{
+    cartesian::{Point, Polygon},
+    Geometry, RightHanded, Shape, Tolerance,
+};
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float,
+{
+    /// Returns this shape scaled by the given factor about its area-weighted centroid.
+    ///
+    /// The shape is left untouched if it encloses no area, since its centroid is then
+    /// undefined. A negative factor mirrors the shape through the centroid, flipping the
+    /// orientation of every boundary.
+    pub fn inflate_about_centroid(self, factor: T) -> Self {
+        let Some(centroid) = centroid(&self.boundaries) else {
+            return self;
+        };
+
+        self.transform_coords(|vertex| centroid + (vertex - centroid) * factor)
+    }
+
+    /// Returns this shape with each of its components translated by the given offset away from
+    /// the centroid of the whole, as in an exploded view.
+    ///
+    /// A component is a shell together with the holes it contains; each moves rigidly along
+    /// the direction from the shape's centroid to its own. Components whose centroid coincides
+    /// with the shape's, and in particular any shape with a single component, are left in
+    /// place. The given tolerance decides which shell contains each hole.
+    pub fn explode(self, offset: T, tolerance: &Tolerance<T>) -> Self {
+        let Some(whole) = centroid(&self.boundaries) else {
+            return self;
+        };
+
+        // Each hole travels with the innermost shell containing it.
+        let component: Vec<usize> = self
+            .boundaries
+            .iter()
+            .enumerate()
+            .map(|(position, boundary)| {
+                if !boundary.is_clockwise() {
+                    return position;
+                }
+
+                self.boundaries
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, shell)| {
+                        !shell.is_clockwise() && shell.contains(&boundary.vertices[0], tolerance)
+                    })
+                    .min_by(|(_, a), (_, b)| {
+                        a.area()
+                            .abs()
+                            .partial_cmp(&b.area().abs())
+                            .unwrap_or(std::cmp::Ordering::Equal)
+                    })
+                    .map(|(shell, _)| shell)
+                    .unwrap_or(position)
+            })
+            .collect();
+
+        let displacements: Vec<Point<T>> = (0..self.boundaries.len())
+            .map(|shell| {
+                let members: Vec<Polygon<T>> = self
+                    .boundaries
+                    .iter()
+                    .zip(&component)
+                    .filter(|&(_, &owner)| owner == shell)
+                    .map(|(boundary, _)| boundary.clone())
+                    .collect();
+
+                let Some(own) = centroid(&members) else {
+                    return Point {
+                        x: T::zero(),
+                        y: T::zero(),
+                    };
+                };
+
+                let direction = own - whole;
+                let distance = (direction.x * direction.x + direction.y * direction.y).sqrt();
+                if distance.is_zero() {
+                    return Point {
+                        x: T::zero(),
+                        y: T::zero(),
+                    };
+                }
+
+                direction * (offset / distance)
+            })
+            .collect();
+
+        Self {
+            boundaries: self
+                .boundaries
+                .into_iter()
+                .zip(&component)
+                .map(|(boundary, &owner)| {
+                    let displacement = displacements[owner];
+                    boundary.map(&mut |vertex| vertex + displacement)
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Returns the area-weighted centroid of the given boundaries, where holes subtract from the
+/// weight, or none if they enclose no area.
+fn centroid<T>(boundaries: &[Polygon<T>]) -> Option<Point<T>>
+where
+    T: Signed + Float,
+{
+    let (doubled_area, x, y) = boundaries
+        .iter()
+        .flat_map(|boundary| {
+            boundary
+                .vertices
+                .iter()
+                .zip(boundary.vertices.iter().cycle().skip(1))
+        })
+        .fold(
+            (T::zero(), T::zero(), T::zero()),
+            |(doubled_area, x, y), (from, to)| {
+                let cross = from.x * to.y - to.x * from.y;
+                (
+                    doubled_area + cross,
+                    x + (from.x + to.x) * cross,
+                    y + (from.y + to.y) * cross,
+                )
+            },
+        );
+
+    if doubled_area.is_zero() {
+        return None;
+    }
+
+    let denominator = T::from(3)? * doubled_area;
+    Some(Point {
+        x: x / denominator,
+        y: y / denominator,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Shape, Tolerance};
+
+    #[test]
+    fn inflation_scales_about_the_centroid() {
+        let shape: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [2., 0.], [2., 2.], [0., 2.]]);
+
+        assert_eq!(
+            shape.clone().inflate_about_centroid(2.),
+            Shape::new(vec![[-1., -1.], [3., -1.], [3., 3.], [-1., 3.]]),
+            "the shape must grow away from its centroid"
+        );
+
+        assert_eq!(
+            shape.clone().inflate_about_centroid(1.),
+            shape,
+            "a unit factor must leave the shape untouched"
+        );
+    }
+
+    #[test]
+    fn explosion_moves_components_apart() {
+        let shape = Shape {
+            boundaries: vec![
+                Polygon::from(vec![[0., 0.], [2., 0.], [2., 2.], [0., 2.]]),
+                // The hole of the first component, which must travel with its shell.
+                Polygon::from(vec![[0.5, 0.5], [0.5, 1.5], [1.5, 1.5], [1.5, 0.5]]),
+                Polygon::from(vec![[4., 0.], [6., 0.], [6., 2.], [4., 2.]]),
+            ],
+        };
+
+        let got = shape.explode(1., &Tolerance::default());
+
+        assert_eq!(
+            got,
+            Shape {
+                boundaries: vec![
+                    Polygon::from(vec![[-1., 0.], [1., 0.], [1., 2.], [-1., 2.]]),
+                    Polygon::from(vec![[-0.5, 0.5], [-0.5, 1.5], [0.5, 1.5], [0.5, 0.5]]),
+                    Polygon::from(vec![[5., 0.], [7., 0.], [7., 2.], [5., 2.]]),
+                ],
+            },
+            "each component must move away from the centroid of the whole"
+        );
+
+        let single: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [2., 0.], [2., 2.], [0., 2.]]);
+        assert_eq!(
+            single.clone().explode(1., &Tolerance::default()),
+            single,
+            "a shape with a single component must be left in place"
+        );
+    }
+}
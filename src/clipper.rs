@@ -1,16 +1,46 @@
 use std::marker::PhantomData;
 
+use num_traits::ToPrimitive;
+
 use crate::{
-    graph::{Graph, GraphBuilder, Node},
-    Edge, Geometry, IsClose, Shape, Vertex,
+    graph::{Graph, GraphBuilder, Node, PositionVec},
+    options::{AntipodalPolicy, ClipError, ClipOptions, DegeneratePolicy, Progress},
+    report::{Diagnosed, DropReason, DroppedBoundary},
+    shape::{NotOperator, ReverseNotOperator, XorOperator},
+    Edge, Geometry, IsClose, RightHanded, Shape, Vertex,
 };
 
 /// Marker for yet undefined generic parameters.
 pub struct Unknown;
 
+/// An operand of the clipping operation, either owning or borrowing its [`Shape`].
+pub(crate) trait Operand {
+    /// The geometry of the operand shape.
+    type Geometry;
+
+    /// Returns a reference to the operand shape.
+    fn shape(&self) -> &Shape<Self::Geometry>;
+}
+
+impl<U> Operand for Shape<U> {
+    type Geometry = U;
+
+    fn shape(&self) -> &Shape<U> {
+        self
+    }
+}
+
+impl<U> Operand for &Shape<U> {
+    type Geometry = U;
+
+    fn shape(&self) -> &Shape<U> {
+        self
+    }
+}
+
 /// A direction to follow when traversing a boundary.
 #[derive(Debug, Default, Clone, Copy)]
-pub(crate) enum Direction {
+pub enum Direction {
     /// Use the `next` field of the [`Node`].
     #[default]
     Forward,
@@ -25,8 +55,8 @@ impl Direction {
         T: Geometry,
     {
         match self {
-            Direction::Forward => node.next,
-            Direction::Backward => node.previous,
+            Direction::Forward => node.next as usize,
+            Direction::Backward => node.previous as usize,
         }
     }
 
@@ -36,7 +66,10 @@ impl Direction {
 }
 
 /// The operation to perform by the clipping algorithm.
-pub(crate) trait Operator<T>
+///
+/// Implementing this trait allows custom output rules on top of the built-in union, difference
+/// and intersection, applied through [`Shape::clip_with`](crate::Shape::clip_with).
+pub trait Operator<T>
 where
     T: Geometry,
 {
@@ -46,16 +79,18 @@ where
         ops: Operands<'a, T>,
         node: &'a Node<T>,
         tolerance: &<T::Vertex as IsClose>::Tolerance,
+        options: &ClipOptions,
     ) -> bool;
 
     /// Returns the direction to take from the given node.
     fn direction(node: &Node<T>) -> Direction;
 }
 
-/// Implements the clipping algorithm.                                                                                                                                    
+/// Implements the clipping algorithm.
 pub(crate) struct Clipper<Operator, Subject, Clip, Tolerance> {
     pub(crate) tolerance: Tolerance,
     operator: PhantomData<Operator>,
+    options: ClipOptions,
     subject: Subject,
     clip: Clip,
 }
@@ -64,6 +99,7 @@ impl Default for Clipper<Unknown, Unknown, Unknown, Unknown> {
     fn default() -> Self {
         Self {
             operator: PhantomData,
+            options: Default::default(),
             tolerance: Unknown,
             subject: Unknown,
             clip: Unknown,
@@ -75,11 +111,17 @@ impl<Op, Sub, Clip, Tol> Clipper<Op, Sub, Clip, Tol> {
     pub(crate) fn with_operator<Operator>(self) -> Clipper<Operator, Sub, Clip, Tol> {
         Clipper {
             operator: PhantomData,
+            options: self.options,
             tolerance: self.tolerance,
             subject: self.subject,
             clip: self.clip,
         }
     }
+
+    pub(crate) fn with_options(mut self, options: ClipOptions) -> Self {
+        self.options = options;
+        self
+    }
 }
 
 impl<Op, Clip, Tol> Clipper<Op, Unknown, Clip, Tol> {
@@ -89,28 +131,56 @@ impl<Op, Clip, Tol> Clipper<Op, Unknown, Clip, Tol> {
     ) -> Clipper<Op, Shape<U>, Clip, Tol> {
         Clipper {
             operator: PhantomData,
+            options: self.options,
             tolerance: self.tolerance,
             subject: subject.into(),
             clip: self.clip,
         }
     }
+
+    /// Like [`Self::with_subject`], but borrowing the subject shape instead of owning it.
+    pub(crate) fn with_subject_ref<U>(
+        self,
+        subject: &Shape<U>,
+    ) -> Clipper<Op, &Shape<U>, Clip, Tol> {
+        Clipper {
+            operator: PhantomData,
+            options: self.options,
+            tolerance: self.tolerance,
+            subject,
+            clip: self.clip,
+        }
+    }
 }
 
 impl<Op, Sub, Tol> Clipper<Op, Sub, Unknown, Tol> {
     pub(crate) fn with_clip<U>(self, clip: impl Into<Shape<U>>) -> Clipper<Op, Sub, Shape<U>, Tol> {
         Clipper {
             operator: PhantomData,
+            options: self.options,
             tolerance: self.tolerance,
             subject: self.subject,
             clip: clip.into(),
         }
     }
+
+    /// Like [`Self::with_clip`], but borrowing the clip shape instead of owning it.
+    pub(crate) fn with_clip_ref<U>(self, clip: &Shape<U>) -> Clipper<Op, Sub, &Shape<U>, Tol> {
+        Clipper {
+            operator: PhantomData,
+            options: self.options,
+            tolerance: self.tolerance,
+            subject: self.subject,
+            clip,
+        }
+    }
 }
 
 impl<Op, Sub, Clip> Clipper<Op, Sub, Clip, Unknown> {
     pub(crate) fn with_tolerance<Tol>(self, tolerance: Tol) -> Clipper<Op, Sub, Clip, Tol> {
         Clipper {
             operator: PhantomData,
+            options: self.options,
             subject: self.subject,
             clip: self.clip,
             tolerance,
@@ -118,50 +188,374 @@ impl<Op, Sub, Clip> Clipper<Op, Sub, Clip, Unknown> {
     }
 }
 
-impl<U, Op, Tol> Clipper<Op, Shape<U>, Shape<U>, Tol>
+impl<B, U, Op, Tol> Clipper<Op, B, B, Tol>
 where
-    U: Geometry + Clone + IntoIterator<Item = U::Vertex>,
+    B: Operand<Geometry = U>,
+    U: Geometry,
     U::Vertex: IsClose<Tolerance = Tol> + Copy + PartialEq + PartialOrd,
     for<'a> U::Edge<'a>: Edge<'a>,
-    <U::Vertex as Vertex>::Scalar: Copy + PartialOrd,
+    <U::Vertex as Vertex>::Scalar: Copy + PartialOrd + ToPrimitive,
     Op: Operator<U>,
+    Tol: Clone,
 {
     /// Performs the clipping operation and returns the resulting [`Shape`], if any.
-    pub(crate) fn execute(self) -> Option<Shape<U>> {
-        let mut graph = GraphBuilder::new(&self.tolerance)
-            .with_subject(&self.subject)
-            .with_clip(&self.clip)
-            .build();
+    ///
+    /// Fails if, and only if, the operation is interrupted before completing.
+    pub(crate) fn try_execute(self) -> Result<Option<Shape<U>>, ClipError> {
+        self.try_execute_diagnosed().map(|(output, _)| output)
+    }
 
-        let mut output_boundaries = Vec::new();
+    /// Like [`Self::try_execute`], but also returning a [`DroppedBoundary`] for every boundary
+    /// discarded while assembling the output.
+    pub(crate) fn try_execute_diagnosed(
+        self,
+    ) -> Result<Diagnosed<U>, ClipError> {
+        let operands = Operands {
+            subject: self.subject.shape(),
+            clip: self.clip.shape(),
+        };
+
+        if self.options.antipodal_policy == AntipodalPolicy::Error {
+            let ill_defined = [operands.subject, operands.clip].into_iter().any(|shape| {
+                shape.boundaries.iter().any(|boundary| {
+                    boundary
+                        .edges()
+                        .any(|edge| edge.is_ill_defined(&self.tolerance))
+                })
+            });
+
+            if ill_defined {
+                return Err(ClipError::IllDefinedEdge);
+            }
+        }
+
+        let graph = GraphBuilder::new(&self.tolerance, &self.options)
+            .with_subject(operands.subject)
+            .with_clip(operands.clip)
+            .build()?;
+
+        self.try_execute_prebuilt(graph)
+    }
+
+    /// Like [`Self::try_execute_diagnosed`], but traversing the given pre-built [`Graph`]
+    /// instead of computing one from the operands.
+    ///
+    /// The graph must have been built from the very same operands, tolerance and options, as a
+    /// [`ClipPlan`](crate::ClipPlan) does.
+    pub(crate) fn try_execute_prebuilt(
+        self,
+        mut graph: Graph<U>,
+    ) -> Result<Diagnosed<U>, ClipError> {
+        let operands = Operands {
+            subject: self.subject.shape(),
+            clip: self.clip.shape(),
+        };
+
+        let overrides = &self.options.tolerance_overrides;
+        let classification_tolerance = overrides.classification::<U::Vertex>(&self.tolerance);
+        let welding_tolerance = overrides.welding::<U::Vertex>(&self.tolerance);
+
+        let mut dropped = Vec::new();
+        let mut output_boundaries = Vec::with_capacity(graph.boundaries.len());
         let mut intersection_search = Resume::<IntersectionSearch<U>>::new(0);
         while let Some(position) = intersection_search.next(&graph) {
+            if self.options.cancelled() {
+                return Err(ClipError::Cancelled);
+            }
+
             let boundary = Follow::new::<Op>(&mut graph, position).collect();
-            if let Some(boundary) = U::from_raw((&self).into(), boundary, &self.tolerance) {
-                output_boundaries.push(boundary);
+            let witness = (boundary.first().copied(), boundary.len());
+            match U::from_raw(operands, boundary, &welding_tolerance) {
+                Some(boundary) => output_boundaries.push(boundary),
+                None => dropped.push(DroppedBoundary {
+                    vertex: witness.0,
+                    vertices: witness.1,
+                    reason: DropReason::Unconstructible,
+                }),
             };
+
+            self.options.report_progress(Progress::TraversingBoundaries {
+                boundaries: output_boundaries.len(),
+            });
         }
 
         let mut intersectionless_search = Resume::<IntersectionlessSearch<U>>::new(0);
         while let Some(position) = intersectionless_search.next(&graph) {
-            if let Some(node) = &graph.nodes[position]
-                && !Op::is_output((&self).into(), node, &self.tolerance)
+            if self.options.cancelled() {
+                return Err(ClipError::Cancelled);
+            }
+
+            if let Some(Some(node)) = graph.nodes.get(position)
+                && !Op::is_output(operands, node, &classification_tolerance, &self.options)
             {
                 continue;
             };
 
             let boundary = Drain::new(&mut graph, position).collect::<Op>();
-            if let Some(boundary) = U::from_raw((&self).into(), boundary, &self.tolerance) {
-                output_boundaries.push(boundary);
+            let witness = (boundary.first().copied(), boundary.len());
+            match U::from_raw(operands, boundary, &welding_tolerance) {
+                Some(boundary) => output_boundaries.push(boundary),
+                None => dropped.push(DroppedBoundary {
+                    vertex: witness.0,
+                    vertices: witness.1,
+                    reason: DropReason::Unconstructible,
+                }),
             };
+
+            self.options.report_progress(Progress::TraversingBoundaries {
+                boundaries: output_boundaries.len(),
+            });
+        }
+
+        if self.options.degenerate_policy != DegeneratePolicy::Keep {
+            let mut kept = Vec::with_capacity(output_boundaries.len());
+            for boundary in output_boundaries {
+                if boundary.total_vertices() > 2 {
+                    kept.push(boundary);
+                } else if self.options.degenerate_policy == DegeneratePolicy::Report {
+                    dropped.push(DroppedBoundary {
+                        vertex: boundary.edges().next().map(|edge| *edge.start()),
+                        vertices: boundary.total_vertices(),
+                        reason: DropReason::Degenerate,
+                    });
+                }
+            }
+
+            output_boundaries = kept;
+        }
+
+        if let Some(decimals) = self.options.round_output {
+            let mut rounded = Vec::with_capacity(output_boundaries.len());
+            for boundary in output_boundaries {
+                let boundary = boundary
+                    .map(|vertex| vertex.rounded(decimals))
+                    .deduped(&welding_tolerance);
+
+                if boundary.total_vertices() > 2 {
+                    rounded.push(boundary);
+                } else {
+                    dropped.push(DroppedBoundary {
+                        vertex: boundary.edges().next().map(|edge| *edge.start()),
+                        vertices: boundary.total_vertices(),
+                        reason: DropReason::Sliver,
+                    });
+                }
+            }
+
+            output_boundaries = rounded;
+        }
+
+        if self.options.filters_slivers() {
+            let mut kept = Vec::with_capacity(output_boundaries.len());
+            for boundary in output_boundaries {
+                if self
+                    .options
+                    .is_sliver(boundary.total_vertices(), boundary.area().to_f64())
+                {
+                    dropped.push(DroppedBoundary {
+                        vertex: boundary.edges().next().map(|edge| *edge.start()),
+                        vertices: boundary.total_vertices(),
+                        reason: DropReason::Sliver,
+                    });
+                } else {
+                    kept.push(boundary);
+                }
+            }
+
+            output_boundaries = kept;
+        }
+
+        if !self.options.skip_orientation_pass {
+            output_boundaries = Self::oriented_by_depth(output_boundaries, &self.tolerance);
+        }
+
+        if self.options.validate_output
+            && let Some(violation) = Self::validity_violation(&output_boundaries, &self.tolerance)
+        {
+            return Err(ClipError::InvalidOutput(violation));
         }
 
         if output_boundaries.is_empty() {
-            return None;
+            return Ok((None, dropped));
+        }
+
+        Ok((
+            Some(Shape {
+                boundaries: output_boundaries,
+            }),
+            dropped,
+        ))
+    }
+
+    /// Returns the given boundaries with each one oriented according to its containment depth:
+    /// counterclockwise at even depths, clockwise at odd ones.
+    ///
+    /// The traversal direction of the graph usually yields the right orientations on its own,
+    /// but coincident geometry can leave a hole winding like its containing boundary. Vertices
+    /// lying on another boundary are not counted as contained by it, so stacked coincident
+    /// boundaries keep their orientation.
+    fn oriented_by_depth(
+        boundaries: Vec<U>,
+        tolerance: &<U::Vertex as IsClose>::Tolerance,
+    ) -> Vec<U> {
+        let depths: Vec<usize> = boundaries
+            .iter()
+            .enumerate()
+            .map(|(position, boundary)| {
+                let Some(vertex) = boundary.edges().next().map(|edge| *edge.start()) else {
+                    return 0;
+                };
+
+                boundaries
+                    .iter()
+                    .enumerate()
+                    .filter(|&(other, candidate)| {
+                        other != position
+                            && !candidate
+                                .edges()
+                                .any(|edge| edge.contains(&vertex, tolerance))
+                            && candidate.winding(&vertex, tolerance) != 0
+                    })
+                    .count()
+            })
+            .collect();
+
+        boundaries
+            .into_iter()
+            .zip(depths)
+            .map(|(boundary, depth)| {
+                if (depth % 2 == 1) != boundary.is_clockwise() {
+                    boundary.reversed()
+                } else {
+                    boundary
+                }
+            })
+            .collect()
+    }
+
+    /// Returns a description of the first validity violation found in the given output
+    /// boundaries, if any.
+    fn validity_violation(
+        boundaries: &[U],
+        tolerance: &<U::Vertex as IsClose>::Tolerance,
+    ) -> Option<&'static str> {
+        for boundary in boundaries {
+            let edges: Vec<_> = boundary.edges().collect();
+            for (position, edge) in edges.iter().enumerate() {
+                for (other_position, other) in edges.iter().enumerate().skip(position + 1) {
+                    let adjacent = other_position == position + 1
+                        || (position == 0 && other_position == edges.len() - 1);
+
+                    if !adjacent && edge.intersection(other, tolerance).is_some() {
+                        return Some("a boundary of the output intersects itself");
+                    }
+                }
+            }
+        }
+
+        for boundary in boundaries {
+            if !boundary.is_clockwise() {
+                continue;
+            }
+
+            let Some(vertex) = boundary.edges().next().map(|edge| *edge.start()) else {
+                continue;
+            };
+
+            let sheltered = boundaries
+                .iter()
+                .any(|shell| !shell.is_clockwise() && shell.contains(&vertex, tolerance));
+
+            if !sheltered {
+                return Some("a hole of the output lies outside every shell");
+            }
+        }
+
+        if !boundaries.is_empty() && boundaries.iter().all(RightHanded::is_clockwise) {
+            return Some("the output contains only holes");
         }
 
-        Some(Shape {
-            boundaries: output_boundaries,
+        None
+    }
+}
+
+impl<B, U, Tol> Clipper<XorOperator<U>, B, B, Tol>
+where
+    B: Operand<Geometry = U>,
+    U: Geometry,
+    U::Vertex: IsClose<Tolerance = Tol> + Copy + PartialEq + PartialOrd,
+    for<'a> U::Edge<'a>: Edge<'a>,
+    <U::Vertex as Vertex>::Scalar: Copy + PartialOrd + ToPrimitive,
+    Tol: Clone,
+{
+    /// Performs the symmetric difference and returns the resulting [`Shape`], if any.
+    ///
+    /// Fails if, and only if, the operation is interrupted before completing.
+    ///
+    /// Unlike the other operators, the symmetric difference cannot come out of a single
+    /// traversal: every intersection node lies on both of its lobes, yet a traversal consumes
+    /// each node at most once. The graph is therefore built once and traversed once per
+    /// direction of the difference. No boundary of one lobe ever reaches the interior of the
+    /// other, so keeping the boundaries of both runs already describes their union.
+    pub(crate) fn try_execute_xor(self) -> Result<Option<Shape<U>>, ClipError> {
+        let operands = Operands {
+            subject: self.subject.shape(),
+            clip: self.clip.shape(),
+        };
+
+        if self.options.antipodal_policy == AntipodalPolicy::Error {
+            let ill_defined = [operands.subject, operands.clip].into_iter().any(|shape| {
+                shape.boundaries.iter().any(|boundary| {
+                    boundary
+                        .edges()
+                        .any(|edge| edge.is_ill_defined(&self.tolerance))
+                })
+            });
+
+            if ill_defined {
+                return Err(ClipError::IllDefinedEdge);
+            }
+        }
+
+        let graph = GraphBuilder::new(&self.tolerance, &self.options)
+            .with_subject(operands.subject)
+            .with_clip(operands.clip)
+            .build()?;
+
+        self.try_execute_xor_prebuilt(graph)
+    }
+
+    /// Like [`Self::try_execute_xor`], but traversing the given pre-built [`Graph`] instead of
+    /// computing one from the operands.
+    ///
+    /// The graph must have been built from the very same operands, tolerance and options, as a
+    /// [`ClipPlan`](crate::ClipPlan) does.
+    pub(crate) fn try_execute_xor_prebuilt(
+        self,
+        graph: Graph<U>,
+    ) -> Result<Option<Shape<U>>, ClipError> {
+        let (removed, _) = Clipper::default()
+            .with_operator::<NotOperator<U>>()
+            .with_options(self.options.clone())
+            .with_tolerance(self.tolerance.clone())
+            .with_subject_ref(self.subject.shape())
+            .with_clip_ref(self.clip.shape())
+            .try_execute_prebuilt(graph.clone())?;
+
+        let (added, _) = Clipper::default()
+            .with_operator::<ReverseNotOperator<U>>()
+            .with_options(self.options.clone())
+            .with_tolerance(self.tolerance.clone())
+            .with_subject_ref(self.subject.shape())
+            .with_clip_ref(self.clip.shape())
+            .try_execute_prebuilt(graph)?;
+
+        Ok(match (removed, added) {
+            (Some(mut removed), Some(added)) => {
+                removed.boundaries.extend(added.boundaries);
+                Some(removed)
+            }
+            (removed, added) => removed.or(added),
         })
     }
 }
@@ -275,7 +669,7 @@ where
     next: Option<usize>,
     direction: Direction,
     operator: PhantomData<Op>,
-    terminal: Vec<usize>,
+    terminal: PositionVec,
     closed: bool,
 }
 
@@ -293,24 +687,37 @@ where
         }
 
         let current = self.next?;
-        let node = self.graph.nodes[current].take()?;
+        let node = self.graph.nodes.get_mut(current)?.take()?;
 
         if node.intersection.has_siblings() {
             self.direction = Op::direction(&node);
         }
 
         let candidate = self.direction.next(&node);
-        self.next = self.graph.nodes[candidate].as_mut().and_then(|next| {
-            if next.intersection.has_siblings() {
-                next.intersection.siblings.pop_first()
-            } else {
-                Some(candidate)
-            }
-        });
+        self.next = self
+            .graph
+            .nodes
+            .get_mut(candidate)
+            .and_then(Option::as_mut)
+            .and_then(|next| {
+                if next.intersection.has_siblings() {
+                    next.intersection
+                        .siblings
+                        .pop_first()
+                        .map(|sibling| sibling as usize)
+                } else {
+                    Some(candidate)
+                }
+            });
 
         if self.terminal.is_empty() {
-            self.terminal
-                .extend(node.intersection.siblings.iter().copied().chain([current]));
+            self.terminal.extend(
+                node.intersection
+                    .siblings
+                    .iter()
+                    .map(|&sibling| sibling as usize)
+                    .chain([current]),
+            );
         } else if let Some(next) = self.next {
             self.closed = self.terminal.contains(&next);
         } else {
@@ -318,8 +725,8 @@ where
                 .intersection
                 .siblings
                 .iter()
-                .filter_map(|&sibling| self.graph.nodes[sibling].as_ref())
-                .map(|sibling| sibling.next)
+                .filter_map(|&sibling| self.graph.nodes.get(sibling as usize)?.as_ref())
+                .map(|sibling| sibling.next as usize)
                 .chain([self.direction.next(&node)])
                 .any(|node| self.terminal.contains(&node));
         };
@@ -338,7 +745,7 @@ where
     fn collect(self) -> Vec<T::Vertex> {
         let orientation = self
             .next
-            .and_then(|position| self.graph.nodes[position].as_ref())
+            .and_then(|position| self.graph.nodes.get(position)?.as_ref())
             .map(|node| Op::direction(node))
             .unwrap_or_default();
         let mut boundary = self.map(|node| node.vertex).collect::<Vec<_>>();
@@ -441,8 +848,8 @@ where
         }
 
         let current = self.next.unwrap_or(self.start);
-        let node = self.graph.nodes[current].take()?;
-        self.next = Some(node.next);
+        let node = self.graph.nodes.get_mut(current)?.take()?;
+        self.next = Some(node.next as usize);
 
         Some(node)
     }
@@ -458,8 +865,11 @@ where
     where
         Op: Operator<T>,
     {
-        let orientation = self.graph.nodes[self.start]
-            .as_ref()
+        let orientation = self
+            .graph
+            .nodes
+            .get(self.start)
+            .and_then(Option::as_ref)
             .map(|node| Op::direction(node))
             .unwrap_or_default();
 
@@ -487,17 +897,34 @@ where
 }
 
 /// The subject and clip operands of a clipping operation.
-#[derive(Debug, Clone, Copy)]
+///
+/// This is the context handed to [`Operator`] and [`Geometry`] implementations. It is marked as
+/// non-exhaustive so future releases can attach more context without breaking downstream
+/// implementors: construct it through the clipping entry points, never by hand.
+#[derive(Debug)]
+#[non_exhaustive]
 pub struct Operands<'a, T> {
     pub subject: &'a Shape<T>,
     pub clip: &'a Shape<T>,
 }
 
-impl<'a, U, Op, Tol> From<&'a Clipper<Op, Shape<U>, Shape<U>, Tol>> for Operands<'a, U> {
-    fn from(clipper: &'a Clipper<Op, Shape<U>, Shape<U>, Tol>) -> Self {
+// Derived implementations would require `T: Copy`, even though only references are held.
+impl<T> Clone for Operands<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Operands<'_, T> {}
+
+impl<'a, B, U, Op, Tol> From<&'a Clipper<Op, B, B, Tol>> for Operands<'a, U>
+where
+    B: Operand<Geometry = U>,
+{
+    fn from(clipper: &'a Clipper<Op, B, B, Tol>) -> Self {
         Operands {
-            subject: &clipper.subject,
-            clip: &clipper.clip,
+            subject: clipper.subject.shape(),
+            clip: clipper.clip.shape(),
         }
     }
 }
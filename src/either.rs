@@ -1,5 +1,5 @@
 /// The intersection between two edges.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Either<L, R> {
     Left(L),
     Right(R),
@@ -0,0 +1,127 @@
+//! Curated hard-case geometry fixtures.
+//!
+//! The pairs in this module are the inputs the crate itself is hardest on: coincident collinear
+//! edges, shared vertices, slivers and pole-crossing boundaries. They are exposed behind the
+//! `fixtures` feature so downstream pipelines can exercise their own logic against known-hard
+//! geometry without re-deriving it. A fixture being shipped is no promise that every operation
+//! handles it gracefully; some exist precisely because they are still troublesome.
+
+use crate::{cartesian::Polygon, Shape};
+
+/// Returns two squares overlapping through coincident collinear edges.
+///
+/// Both the top and bottom edges of the operands run along the same lines, which is the hardest
+/// configuration for the intersection classifier.
+pub fn coincident_edges() -> (Shape<Polygon<f64>>, Shape<Polygon<f64>>) {
+    (
+        Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+        Shape::new(vec![[2., 0.], [6., 0.], [6., 4.], [2., 4.]]),
+    )
+}
+
+/// Returns two squares touching at a single shared vertex.
+///
+/// The touch point is an intersection with no interior overlap, exercising the tangential
+/// branch of the entry/exit classification.
+pub fn shared_vertex() -> (Shape<Polygon<f64>>, Shape<Polygon<f64>>) {
+    (
+        Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+        Shape::new(vec![[4., 4.], [8., 4.], [8., 8.], [4., 8.]]),
+    )
+}
+
+/// Returns a square and a near-degenerate rectangle crossing it.
+///
+/// Their intersection is a sliver of near-zero area whose vertices are separated by less than
+/// most practical tolerances, exercising sliver filtering and tolerance scaling.
+pub fn sliver() -> (Shape<Polygon<f64>>, Shape<Polygon<f64>>) {
+    (
+        Shape::new(vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]]),
+        Shape::new(vec![[-1., 2.], [9., 2.], [9., 2. + 1e-7], [-1., 2. + 1e-7]]),
+    )
+}
+
+/// Returns a self-crossing subject and a square straddling its crossing point.
+///
+/// The subject revisits a vertex, so outputs derived from it may legitimately touch
+/// themselves, exercising any downstream validity assumption.
+pub fn self_crossing() -> (Shape<Polygon<f64>>, Shape<Polygon<f64>>) {
+    (
+        Shape::new(vec![
+            [-1., 0.],
+            [-1., -1.],
+            [1., -1.],
+            [1., 0.],
+            [-1., 0.],
+            [-1., 1.],
+            [1., 1.],
+            [1., 0.],
+        ]),
+        Shape::new(vec![[0.75, 0.75], [0.75, 1.25], [-0.75, 1.25], [-0.75, 0.75]]),
+    )
+}
+
+/// Returns two overlapping spherical triangles enclosing the north pole.
+///
+/// No cartesian projection represents their interiors without a discontinuity, exercising the
+/// exterior-point bookkeeping of the spherical backend.
+#[cfg(feature = "spherical")]
+pub fn pole_crossing() -> (
+    Shape<crate::spherical::Polygon<f64>>,
+    Shape<crate::spherical::Polygon<f64>>,
+) {
+    use std::f64::consts::{FRAC_PI_3, FRAC_PI_4, PI};
+
+    use crate::spherical::Polygon;
+
+    (
+        Shape::new(Polygon::new(
+            vec![
+                [FRAC_PI_4, 0.],
+                [FRAC_PI_4, 2. * FRAC_PI_3],
+                [FRAC_PI_4, 4. * FRAC_PI_3],
+            ],
+            [PI, 0.],
+        )),
+        Shape::new(Polygon::new(
+            vec![
+                [FRAC_PI_4, FRAC_PI_3],
+                [FRAC_PI_4, PI],
+                [FRAC_PI_4, 5. * FRAC_PI_3],
+            ],
+            [PI, 0.],
+        )),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn fixtures_are_constructible() {
+        let pairs = [
+            super::coincident_edges(),
+            super::shared_vertex(),
+            super::sliver(),
+            super::self_crossing(),
+        ];
+
+        for (subject, clip) in pairs {
+            assert!(subject.total_vertices() > 2);
+            assert!(clip.total_vertices() > 2);
+        }
+
+        let (subject, clip) = super::shared_vertex();
+        assert!(
+            subject.or(clip, Default::default()).is_some(),
+            "the tangential fixture must survive a union"
+        );
+    }
+
+    #[cfg(feature = "spherical")]
+    #[test]
+    fn pole_crossing_fixture_is_constructible() {
+        let (subject, clip) = super::pole_crossing();
+        assert!(subject.total_vertices() > 2);
+        assert!(clip.total_vertices() > 2);
+    }
+}
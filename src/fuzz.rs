@@ -0,0 +1,169 @@
+//! Fuzzing harness over the clipping operators.
+//!
+//! A cargo-fuzz target only needs to forward its raw input to [`fuzz_clip`]:
+//!
+//! ```ignore
+//! fuzz_target!(|data: &[u8]| boolygon::fuzz::fuzz_clip(data));
+//! ```
+
+use crate::{
+    cartesian::{Point, Polygon, Segment},
+    Edge, Either, Geometry, Shape, Tolerance,
+};
+
+/// The subject and clip operands decoded from a fuzzed input.
+pub type ShapePair = (Shape<Polygon<f64>>, Shape<Polygon<f64>>);
+
+/// Decodes the given bytes into a pair of simple, non-degenerate shapes, if possible.
+///
+/// Coordinates are decoded as small signed integers, so that fuzzed inputs exercise the
+/// grid-aligned coincidences where clipping is most fragile.
+pub fn shape_pair(mut data: &[u8]) -> Option<ShapePair> {
+    let subject = polygon(&mut data)?;
+    let clip = polygon(&mut data)?;
+
+    Some((Shape::new(subject), Shape::new(clip)))
+}
+
+/// Decodes the given bytes and runs every operator over the resulting pair, panicking on any
+/// violated invariant.
+///
+/// Undecodable inputs are silently discarded, as expected by fuzzing engines.
+pub fn fuzz_clip(data: &[u8]) {
+    let Some((subject, clip)) = shape_pair(data) else {
+        return;
+    };
+
+    let tolerance = Tolerance::default();
+    let union = subject.or_ref(&clip, tolerance);
+    let intersection = subject.and_ref(&clip, tolerance);
+    let difference = subject.not_ref(&clip, tolerance);
+
+    for shape in [&union, &intersection, &difference].into_iter().flatten() {
+        assert!(is_valid(shape), "clipping output must be valid");
+    }
+
+    // Collinear overlapping edges are a known limitation of the clipper; area conservation is
+    // only meaningful without them.
+    if overlapping_edges(&subject, &clip, &tolerance) {
+        return;
+    }
+
+    let total = area(&subject) + area(&clip);
+    let combined = area_of(&union) + area_of(&intersection);
+    assert!(
+        (total - combined).abs() <= 1e-6 * (total.abs() + 1.),
+        "area must be conserved: {total} != {combined}",
+    );
+}
+
+/// Decodes the next polygon from the given bytes, consuming them.
+fn polygon(data: &mut &[u8]) -> Option<Polygon<f64>> {
+    let (&amount, rest) = data.split_first()?;
+    *data = rest;
+
+    let amount = 3 + (amount as usize) % 6;
+    let mut vertices: Vec<Point<f64>> = Vec::with_capacity(amount);
+    for _ in 0..amount {
+        let &[x, y, ref rest @ ..] = *data else {
+            return None;
+        };
+
+        *data = rest;
+        let vertex = [(x as i8) as f64, (y as i8) as f64].into();
+        if vertices.last() != Some(&vertex) {
+            vertices.push(vertex);
+        }
+    }
+
+    if vertices.first() == vertices.last() {
+        vertices.pop();
+    }
+
+    let polygon = Polygon { vertices };
+    (polygon.vertices.len() >= 3 && is_simple(&polygon) && polygon.area() > 0.).then_some(polygon)
+}
+
+/// Returns true if, and only if, no two non-adjacent edges of the polygon intersect.
+fn is_simple(polygon: &Polygon<f64>) -> bool {
+    let tolerance = Tolerance::default();
+    let vertices = &polygon.vertices;
+    let len = vertices.len();
+
+    for from in 0..len {
+        for other in (from + 1)..len {
+            if other == from + 1 || (from == 0 && other == len - 1) {
+                continue;
+            }
+
+            let edge = Segment::new(&vertices[from], &vertices[(from + 1) % len]);
+            let other = Segment::new(&vertices[other], &vertices[(other + 1) % len]);
+            if edge.intersection(&other, &tolerance).is_some() {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+/// Returns true if, and only if, every boundary of the shape has at least three finite vertices.
+fn is_valid(shape: &Shape<Polygon<f64>>) -> bool {
+    shape.boundaries.iter().all(|boundary| {
+        boundary.vertices.len() >= 3
+            && boundary
+                .vertices
+                .iter()
+                .all(|vertex| vertex.x.is_finite() && vertex.y.is_finite())
+    })
+}
+
+/// Returns true if, and only if, any edge of the subject collinearly overlaps an edge of the
+/// clip.
+fn overlapping_edges(
+    subject: &Shape<Polygon<f64>>,
+    clip: &Shape<Polygon<f64>>,
+    tolerance: &Tolerance<f64>,
+) -> bool {
+    subject.edges().any(|(_, edge)| {
+        clip.edges().any(|(_, other)| {
+            matches!(edge.intersection(&other, tolerance), Some(Either::Right(_)))
+        })
+    })
+}
+
+/// Returns the filled area of the given shape.
+fn area(shape: &Shape<Polygon<f64>>) -> f64 {
+    shape.stats().map(|stats| stats.area).unwrap_or_default()
+}
+
+/// Returns the filled area of the given optional shape.
+fn area_of(shape: &Option<Shape<Polygon<f64>>>) -> f64 {
+    shape.as_ref().map(area).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fuzz_clip, shape_pair};
+
+    #[test]
+    fn decoded_pairs_must_be_clippable() {
+        // Two overlapping axis-aligned squares.
+        let data = [
+            1, 0, 0, 4, 0, 4, 4, 0, 4, // subject
+            1, 2, 2, 6, 2, 6, 6, 2, 6, // clip
+        ];
+
+        assert!(shape_pair(&data).is_some());
+        fuzz_clip(&data);
+    }
+
+    #[test]
+    fn undecodable_inputs_must_be_discarded() {
+        assert!(shape_pair(&[]).is_none());
+        assert!(shape_pair(&[7, 1, 2]).is_none());
+
+        fuzz_clip(&[]);
+        fuzz_clip(&[7, 1, 2]);
+    }
+}
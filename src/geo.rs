@@ -0,0 +1,162 @@
+//! Implementations of the [`geo`] algorithm traits for cartesian shapes.
+
+use geo::{Area, Centroid, Coord, CoordFloat, CoordsIter};
+
+use crate::{cartesian::Polygon, Shape};
+
+impl<T> CoordsIter for Shape<Polygon<T>>
+where
+    T: CoordFloat,
+{
+    type Iter<'a>
+        = Box<dyn Iterator<Item = Coord<T>> + 'a>
+    where
+        Self: 'a;
+
+    type ExteriorCoordsIter<'a>
+        = Box<dyn Iterator<Item = Coord<T>> + 'a>
+    where
+        Self: 'a;
+
+    type Scalar = T;
+
+    fn coords_iter(&self) -> Self::Iter<'_> {
+        Box::new(self.boundaries.iter().flat_map(coords))
+    }
+
+    fn coords_count(&self) -> usize {
+        self.boundaries
+            .iter()
+            .map(|boundary| boundary.vertices.len())
+            .sum()
+    }
+
+    fn exterior_coords_iter(&self) -> Self::ExteriorCoordsIter<'_> {
+        Box::new(self.boundaries.first().into_iter().flat_map(coords))
+    }
+}
+
+impl<T> Area<T> for Shape<Polygon<T>>
+where
+    T: CoordFloat,
+{
+    fn signed_area(&self) -> T {
+        self.boundaries
+            .iter()
+            .fold(T::zero(), |total, boundary| total + ring_area(boundary))
+    }
+
+    fn unsigned_area(&self) -> T {
+        self.signed_area().abs()
+    }
+}
+
+impl<T> Centroid for Shape<Polygon<T>>
+where
+    T: CoordFloat,
+{
+    type Output = Option<geo::Point<T>>;
+
+    /// Returns the area-weighted centroid of the shape, where holes subtract from the weight,
+    /// or none if the shape encloses no area.
+    fn centroid(&self) -> Self::Output {
+        let (doubled_area, x, y) = self
+            .boundaries
+            .iter()
+            .flat_map(|boundary| {
+                boundary
+                    .vertices
+                    .iter()
+                    .zip(boundary.vertices.iter().cycle().skip(1))
+            })
+            .fold(
+                (T::zero(), T::zero(), T::zero()),
+                |(doubled_area, x, y), (from, to)| {
+                    let cross = from.x * to.y - to.x * from.y;
+                    (
+                        doubled_area + cross,
+                        x + (from.x + to.x) * cross,
+                        y + (from.y + to.y) * cross,
+                    )
+                },
+            );
+
+        if doubled_area.is_zero() {
+            return None;
+        }
+
+        let denominator = T::from(3)? * doubled_area;
+        Some(geo::Point::new(x / denominator, y / denominator))
+    }
+}
+
+/// Returns an iterator over the coordinates of the given boundary.
+fn coords<T>(boundary: &Polygon<T>) -> impl Iterator<Item = Coord<T>> + '_
+where
+    T: CoordFloat,
+{
+    boundary
+        .vertices
+        .iter()
+        .map(|vertex| Coord {
+            x: vertex.x,
+            y: vertex.y,
+        })
+}
+
+/// Returns the signed area of the given boundary, positive when counterclockwise.
+fn ring_area<T>(boundary: &Polygon<T>) -> T
+where
+    T: CoordFloat,
+{
+    let two = T::one() + T::one();
+    boundary
+        .vertices
+        .iter()
+        .zip(boundary.vertices.iter().cycle().skip(1))
+        .fold(T::zero(), |total, (from, to)| {
+            total + (from.x * to.y - to.x * from.y)
+        })
+        / two
+}
+
+#[cfg(test)]
+mod tests {
+    use geo::{Area, Centroid, CoordsIter};
+
+    use crate::{cartesian::Polygon, Shape};
+
+    fn shape_with_hole() -> Shape<Polygon<f64>> {
+        Shape {
+            boundaries: vec![
+                vec![[0., 0.], [8., 0.], [8., 8.], [0., 8.]].into(),
+                vec![[2., 2.], [2., 6.], [6., 6.], [6., 2.]].into(),
+            ],
+        }
+    }
+
+    #[test]
+    fn shape_area_through_geo_traits() {
+        let shape = shape_with_hole();
+
+        assert_eq!(shape.signed_area(), 48.);
+        assert_eq!(shape.unsigned_area(), 48.);
+    }
+
+    #[test]
+    fn shape_centroid_through_geo_traits() {
+        let centroid = shape_with_hole().centroid().expect("centroid must exist");
+
+        assert!((centroid.x() - 4.).abs() < 1e-9);
+        assert!((centroid.y() - 4.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn shape_coordinates_through_geo_traits() {
+        let shape = shape_with_hole();
+
+        assert_eq!(shape.coords_count(), 8);
+        assert_eq!(shape.coords_iter().count(), 8);
+        assert_eq!(shape.exterior_coords_iter().count(), 4);
+    }
+}
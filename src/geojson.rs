@@ -0,0 +1,567 @@
+//! GeoJSON support for cartesian shapes.
+//!
+//! Besides the conversions between GeoJSON geometries and [`Shape`], this module provides a thin
+//! orchestration layer that clips every feature of a `FeatureCollection` against a mask shape
+//! while preserving the identifier and properties of each feature. When the `spherical` feature
+//! is enabled, spherical shapes can also be exported by densifying their great-circle edges.
+
+use ::geojson::{Feature, FeatureCollection, Geometry, Value};
+
+use crate::{batch::Operation, cartesian::Polygon, Geometry as _, RightHanded, Shape, Tolerance};
+
+/// The reason why a GeoJSON input could not be converted into a shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeoJsonError {
+    /// The feature holds no geometry at all.
+    MissingGeometry,
+    /// The geometry is neither a polygon nor a multi-polygon.
+    UnsupportedGeometry,
+    /// A position does not hold at least two coordinates.
+    MalformedPosition,
+    /// A ring holds too few positions to describe a boundary.
+    MalformedRing,
+    /// A ring is not explicitly closed.
+    UnclosedRing { polygon: usize, ring: usize },
+    /// A ring does not follow the orientation its role requires.
+    MisorientedRing { polygon: usize, ring: usize },
+    /// An interior ring does not lie inside its exterior.
+    MisnestedRing { polygon: usize, ring: usize },
+}
+
+/// Returns the polygon described by the given GeoJSON ring.
+fn boundary(ring: &[Vec<f64>]) -> Result<Polygon<f64>, GeoJsonError> {
+    let mut vertices = ring
+        .iter()
+        .map(|position| match position[..] {
+            [x, y, ..] => Ok([x, y]),
+            _ => Err(GeoJsonError::MalformedPosition),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // GeoJSON rings are explicitly closed, while boundaries are implicitly so.
+    if vertices.len() > 1 && vertices.first() == vertices.last() {
+        vertices.pop();
+    }
+
+    if vertices.len() < 3 {
+        return Err(GeoJsonError::MalformedRing);
+    }
+
+    Ok(vertices.into())
+}
+
+impl TryFrom<&Geometry> for Shape<Polygon<f64>> {
+    type Error = GeoJsonError;
+
+    fn try_from(geometry: &Geometry) -> Result<Self, Self::Error> {
+        let boundaries = match &geometry.value {
+            Value::Polygon(rings) => rings
+                .iter()
+                .map(|ring| boundary(ring))
+                .collect::<Result<Vec<_>, _>>()?,
+            Value::MultiPolygon(polygons) => polygons
+                .iter()
+                .flatten()
+                .map(|ring| boundary(ring))
+                .collect::<Result<Vec<_>, _>>()?,
+            _ => return Err(GeoJsonError::UnsupportedGeometry),
+        };
+
+        Ok(Shape { boundaries })
+    }
+}
+
+impl TryFrom<Geometry> for Shape<Polygon<f64>> {
+    type Error = GeoJsonError;
+
+    fn try_from(geometry: Geometry) -> Result<Self, Self::Error> {
+        Self::try_from(&geometry)
+    }
+}
+
+/// Returns the shape described by the given GeoJSON geometry, validating every ring on the way.
+///
+/// On top of the structural checks performed by the `TryFrom` conversions, this requires each
+/// ring to be explicitly closed, exteriors to be counterclockwise and holes clockwise, as
+/// mandated by RFC 7946, and every hole to lie inside its exterior. Errors report the offending
+/// polygon and ring.
+pub fn validated_shape(geometry: &Geometry) -> Result<Shape<Polygon<f64>>, GeoJsonError> {
+    let polygons: &[Vec<Vec<Vec<f64>>>] = match &geometry.value {
+        Value::Polygon(rings) => std::slice::from_ref(rings),
+        Value::MultiPolygon(polygons) => polygons,
+        _ => return Err(GeoJsonError::UnsupportedGeometry),
+    };
+
+    let mut boundaries = Vec::new();
+    for (polygon, rings) in polygons.iter().enumerate() {
+        let mut exterior: Option<Polygon<f64>> = None;
+        for (ring, positions) in rings.iter().enumerate() {
+            if positions.first() != positions.last() {
+                return Err(GeoJsonError::UnclosedRing { polygon, ring });
+            }
+
+            let candidate = boundary(positions)?;
+            if (ring == 0) == candidate.is_clockwise() {
+                return Err(GeoJsonError::MisorientedRing { polygon, ring });
+            }
+
+            match &exterior {
+                None => exterior = Some(candidate.clone()),
+                Some(exterior) => {
+                    let nested = candidate.vertices.first().is_some_and(|vertex| {
+                        exterior.contains(vertex, &Tolerance::default())
+                    });
+
+                    if !nested {
+                        return Err(GeoJsonError::MisnestedRing { polygon, ring });
+                    }
+                }
+            }
+
+            boundaries.push(candidate);
+        }
+    }
+
+    Ok(Shape { boundaries })
+}
+
+impl From<&Shape<Polygon<f64>>> for Geometry {
+    fn from(shape: &Shape<Polygon<f64>>) -> Self {
+        let rings = shape
+            .boundaries
+            .iter()
+            .map(|boundary| {
+                boundary
+                    .vertices
+                    .iter()
+                    .chain(boundary.vertices.first())
+                    .map(|point| vec![point.x, point.y])
+                    .collect()
+            })
+            .collect();
+
+        Geometry::new(Value::Polygon(rings))
+    }
+}
+
+/// Clips every feature of the given collection against the mask shape.
+///
+/// Each output feature keeps the identifier and properties of its input counterpart; only its
+/// geometry is replaced by the result of the operation. Features whose resulting geometry is
+/// empty are dropped from the output collection.
+pub fn clip_feature_collection(
+    collection: FeatureCollection,
+    mask: &Shape<Polygon<f64>>,
+    operation: Operation,
+    tolerance: Tolerance<f64>,
+) -> Result<FeatureCollection, GeoJsonError> {
+    let features = collection
+        .features
+        .into_iter()
+        .filter_map(|mut feature| {
+            let Some(geometry) = feature.geometry.take() else {
+                return Some(Err(GeoJsonError::MissingGeometry));
+            };
+
+            let subject = match Shape::try_from(&geometry) {
+                Ok(subject) => subject,
+                Err(error) => return Some(Err(error)),
+            };
+
+            let clipped = match operation {
+                Operation::Union => subject.or_ref(mask, tolerance),
+                Operation::Difference => subject.not_ref(mask, tolerance),
+                Operation::Intersection => subject.and_ref(mask, tolerance),
+            }?;
+
+            feature.geometry = Some((&clipped).into());
+            Some(Ok(feature))
+        })
+        .collect::<Result<Vec<Feature>, _>>()?;
+
+    Ok(FeatureCollection {
+        features,
+        ..collection
+    })
+}
+
+#[cfg(feature = "spherical")]
+mod spherical_export {
+    //! Densified export of spherical shapes.
+    //!
+    //! Great-circle edges are straight on the sphere but curved on the plane, so a faithful
+    //! planar export must subdivide them until each segment is short enough to pass as straight.
+    //! Likewise, rings crossing the antimeridian must be split beforehand, since their planar
+    //! longitudes would otherwise wrap around the globe.
+
+    use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+    use ::geojson::{Geometry, Value};
+
+    use crate::{
+        spherical::{destination, Point, Polygon},
+        Shape, Tolerance, Vertex,
+    };
+
+    impl Shape<Polygon<f64>> {
+        /// Returns this shape as a GeoJSON multi-polygon whose segments span at most the given
+        /// angle, or none if the angle is not positive.
+        ///
+        /// Each great-circle edge is densified by inserting intermediate vertices until no
+        /// segment exceeds the given angular length. Shapes crossing the antimeridian are
+        /// previously split along the meridian circle through the poles, yielding one polygon
+        /// per resulting piece. Positions are expressed in longitude and latitude degrees.
+        pub fn to_geojson_with_max_segment(
+            &self,
+            angle: f64,
+            tolerance: Tolerance<f64>,
+        ) -> Option<Geometry> {
+            if !(angle > 0.) {
+                return None;
+            }
+
+            let rings = self.densified_rings(angle);
+            let polygons = if rings.iter().any(|ring| crosses_antimeridian(ring)) {
+                hemispheres()
+                    .iter()
+                    .filter_map(|hemisphere| self.and_ref(hemisphere, tolerance))
+                    .map(|piece| {
+                        piece
+                            .densified_rings(angle)
+                            .into_iter()
+                            .map(unwrapped)
+                            .collect()
+                    })
+                    .collect()
+            } else {
+                vec![rings]
+            };
+
+            Some(Geometry::new(Value::MultiPolygon(polygons)))
+        }
+
+        /// Returns one explicitly closed ring of positions per boundary of this shape, with no
+        /// segment spanning more than the given angle.
+        fn densified_rings(&self, angle: f64) -> Vec<Vec<Vec<f64>>> {
+            self.boundaries
+                .iter()
+                .map(|boundary| {
+                    let mut ring = Vec::new();
+                    for (index, from) in boundary.vertices.iter().enumerate() {
+                        let to = &boundary.vertices[(index + 1) % boundary.vertices.len()];
+                        ring.push(position(from));
+
+                        let distance = from.distance(to);
+                        let steps = (distance / angle).ceil();
+                        for step in 1..steps as usize {
+                            let along = distance * step as f64 / steps;
+                            ring.push(position(&destination(from, bearing(from, to), along)));
+                        }
+                    }
+
+                    // GeoJSON rings are explicitly closed, while boundaries are implicitly so.
+                    if let Some(first) = ring.first().cloned() {
+                        ring.push(first);
+                    }
+
+                    ring
+                })
+                .collect()
+        }
+    }
+
+    /// Returns the initial bearing of the great-circle course from one point to the other.
+    fn bearing(from: &Point<f64>, to: &Point<f64>) -> f64 {
+        let delta = to.azimuth.into_inner() - from.azimuth.into_inner();
+        let from_inclination = from.inclination.into_inner();
+        let to_inclination = to.inclination.into_inner();
+
+        (delta.sin() * to_inclination.sin()).atan2(
+            from_inclination.sin() * to_inclination.cos()
+                - from_inclination.cos() * to_inclination.sin() * delta.cos(),
+        )
+    }
+
+    /// Returns the GeoJSON position of the given point, in longitude and latitude degrees.
+    fn position(point: &Point<f64>) -> Vec<f64> {
+        let mut longitude = point.azimuth.into_inner();
+        if longitude > PI {
+            longitude -= TAU;
+        }
+
+        let latitude = FRAC_PI_2 - point.inclination.into_inner();
+        vec![longitude.to_degrees(), latitude.to_degrees()]
+    }
+
+    /// Returns the given ring with every longitude unwrapped towards its predecessor, keeping
+    /// points at exactly 180 degrees on the same side as the rest of the ring.
+    ///
+    /// This is only well-defined for rings spanning at most half a turn of longitude, as is the
+    /// case for any piece resulting from a hemisphere split.
+    fn unwrapped(mut ring: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+        for index in 1..ring.len() {
+            let delta = ring[index][0] - ring[index - 1][0];
+            if delta > 180. {
+                ring[index][0] -= 360.;
+            } else if delta < -180. {
+                ring[index][0] += 360.;
+            }
+        }
+
+        let beyond = ring.iter().any(|position| position[0] > 180.);
+        let below = ring.iter().any(|position| position[0] < -180.);
+        if beyond && !below {
+            ring.iter_mut().for_each(|position| position[0] -= 360.);
+        } else if below && !beyond {
+            ring.iter_mut().for_each(|position| position[0] += 360.);
+        }
+
+        ring
+    }
+
+    /// Returns true if, and only if, two consecutive positions of the given ring lie on opposite
+    /// sides of the antimeridian.
+    fn crosses_antimeridian(ring: &[Vec<f64>]) -> bool {
+        ring.windows(2)
+            .any(|pair| (pair[0][0] - pair[1][0]).abs() > 180.)
+    }
+
+    /// Returns the shapes covering each half of the sphere split along the meridian circle
+    /// through the poles.
+    fn hemispheres() -> [Shape<Polygon<f64>>; 2] {
+        let eastern = Polygon::new(
+            vec![[0., 0.], [FRAC_PI_2, 0.], [PI, 0.], [FRAC_PI_2, PI]],
+            [FRAC_PI_2, 3. * FRAC_PI_2],
+        );
+
+        let western = Polygon::new(
+            vec![[0., 0.], [FRAC_PI_2, PI], [PI, 0.], [FRAC_PI_2, 0.]],
+            [FRAC_PI_2, FRAC_PI_2],
+        );
+
+        [
+            Shape {
+                boundaries: vec![eastern],
+            },
+            Shape {
+                boundaries: vec![western],
+            },
+        ]
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+        use crate::{spherical::Polygon, spherical_polygon, Shape, Tolerance};
+
+        #[test]
+        fn densified_export_bounds_segment_length() {
+            let shape = Shape::new(spherical_polygon!(
+                [FRAC_PI_2, 0.],
+                [FRAC_PI_2, FRAC_PI_2],
+                [FRAC_PI_4, FRAC_PI_4];
+                [PI, 0.]
+            ));
+
+            let geometry = shape
+                .to_geojson_with_max_segment(0.1, Tolerance::default())
+                .expect("the export must succeed");
+
+            let ::geojson::Value::MultiPolygon(polygons) = geometry.value else {
+                panic!("the export must be a multi-polygon");
+            };
+
+            assert_eq!(polygons.len(), 1, "the shape must not be split");
+
+            let ring = &polygons[0][0];
+            assert_eq!(ring.first(), ring.last(), "the ring must be closed");
+            assert!(
+                ring.len() > shape.boundaries[0].vertices.len() + 1,
+                "the ring must be densified"
+            );
+        }
+
+        #[test]
+        fn export_splits_at_the_antimeridian() {
+            let shape = Shape::new(spherical_polygon!(
+                [1.2, PI - 0.4],
+                [1.2, PI + 0.4],
+                [1.8, PI + 0.4],
+                [1.8, PI - 0.4];
+                [0.2, 0.]
+            ));
+
+            let geometry = shape
+                .to_geojson_with_max_segment(0.1, Tolerance::default())
+                .expect("the export must succeed");
+
+            let ::geojson::Value::MultiPolygon(polygons) = geometry.value else {
+                panic!("the export must be a multi-polygon");
+            };
+
+            assert_eq!(polygons.len(), 2, "the shape must be split in two pieces");
+            polygons.iter().flatten().for_each(|ring| {
+                assert!(
+                    ring.windows(2)
+                        .all(|pair| (pair[0][0] - pair[1][0]).abs() <= 180.),
+                    "no piece must jump across the antimeridian"
+                );
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::geojson::{Feature, FeatureCollection, Geometry, JsonObject, Value};
+
+    use crate::{batch::Operation, cartesian::Polygon, Shape};
+
+    use super::{clip_feature_collection, validated_shape, GeoJsonError};
+
+    #[test]
+    fn shape_validation_reports_the_offending_ring() {
+        struct Test {
+            name: &'static str,
+            rings: Vec<Vec<Vec<f64>>>,
+            want: Result<usize, GeoJsonError>,
+        }
+
+        vec![
+            Test {
+                name: "well-formed polygon with a hole",
+                rings: vec![
+                    vec![
+                        vec![0., 0.],
+                        vec![8., 0.],
+                        vec![8., 8.],
+                        vec![0., 8.],
+                        vec![0., 0.],
+                    ],
+                    vec![
+                        vec![2., 2.],
+                        vec![2., 6.],
+                        vec![6., 6.],
+                        vec![6., 2.],
+                        vec![2., 2.],
+                    ],
+                ],
+                want: Ok(2),
+            },
+            Test {
+                name: "unclosed exterior",
+                rings: vec![vec![vec![0., 0.], vec![8., 0.], vec![8., 8.]]],
+                want: Err(GeoJsonError::UnclosedRing { polygon: 0, ring: 0 }),
+            },
+            Test {
+                name: "clockwise exterior",
+                rings: vec![vec![
+                    vec![0., 0.],
+                    vec![0., 8.],
+                    vec![8., 8.],
+                    vec![8., 0.],
+                    vec![0., 0.],
+                ]],
+                want: Err(GeoJsonError::MisorientedRing { polygon: 0, ring: 0 }),
+            },
+            Test {
+                name: "counterclockwise hole",
+                rings: vec![
+                    vec![
+                        vec![0., 0.],
+                        vec![8., 0.],
+                        vec![8., 8.],
+                        vec![0., 8.],
+                        vec![0., 0.],
+                    ],
+                    vec![
+                        vec![2., 2.],
+                        vec![6., 2.],
+                        vec![6., 6.],
+                        vec![2., 6.],
+                        vec![2., 2.],
+                    ],
+                ],
+                want: Err(GeoJsonError::MisorientedRing { polygon: 0, ring: 1 }),
+            },
+            Test {
+                name: "hole outside its exterior",
+                rings: vec![
+                    vec![
+                        vec![0., 0.],
+                        vec![8., 0.],
+                        vec![8., 8.],
+                        vec![0., 8.],
+                        vec![0., 0.],
+                    ],
+                    vec![
+                        vec![10., 10.],
+                        vec![10., 12.],
+                        vec![12., 12.],
+                        vec![12., 10.],
+                        vec![10., 10.],
+                    ],
+                ],
+                want: Err(GeoJsonError::MisnestedRing { polygon: 0, ring: 1 }),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let geometry = Geometry::new(Value::Polygon(test.rings));
+            let got = validated_shape(&geometry).map(|shape| shape.boundaries.len());
+
+            assert_eq!(got, test.want, "{}", test.name);
+        });
+    }
+
+    #[test]
+    fn feature_collection_clipping_preserves_properties() {
+        let mut properties = JsonObject::new();
+        properties.insert("name".to_string(), "subject".into());
+
+        let collection = FeatureCollection {
+            bbox: None,
+            features: vec![Feature {
+                bbox: None,
+                geometry: Some(Geometry::new(Value::Polygon(vec![vec![
+                    vec![0., 0.],
+                    vec![4., 0.],
+                    vec![4., 4.],
+                    vec![0., 4.],
+                    vec![0., 0.],
+                ]]))),
+                id: None,
+                properties: Some(properties.clone()),
+                foreign_members: None,
+            }],
+            foreign_members: None,
+        };
+
+        let mask: Shape<Polygon<f64>> = Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]);
+
+        let got = clip_feature_collection(
+            collection,
+            &mask,
+            Operation::Intersection,
+            Default::default(),
+        )
+        .expect("clipping must succeed");
+
+        assert_eq!(got.features.len(), 1, "the feature must remain");
+        assert_eq!(
+            got.features[0].properties,
+            Some(properties),
+            "the properties must be preserved"
+        );
+
+        let geometry = got.features[0]
+            .geometry
+            .as_ref()
+            .expect("the feature must keep a geometry");
+
+        let clipped = Shape::try_from(geometry).expect("the geometry must convert back");
+        let want: Shape<Polygon<f64>> = Shape::new(vec![[2., 2.], [4., 2.], [4., 4.], [2., 4.]]);
+        assert_eq!(clipped, want, "the geometry must be clipped by the mask");
+    }
+}
@@ -0,0 +1,300 @@
+//! Experimental GPU-offloaded broad phase for batch intersection workloads.
+//!
+//! The quadratic part of a clipping operation is pairing every subject edge with every clip
+//! edge. For massive batch workloads that pairing can be offloaded to a compute shader: the GPU
+//! performs a conservative bounding-box test per pair and returns the candidate crossings, which
+//! are then confirmed exactly on the CPU through the same [`Segment::intersection`] the clipper
+//! uses. Coordinates are single precision on the GPU, so the broad phase may report spurious
+//! candidates near the resolution of `f32`, but never misses a true one by more than the
+//! padding applied to each box.
+//!
+//! This module is experimental: dispatch limits cap the workload at roughly four million edge
+//! pairs per call, and falling back to the CPU when no adapter is available is the caller's
+//! responsibility, as signalled by [`GpuBroadPhase::new`] returning none.
+//!
+//! [`Segment::intersection`]: crate::Edge::intersection
+
+use crate::{
+    cartesian::{Point, Polygon, Segment},
+    Edge, Geometry, Intersection, Shape, Tolerance,
+};
+
+/// The workgroup size the shader is compiled with.
+const WORKGROUP_SIZE: u32 = 64;
+
+/// The bounding-box overlap shader.
+const SHADER: &str = r#"
+struct Box {
+    min_x: f32,
+    min_y: f32,
+    max_x: f32,
+    max_y: f32,
+}
+
+@group(0) @binding(0) var<storage, read> subject: array<Box>;
+@group(0) @binding(1) var<storage, read> clip: array<Box>;
+@group(0) @binding(2) var<storage, read_write> flags: array<u32>;
+
+@compute @workgroup_size(64)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let total = arrayLength(&subject) * arrayLength(&clip);
+    if (id.x >= total) {
+        return;
+    }
+
+    let a = subject[id.x / arrayLength(&clip)];
+    let b = clip[id.x % arrayLength(&clip)];
+    let overlap = a.min_x <= b.max_x && a.max_x >= b.min_x
+        && a.min_y <= b.max_y && a.max_y >= b.min_y;
+
+    flags[id.x] = select(0u, 1u, overlap);
+}
+"#;
+
+/// A GPU-backed broad phase pairing operand edges through their bounding boxes.
+#[derive(Debug)]
+pub struct GpuBroadPhase {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+}
+
+impl GpuBroadPhase {
+    /// Returns a broad phase running on the first available adapter, or none if the host
+    /// exposes no usable GPU.
+    pub fn new() -> Option<Self> {
+        let instance = wgpu::Instance::default();
+        let adapter =
+            pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))?;
+
+        let (device, queue) =
+            pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default(), None))
+                .ok()?;
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("boolygon broad phase"),
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        });
+
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("boolygon broad phase"),
+            layout: None,
+            module: &shader,
+            entry_point: Some("main"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        Some(Self {
+            device,
+            queue,
+            pipeline,
+        })
+    }
+
+    /// Returns the positions of every pair of edges whose bounding boxes overlap, pairing each
+    /// edge of the subject with each edge of the clip.
+    ///
+    /// Edges are numbered across boundaries in traversal order, matching the enumeration of
+    /// [`crate::intersections`]. The test is conservative: every truly intersecting pair is
+    /// reported, along with any pair whose boxes overlap without the edges crossing.
+    pub fn candidate_pairs(
+        &self,
+        subject: &Shape<Polygon<f32>>,
+        clip: &Shape<Polygon<f32>>,
+    ) -> Vec<(usize, usize)> {
+        let subject_boxes = edge_boxes(subject);
+        let clip_boxes = edge_boxes(clip);
+        if subject_boxes.is_empty() || clip_boxes.is_empty() {
+            return Vec::new();
+        }
+
+        let total = subject_boxes.len() * clip_boxes.len();
+        let subject_buffer = self.storage_buffer(
+            &box_bytes(&subject_boxes),
+            wgpu::BufferUsages::STORAGE,
+        );
+        let clip_buffer = self.storage_buffer(&box_bytes(&clip_boxes), wgpu::BufferUsages::STORAGE);
+
+        let flags_size = (total * size_of::<u32>()) as u64;
+        let flags_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: flags_size,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        let staging_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: flags_size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &self.pipeline.get_bind_group_layout(0),
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: subject_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: clip_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: flags_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups((total as u32).div_ceil(WORKGROUP_SIZE), 1, 1);
+        }
+
+        encoder.copy_buffer_to_buffer(&flags_buffer, 0, &staging_buffer, 0, flags_size);
+        self.queue.submit([encoder.finish()]);
+
+        let slice = staging_buffer.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |_| {});
+        self.device.poll(wgpu::Maintain::Wait);
+
+        let flags = slice.get_mapped_range();
+        let candidates = flags
+            .chunks_exact(size_of::<u32>())
+            .enumerate()
+            .filter(|(_, flag)| flag.iter().any(|&byte| byte != 0))
+            .map(|(position, _)| (position / clip_boxes.len(), position % clip_boxes.len()))
+            .collect();
+
+        drop(flags);
+        staging_buffer.unmap();
+
+        candidates
+    }
+
+    /// Returns every [`Intersection`] between the edges of the subject and those of the clip,
+    /// confirming the GPU candidates exactly on the CPU.
+    pub fn intersections(
+        &self,
+        subject: &Shape<Polygon<f32>>,
+        clip: &Shape<Polygon<f32>>,
+        tolerance: &Tolerance<f32>,
+    ) -> Vec<Intersection<Point<f32>>> {
+        let subject_edges = edge_endpoints(subject);
+        let clip_edges = edge_endpoints(clip);
+
+        self.candidate_pairs(subject, clip)
+            .into_iter()
+            .filter_map(|(left, right)| {
+                let (from, to) = &subject_edges[left];
+                let (other_from, other_to) = &clip_edges[right];
+
+                Segment::new(from, to)
+                    .intersection(&Segment::new(other_from, other_to), tolerance)
+                    .map(|intersection| Intersection {
+                        left,
+                        right,
+                        intersection,
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns a storage buffer holding the given bytes.
+    fn storage_buffer(&self, bytes: &[u8], usage: wgpu::BufferUsages) -> wgpu::Buffer {
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: bytes.len() as u64,
+            usage,
+            mapped_at_creation: true,
+        });
+
+        buffer
+            .slice(..)
+            .get_mapped_range_mut()
+            .copy_from_slice(bytes);
+        buffer.unmap();
+
+        buffer
+    }
+}
+
+/// Returns the bounding box of each edge of the shape, across boundaries in traversal order.
+fn edge_boxes(shape: &Shape<Polygon<f32>>) -> Vec<[f32; 4]> {
+    shape
+        .boundaries
+        .iter()
+        .flat_map(|boundary| boundary.edges())
+        .map(|segment| {
+            [
+                segment.from.x.min(segment.to.x),
+                segment.from.y.min(segment.to.y),
+                segment.from.x.max(segment.to.x),
+                segment.from.y.max(segment.to.y),
+            ]
+        })
+        .collect()
+}
+
+/// Returns the endpoints of each edge of the shape, across boundaries in traversal order.
+fn edge_endpoints(shape: &Shape<Polygon<f32>>) -> Vec<(Point<f32>, Point<f32>)> {
+    shape
+        .boundaries
+        .iter()
+        .flat_map(|boundary| boundary.edges())
+        .map(|segment| (*segment.from, *segment.to))
+        .collect()
+}
+
+/// Returns the given boxes as the byte layout the shader expects.
+fn box_bytes(boxes: &[[f32; 4]]) -> Vec<u8> {
+    boxes
+        .iter()
+        .flatten()
+        .flat_map(|coordinate| coordinate.to_le_bytes())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian::Polygon, Shape, Tolerance};
+
+    use super::GpuBroadPhase;
+
+    #[test]
+    fn gpu_candidates_cover_every_crossing() {
+        // Hosts without a GPU cannot exercise the broad phase; the construction path is still
+        // covered by reaching this point.
+        let Some(gpu) = GpuBroadPhase::new() else {
+            return;
+        };
+
+        let subject: Shape<Polygon<f32>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip: Shape<Polygon<f32>> = Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]);
+
+        let candidates = gpu.candidate_pairs(&subject, &clip);
+        assert!(
+            candidates.contains(&(1, 0)) && candidates.contains(&(2, 3)),
+            "both true crossings must be candidates"
+        );
+
+        let confirmed = gpu.intersections(&subject, &clip, &Tolerance::default());
+        let exact = crate::intersections(
+            subject.boundaries[0].edges(),
+            clip.boundaries[0].edges(),
+            &Tolerance::default(),
+        );
+
+        assert_eq!(confirmed, exact, "confirmation must match the CPU pairing");
+    }
+}
@@ -3,7 +3,28 @@ use std::{
     collections::{BTreeMap, BTreeSet},
 };
 
-use crate::{either::Either, Edge, Geometry, IsClose, Shape, Vertex};
+use crate::{
+    either::Either,
+    options::{ClipError, ClipOptions, Progress},
+    report::Touch,
+    Edge, Geometry, IsClose, Shape, Vertex,
+};
+
+/// A vector of graph positions that avoids heap allocation while small.
+///
+/// Most edges are involved in very few intersections, so the handful of positions tracked per
+/// edge fits in the inline buffer when the `smallvec` feature is enabled.
+#[cfg(feature = "smallvec")]
+pub(crate) type PositionVec = smallvec::SmallVec<[usize; 4]>;
+#[cfg(not(feature = "smallvec"))]
+pub(crate) type PositionVec = Vec<usize>;
+
+/// The index of a [`Node`] inside the [`Graph`].
+///
+/// Links between nodes are stored as `u32`, halving the memory spent on indices compared to
+/// pointer-sized ones. This bounds graphs to [`u32::MAX`] nodes, which is far beyond the point
+/// where clipping becomes impractical anyway.
+pub(crate) type NodeIndex = u32;
 
 /// The role of the boundary at the inner position in the [`Graph`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -28,6 +49,7 @@ impl BoundaryRole {
 }
 
 /// A boundary in the [`Graph`].
+#[derive(Debug, Clone, Copy)]
 pub(crate) struct Boundary {
     /// The amount of intersections in this boundary.
     pub(crate) intersection_count: usize,
@@ -39,7 +61,7 @@ pub(crate) struct Boundary {
 
 /// The kind of intersection being represented by a [`Node`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum IntersectionKind {
+pub enum IntersectionKind {
     /// The shape is entering into the oposite one.
     Entry,
     /// The shape is exiting from the oposite one.
@@ -56,8 +78,19 @@ impl IntersectionKind {
     }
 }
 
+/// The side of the opposite [`Shape`] at which the midpoint of a portion of an edge lies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    /// The midpoint lies strictly inside the opposite shape.
+    Inside,
+    /// The midpoint lies strictly outside the opposite shape.
+    Outside,
+    /// The midpoint lies on a boundary of the opposite shape.
+    Coincident,
+}
+
 /// The intersection data of a [`Node`].
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub(crate) struct Intersection {
     /// Indicates whether this intersection is a pseudo-intersection.
     ///
@@ -69,11 +102,11 @@ pub(crate) struct Intersection {
     /// Whether the boundary is entering or exiting the opposite one.
     pub(crate) kind: Option<IntersectionKind>,
     /// Vertices from the oposite shape located at the same point.
-    pub(crate) siblings: BTreeSet<usize>,
+    pub(crate) siblings: BTreeSet<NodeIndex>,
 }
 
-impl FromIterator<usize> for Intersection {
-    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+impl FromIterator<NodeIndex> for Intersection {
+    fn from_iter<T: IntoIterator<Item = NodeIndex>>(iter: T) -> Self {
         Self {
             siblings: FromIterator::from_iter(iter),
             ..Default::default()
@@ -90,7 +123,7 @@ impl Intersection {
 
 /// A vertex and its metadata inside a graph.
 #[derive(Debug)]
-pub(crate) struct Node<T>
+pub struct Node<T>
 where
     T: Geometry,
 {
@@ -99,20 +132,79 @@ where
     /// The boundary at which this node belongs.
     pub(crate) boundary: BoundaryRole,
     /// The index of the node previous to this one.
-    pub(crate) previous: usize,
+    pub(crate) previous: NodeIndex,
     /// The index of the node following this one.
-    pub(crate) next: usize,
+    pub(crate) next: NodeIndex,
     /// The intersection info of this node.
     pub(crate) intersection: Intersection,
 }
 
+// A derived implementation would require `T: Clone`, even though only vertices are held.
+impl<T> Clone for Node<T>
+where
+    T: Geometry,
+    T::Vertex: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            vertex: self.vertex.clone(),
+            boundary: self.boundary,
+            previous: self.previous,
+            next: self.next,
+            intersection: self.intersection.clone(),
+        }
+    }
+}
+
+impl<T> Node<T>
+where
+    T: Geometry,
+{
+    /// Returns the vertex being represented by this node.
+    pub fn vertex(&self) -> &T::Vertex {
+        &self.vertex
+    }
+
+    /// Returns true if, and only if, this node belongs to the subject shape.
+    pub fn is_subject(&self) -> bool {
+        self.boundary.is_subject()
+    }
+
+    /// Returns the [`IntersectionKind`] of this node, if it is an intersection.
+    pub fn intersection_kind(&self) -> Option<IntersectionKind> {
+        self.intersection.kind
+    }
+}
+
 /// A graph of vertices and its relations.
+///
+/// The construction of the graph is fully deterministic: nodes are indexed in input order, every
+/// associative container involved is ordered, and ambiguous orderings are resolved through
+/// explicit tie-breaking keys. Identical inputs therefore always produce identical graphs,
+/// regardless of the platform.
 pub(crate) struct Graph<T>
 where
     T: Geometry,
 {
     pub(crate) nodes: Vec<Option<Node<T>>>,
     pub(crate) boundaries: Vec<Boundary>,
+    /// The points where both shapes touch without crossing each other.
+    pub(crate) touches: Vec<Touch<T::Vertex>>,
+}
+
+// A derived implementation would require `T: Clone`, even though only vertices are held.
+impl<T> Clone for Graph<T>
+where
+    T: Geometry,
+    T::Vertex: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            nodes: self.nodes.clone(),
+            boundaries: self.boundaries.clone(),
+            touches: self.touches.clone(),
+        }
+    }
 }
 
 impl<T> Default for Graph<T>
@@ -123,6 +215,7 @@ where
         Self {
             nodes: Default::default(),
             boundaries: Default::default(),
+            touches: Default::default(),
         }
     }
 }
@@ -137,7 +230,12 @@ where
 {
     nodes: Vec<Node<T>>,
     boundaries: Vec<Boundary>,
-    tolerance: &'a <T::Vertex as IsClose>::Tolerance,
+    touches: Vec<Touch<T::Vertex>>,
+    /// The tolerance through which edges are paired in search of intersections.
+    pairing_tolerance: <T::Vertex as IsClose>::Tolerance,
+    /// The tolerance through which intersections are classified.
+    classification_tolerance: <T::Vertex as IsClose>::Tolerance,
+    options: &'a ClipOptions,
     subject: S,
     clip: C,
 }
@@ -146,11 +244,19 @@ impl<'a, T> GraphBuilder<'a, T, Unknown, Unknown>
 where
     T: Geometry,
 {
-    pub(crate) fn new(tolerance: &'a <T::Vertex as IsClose>::Tolerance) -> Self {
+    pub(crate) fn new(tolerance: &<T::Vertex as IsClose>::Tolerance, options: &'a ClipOptions) -> Self
+    where
+        <T::Vertex as IsClose>::Tolerance: Clone,
+    {
         Self {
             nodes: Default::default(),
             boundaries: Default::default(),
-            tolerance,
+            touches: Default::default(),
+            pairing_tolerance: options.tolerance_overrides.intersection::<T::Vertex>(tolerance),
+            classification_tolerance: options
+                .tolerance_overrides
+                .classification::<T::Vertex>(tolerance),
+            options,
             subject: Unknown,
             clip: Unknown,
         }
@@ -164,150 +270,251 @@ where
     <T::Vertex as Vertex>::Scalar: PartialOrd,
 {
     /// Populates the graph with all the intersections.
-    fn with_intersections(mut self) -> Self {
-        let intersections = self.intersections();
+    fn with_intersections(mut self) -> Result<Self, ClipError> {
+        let intersections = self.intersections()?;
         let mut visited = PartialOrdBTreeMap::new();
         for (edge, mut intersection_indexes) in intersections.by_edge {
-            let &Node {
+            if self.options.nodes_exceeded(self.nodes.len()) {
+                return Err(ClipError::NodesLimitExceeded);
+            }
+
+            let Some(&Node {
                 vertex: first,
                 boundary,
                 next,
                 ..
-            } = &self.nodes[edge];
+            }) = self.nodes.get(edge)
+            else {
+                return Err(ClipError::Internal("intersection refers to a missing edge"));
+            };
 
-            let last = self.nodes[next].vertex;
+            let next = next as usize;
+            let Some(&Node { vertex: last, .. }) = self.nodes.get(next) else {
+                return Err(ClipError::Internal("edge refers to a missing endpoint"));
+            };
 
             // Sorting the intersections by its distance to the edge starting point ensures each
             // intersection will "cut" the edge in order of appearance, preserving its original
-            // direction.
+            // direction. Distance ties are broken by comparing the vertices themselves, so the
+            // resulting order never depends on the insertion one.
             intersection_indexes.sort_by(|&a, &b| {
+                let a = &intersections.all[a].vertex;
+                let b = &intersections.all[b].vertex;
+
                 first
-                    .distance(&intersections.all[a].vertex)
-                    .partial_cmp(&first.distance(&intersections.all[b].vertex))
+                    .distance(a)
+                    .partial_cmp(&first.distance(b))
                     .unwrap_or(Ordering::Equal)
+                    .then_with(|| a.partial_cmp(b).unwrap_or(Ordering::Equal))
             });
 
-            intersection_indexes
+            let mut previous = edge;
+            for chunk in intersection_indexes
                 .chunk_by(|&a, &b| intersections.all[a].vertex == intersections.all[b].vertex)
-                .fold(edge, |previous, chunk| {
-                    let intersection_point = intersections.all[chunk[0]].vertex;
-
-                    let index = if intersection_point == first {
-                        // If the intersection point equals the edge starting point there is
-                        // nothing to add into the graph. The index of this intersection in the
-                        // graph is the index of the starting point.
-                        edge
-                    } else if intersection_point == last {
-                        // Likewise, if the intersection point equals the edge final point there is
-                        // nothing to add into the graph. The index of this intersection in the
-                        // graph is the index of the final point.
-                        next
-                    } else {
-                        // Otherwise, the intersection point is a new point somewhere between of
-                        // the endpoints of the edge.
-                        self.nodes.len()
+            {
+                let intersection_point = intersections.all[chunk[0]].vertex;
+
+                let index = if intersection_point == first {
+                    // If the intersection point equals the edge starting point there is
+                    // nothing to add into the graph. The index of this intersection in the
+                    // graph is the index of the starting point.
+                    edge
+                } else if intersection_point == last {
+                    // Likewise, if the intersection point equals the edge final point there is
+                    // nothing to add into the graph. The index of this intersection in the
+                    // graph is the index of the final point.
+                    next
+                } else {
+                    // Otherwise, the intersection point is a new point somewhere between of
+                    // the endpoints of the edge.
+                    self.nodes.len()
+                };
+
+                // Mark this intersection point as been visited by this edge. This will allow
+                // siblings from the oposite shape to know about its index in the graph.
+                visited.insert((edge, intersection_point), index);
+
+                // Count this intersection into the corresponding boundary.
+                let Some(intersected) = self.boundaries.get_mut(boundary.position()) else {
+                    return Err(ClipError::Internal("node refers to a missing boundary"));
+                };
+
+                intersected.intersection_count += 1;
+
+                let siblings = chunk
+                    .iter()
+                    .map(|&index| {
+                        // Select the oposite shape of this intersection.
+                        // e.g. If this edge belong to the clip shape, return the subject edge
+                        // involved in the intersection.
+                        if edge == intersections.all[index].clip {
+                            intersections.all[index].subject
+                        } else {
+                            intersections.all[index].clip
+                        }
+                    })
+                    .filter_map(|edge| {
+                        // Get the index of the intersection point on that edge, if is already
+                        // set.
+                        visited.get((edge, intersection_point))
+                    })
+                    .copied()
+                    .collect::<PositionVec>();
+
+                for &sibling in &siblings {
+                    // Update the siblings list of each sibling by adding the index of this
+                    // intersection.
+                    let Some(sibling) = self.nodes.get_mut(sibling) else {
+                        return Err(ClipError::Internal("intersection refers to a missing sibling"));
                     };
 
-                    // Mark this intersection point as been visited by this edge. This will allow
-                    // siblings from the oposite shape to know about its index in the graph.
-                    visited.insert((edge, intersection_point), index);
-
-                    // Count this intersection into the corresponding boundary.
-                    self.boundaries[boundary.position()].intersection_count += 1;
-
-                    let siblings = chunk
-                        .iter()
-                        .map(|&index| {
-                            // Select the oposite shape of this intersection.
-                            // e.g. If this edge belong to the clip shape, return the subject edge
-                            // involved in the intersection.
-                            if edge == intersections.all[index].clip {
-                                intersections.all[index].subject
-                            } else {
-                                intersections.all[index].clip
-                            }
-                        })
-                        .filter_map(|edge| {
-                            // Get the index of the intersection point on that edge, if is already
-                            // set.
-                            visited.get((edge, intersection_point))
-                        })
-                        .copied()
-                        .inspect(|&sibling| {
-                            // While searching for siblings, update their siblings list by adding
-                            // the index of this intersection.
-                            self.nodes[sibling].intersection.siblings.insert(index);
-                        })
-                        .collect::<Vec<_>>();
-
-                    if [first, last].contains(&intersection_point) {
-                        // If the intersection point is any of the endpoints of the edge, do not
-                        // create any node in the graph. Instead finds that endpoint and update
-                        // the siblings list.
-                        self.nodes[index].intersection.siblings.extend(siblings);
-                        self.nodes[index].intersection.is_pseudo = true;
-                    } else {
-                        // Cut the edge and register the new vertex.
-                        let next = self.nodes[previous].next;
-
-                        self.nodes[previous].next = index;
-
-                        self.nodes[next].previous = index;
-
-                        self.nodes.push(Node {
-                            vertex: intersection_point,
-                            intersection: FromIterator::from_iter(siblings),
-                            boundary,
-                            previous,
-                            next,
-                        });
+                    sibling.intersection.siblings.insert(index as NodeIndex);
+                }
+
+                if [first, last].contains(&intersection_point) {
+                    // If the intersection point is any of the endpoints of the edge, do not
+                    // create any node in the graph. Instead finds that endpoint and update
+                    // the siblings list.
+                    let Some(endpoint) = self.nodes.get_mut(index) else {
+                        return Err(ClipError::Internal("intersection refers to a missing endpoint"));
                     };
 
-                    index
-                });
+                    endpoint
+                        .intersection
+                        .siblings
+                        .extend(siblings.into_iter().map(|sibling| sibling as NodeIndex));
+                    endpoint.intersection.is_pseudo = true;
+                } else {
+                    // Cut the edge and register the new vertex.
+                    let Some(previous_node) = self.nodes.get_mut(previous) else {
+                        return Err(ClipError::Internal("edge refers to a missing predecessor"));
+                    };
+
+                    let next = previous_node.next;
+                    previous_node.next = index as NodeIndex;
+
+                    let Some(next_node) = self.nodes.get_mut(next as usize) else {
+                        return Err(ClipError::Internal("edge refers to a missing successor"));
+                    };
+
+                    next_node.previous = index as NodeIndex;
+
+                    self.nodes.push(Node {
+                        vertex: intersection_point,
+                        intersection: siblings
+                            .into_iter()
+                            .map(|sibling| sibling as NodeIndex)
+                            .collect(),
+                        boundary,
+                        previous: previous as NodeIndex,
+                        next,
+                    });
+                };
+
+                previous = index;
+            }
         }
 
-        self
+        Ok(self)
     }
 
     /// Returns the graph.
-    pub(crate) fn build(self) -> Graph<T> {
-        let builder = self.with_intersections().with_statuses();
+    ///
+    /// Fails if, and only if, the construction is interrupted before completing.
+    pub(crate) fn build(self) -> Result<Graph<T>, ClipError> {
+        if self.options.nodes_exceeded(self.nodes.len()) {
+            return Err(ClipError::NodesLimitExceeded);
+        }
 
-        Graph {
+        if self.nodes.len() > NodeIndex::MAX as usize {
+            return Err(ClipError::Internal("graph exceeds the node index space"));
+        }
+
+        let builder = self.with_intersections()?.with_overlaps().with_statuses()?;
+
+        Ok(Graph {
             nodes: builder.nodes.into_iter().map(Some).collect(),
             boundaries: builder.boundaries,
-        }
+            touches: builder.touches,
+        })
     }
 }
 
 impl<T> GraphBuilder<'_, T, &Shape<T>, &Shape<T>>
 where
     T: Geometry,
+    T::Vertex: Copy,
 {
     /// Returns a record of all the intersections between the edges of the subject and clip shapes.
-    fn intersections(&self) -> EdgeIntersections<T> {
+    fn intersections(&self) -> Result<EdgeIntersections<T>, ClipError> {
         let edges_of = |boundary: &Boundary| Edges {
             nodes: &self.nodes,
             start: boundary.start,
             next: None,
         };
 
+        let total_edges = self
+            .subject
+            .boundaries
+            .iter()
+            .map(Geometry::total_vertices)
+            .sum::<usize>()
+            * self
+                .clip
+                .boundaries
+                .iter()
+                .map(Geometry::total_vertices)
+                .sum::<usize>();
+
+        let mut edges_processed = 0;
         let mut intersections = EdgeIntersections::default();
         for subject_boundary in self
             .boundaries
             .iter()
             .filter(|boundary| boundary.role.is_subject())
         {
+            let subject_geometry = self.subject.boundaries.get(subject_boundary.role.position());
+
             for clip_boundary in self
                 .boundaries
                 .iter()
                 .filter(|boundary| !boundary.role.is_subject())
             {
+                if self.options.cancelled() {
+                    return Err(ClipError::Cancelled);
+                }
+
+                if self.options.intersections_exceeded(intersections.all.len()) {
+                    return Err(ClipError::IntersectionsLimitExceeded);
+                }
+
+                let clip_geometry = clip_boundary
+                    .role
+                    .position()
+                    .checked_sub(self.subject.boundaries.len())
+                    .and_then(|position| self.clip.boundaries.get(position));
+
+                edges_processed += subject_geometry
+                    .zip(clip_geometry)
+                    .map(|(subject, clip)| subject.total_vertices() * clip.total_vertices())
+                    .unwrap_or_default();
+
+                if let (Some(subject), Some(clip)) = (subject_geometry, clip_geometry)
+                    && !subject.might_intersect(clip)
+                {
+                    self.options.report_progress(Progress::SearchingIntersections {
+                        edges_processed,
+                        total_edges,
+                        intersections: intersections.all.len(),
+                    });
+
+                    continue;
+                }
+
                 for (subject_index, subject_edge) in edges_of(subject_boundary) {
                     for (clip_index, clip_edge) in edges_of(clip_boundary) {
                         if let Some(intersection) =
-                            subject_edge.intersection(&clip_edge, self.tolerance)
+                            subject_edge.intersection(&clip_edge, &self.pairing_tolerance)
                         {
                             intersections = match intersection {
                                 Either::Left(vertex) => {
@@ -335,10 +542,126 @@ where
                         };
                     }
                 }
+
+                self.options.report_progress(Progress::SearchingIntersections {
+                    edges_processed,
+                    total_edges,
+                    intersections: intersections.all.len(),
+                });
+            }
+        }
+
+        if self.options.intersections_exceeded(intersections.all.len()) {
+            return Err(ClipError::IntersectionsLimitExceeded);
+        }
+
+        Ok(intersections)
+    }
+
+    /// Returns the [`Side`] of the opposite shape at which lies the midpoint between the [`Node`]
+    /// at the given position and the given neighbour.
+    fn side(&self, position: usize, neighbour: usize) -> Side {
+        let node = &self.nodes[position];
+        let oposite = match node.boundary {
+            BoundaryRole::Subject(_) => self.clip,
+            BoundaryRole::Clip(_) => self.subject,
+        };
+
+        let midpoint = T::Edge::new(&node.vertex, &self.nodes[neighbour].vertex).midpoint();
+        if oposite.is_boundary(&midpoint, &self.classification_tolerance) {
+            Side::Coincident
+        } else if oposite.contains_with(&midpoint, &self.classification_tolerance, self.options.fill_rule) {
+            Side::Inside
+        } else {
+            Side::Outside
+        }
+    }
+
+    /// Returns the first non-coincident [`Side`] found when walking the boundary of the [`Node`]
+    /// at the given position, skipping the edges lying on the opposite shape.
+    ///
+    /// Walking forward probes the side beyond the node's next edge, walking backward the one
+    /// beyond its previous edge. When every edge of the boundary is coincident, as happens with
+    /// identical operands, there is no such side and [`Side::Coincident`] is returned.
+    fn resolved_side(&self, position: usize, forward: bool) -> Side {
+        let mut current = position;
+        for _ in 0..self.nodes.len() {
+            let node = &self.nodes[current];
+            let neighbour = if forward { node.next } else { node.previous } as usize;
+            match self.side(current, neighbour) {
+                Side::Coincident => current = neighbour,
+                side => return side,
+            }
+        }
+
+        Side::Coincident
+    }
+
+    /// Classifies the intersections lying on collinear overlapping edges.
+    ///
+    /// When a run of edges from one shape coincides with a run from the other, the midpoint
+    /// probes of [`Self::is_intersection`] fall on the opposite boundary and decide nothing.
+    /// This pass resolves each run as a whole instead: the run crosses if, and only if, walking
+    /// past its coincident edges leads inside the opposite shape on one end and outside on the
+    /// other. The node adjacent to the outside becomes the crossing, carrying the [`Entry`] or
+    /// [`Exit`] of the whole run, while the remaining nodes of the run become regular vertices.
+    /// Runs reaching the same side on both ends are left to the pseudo-intersection handling of
+    /// [`Self::with_statuses`], which already classifies them correctly.
+    ///
+    /// [`Entry`]: IntersectionKind::Entry
+    /// [`Exit`]: IntersectionKind::Exit
+    fn with_overlaps(mut self) -> Self {
+        for position in 0..self.nodes.len() {
+            let node = &self.nodes[position];
+            if !node.boundary.is_subject() || !node.intersection.has_siblings() {
+                continue;
+            }
+
+            let previous = self.side(position, node.previous as usize);
+            let next = self.side(position, node.next as usize);
+            if previous != Side::Coincident && next != Side::Coincident {
+                continue;
+            }
+
+            let resolved = [
+                self.resolved_side(position, false),
+                self.resolved_side(position, true),
+            ];
+
+            if resolved[0] == resolved[1] || resolved.contains(&Side::Coincident) {
+                continue;
+            }
+
+            if previous != Side::Outside && next != Side::Outside {
+                // The node joins the run with the interior of the opposite shape: the crossing
+                // of the run belongs to its other end.
+                self.downgrade_intersection(position);
+                continue;
+            }
+
+            let kind = |resolved_previous| {
+                if resolved_previous == Side::Inside {
+                    IntersectionKind::Exit
+                } else {
+                    IntersectionKind::Entry
+                }
+            };
+
+            self.nodes[position].intersection.kind = Some(kind(resolved[0]));
+            let siblings: PositionVec = self.nodes[position]
+                .intersection
+                .siblings
+                .iter()
+                .map(|&sibling| sibling as usize)
+                .collect();
+
+            for sibling in siblings {
+                let resolved_previous = self.resolved_side(sibling, false);
+                self.nodes[sibling].intersection.kind = Some(kind(resolved_previous));
             }
         }
 
-        intersections
+        self
     }
 
     /// Returns the [`IntersectionKind`] corresponding to the [`Node`] at the given position.
@@ -350,13 +673,13 @@ where
         };
 
         let previous = if node.intersection.has_siblings() {
-            let previous = &self.nodes[node.previous];
+            let previous = &self.nodes[node.previous as usize];
             &T::Edge::new(&previous.vertex, &node.vertex).midpoint()
         } else {
             &node.vertex
         };
 
-        if boundary.contains(previous, self.tolerance) {
+        if boundary.contains_with(previous, &self.classification_tolerance, self.options.fill_rule) {
             IntersectionKind::Exit
         } else {
             IntersectionKind::Entry
@@ -365,9 +688,16 @@ where
 
     /// Returns true if, and only if, the [`Node`] at the given position is indeed an intersection.
     fn is_intersection(&self, position: usize) -> bool {
-        let node = &self.nodes[position];
-        let previous = &self.nodes[node.previous];
-        let next = &self.nodes[node.next];
+        let Some(node) = self.nodes.get(position) else {
+            return false;
+        };
+
+        let (Some(previous), Some(next)) = (
+            self.nodes.get(node.previous as usize),
+            self.nodes.get(node.next as usize),
+        ) else {
+            return false;
+        };
 
         if previous.intersection.is_pseudo && next.intersection.is_pseudo {
             return false;
@@ -380,52 +710,83 @@ where
             BoundaryRole::Clip(_) => self.subject,
         };
 
-        oposite.contains(&previous, self.tolerance) != oposite.contains(&next, self.tolerance)
+        oposite.contains_with(&previous, &self.classification_tolerance, self.options.fill_rule)
+            != oposite.contains_with(&next, &self.classification_tolerance, self.options.fill_rule)
     }
 
     /// Downgrades the [`Node`] at the given position from intersection to non-intersection.
     fn downgrade_intersection(&mut self, position: usize) {
-        let node = &mut self.nodes[position];
+        let Some(node) = self.nodes.get_mut(position) else {
+            return;
+        };
+
         if !node.intersection.has_siblings() {
             return;
         }
 
-        let boundary = &mut self.boundaries[node.boundary.position()];
+        let is_pseudo = node.intersection.is_pseudo;
+        let Some(boundary) = self.boundaries.get_mut(node.boundary.position()) else {
+            return;
+        };
+
         boundary.intersection_count = boundary
             .intersection_count
-            .saturating_sub(if node.intersection.is_pseudo { 2 } else { 1 });
+            .saturating_sub(if is_pseudo { 2 } else { 1 });
 
         node.intersection.kind.take();
         std::mem::take(&mut self.nodes[position].intersection.siblings)
             .into_iter()
-            .for_each(|sibling| self.downgrade_intersection(sibling));
+            .for_each(|sibling| self.downgrade_intersection(sibling as usize));
     }
 
     /// Computes the [`Status`] of each intersection [`Node`] in the graph.
-    fn with_statuses(mut self) -> Self {
+    fn with_statuses(mut self) -> Result<Self, ClipError> {
         for boundary in 0..self.boundaries.len() {
+            if self.options.cancelled() {
+                return Err(ClipError::Cancelled);
+            }
+
             let start = self.boundaries[boundary].start;
+            if start >= self.nodes.len() {
+                return Err(ClipError::Internal("boundary starts at a missing node"));
+            }
 
             let mut intersection_traversal = IntersectionSearch::new(start);
             let mut intersection_kind = self.intersection_kind(start);
 
             while let Some(node) = intersection_traversal.next(&self.nodes) {
+                if let Some(kind) = self.nodes[node].intersection.kind {
+                    // The node crosses through a run of coincident edges and was classified by
+                    // the overlaps pass; realign the alternation with its kind and move on.
+                    intersection_kind = kind.oposite();
+                    continue;
+                }
+
                 if self.nodes[node].intersection.is_pseudo && !self.is_intersection(node) {
+                    // A pseudo-intersection that does not cross is a tangential touch; report it
+                    // before the downgrade clears the evidence.
+                    let touched = &self.nodes[node];
+                    self.touches.push(Touch {
+                        vertex: touched.vertex,
+                        multiplicity: touched.intersection.siblings.len() + 1,
+                    });
+
                     self.downgrade_intersection(node);
-                } else {
-                    self.nodes[node].intersection.kind = Some(intersection_kind);
+                } else if let Some(node) = self.nodes.get_mut(node) {
+                    node.intersection.kind = Some(intersection_kind);
                     intersection_kind = intersection_kind.oposite();
                 }
             }
         }
 
-        self
+        Ok(self)
     }
 }
 
 impl<'a, T, S, C> GraphBuilder<'a, T, S, C>
 where
-    T: Geometry + Clone + IntoIterator<Item = T::Vertex>,
+    T: Geometry,
+    T::Vertex: Copy,
 {
     pub(crate) fn with_subject(
         self,
@@ -434,39 +795,47 @@ where
         GraphBuilder {
             nodes: self.nodes,
             boundaries: self.boundaries,
-            tolerance: self.tolerance,
+            touches: self.touches,
+            pairing_tolerance: self.pairing_tolerance,
+            classification_tolerance: self.classification_tolerance,
+            options: self.options,
             clip: self.clip,
             subject,
         }
-        .with_shape(subject.clone(), BoundaryRole::Subject)
+        .with_shape(subject, BoundaryRole::Subject)
     }
 }
 
 impl<'a, T, S, C> GraphBuilder<'a, T, S, C>
 where
-    T: Geometry + Clone + IntoIterator<Item = T::Vertex>,
+    T: Geometry,
+    T::Vertex: Copy,
 {
     pub(crate) fn with_clip(self, clip: &'a Shape<T>) -> GraphBuilder<'a, T, S, &'a Shape<T>> {
         GraphBuilder {
             nodes: self.nodes,
             boundaries: self.boundaries,
-            tolerance: self.tolerance,
+            touches: self.touches,
+            pairing_tolerance: self.pairing_tolerance,
+            classification_tolerance: self.classification_tolerance,
+            options: self.options,
             subject: self.subject,
             clip,
         }
-        .with_shape(clip.clone(), BoundaryRole::Clip)
+        .with_shape(clip, BoundaryRole::Clip)
     }
 }
 
 impl<T, S, C> GraphBuilder<'_, T, S, C>
 where
-    T: Geometry + IntoIterator<Item = T::Vertex>,
+    T: Geometry,
+    T::Vertex: Copy,
 {
-    fn with_shape(mut self, shape: Shape<T>, role: impl Fn(usize) -> BoundaryRole) -> Self {
+    fn with_shape(mut self, shape: &Shape<T>, role: impl Fn(usize) -> BoundaryRole) -> Self {
         self.nodes.reserve(shape.total_vertices());
         self.boundaries.reserve(shape.boundaries.len());
 
-        for boundary in shape.boundaries {
+        for boundary in &shape.boundaries {
             let offset = self.nodes.len();
             let role = role(self.boundaries.len());
             self.boundaries.push(Boundary {
@@ -476,15 +845,16 @@ where
             });
 
             let total_vertices = boundary.total_vertices();
-            for (mut index, point) in boundary.into_iter().enumerate() {
+            let points = boundary.edges().map(|edge| *edge.start());
+            for (mut index, point) in points.enumerate() {
                 // Avoid usize overflow when index == 0.
                 index += total_vertices;
 
                 self.nodes.push(Node {
                     vertex: point,
                     boundary: role,
-                    previous: offset + ((index - 1) % total_vertices),
-                    next: offset + ((index + 1) % total_vertices),
+                    previous: (offset + ((index - 1) % total_vertices)) as NodeIndex,
+                    next: (offset + ((index + 1) % total_vertices)) as NodeIndex,
                     intersection: Default::default(),
                 });
             }
@@ -514,7 +884,7 @@ where
     T: Geometry,
 {
     all: Vec<EdgeIntersection<T>>,
-    by_edge: BTreeMap<usize, Vec<usize>>,
+    by_edge: BTreeMap<usize, PositionVec>,
 }
 
 impl<T> Default for EdgeIntersections<T>
@@ -538,13 +908,10 @@ where
 
         self.by_edge
             .entry(intersection.subject)
-            .and_modify(|group| group.push(index))
-            .or_insert(vec![index]);
+            .or_default()
+            .push(index);
 
-        self.by_edge
-            .entry(intersection.clip)
-            .and_modify(|group| group.push(index))
-            .or_insert(vec![index]);
+        self.by_edge.entry(intersection.clip).or_default().push(index);
 
         self.all.push(intersection);
         self
@@ -573,8 +940,8 @@ impl IntersectionSearch {
         }
 
         let current = self.next.unwrap_or(self.start);
-        let node = &nodes[current];
-        self.next = Some(node.next);
+        let node = nodes.get(current)?;
+        self.next = Some(node.next as usize);
 
         if !node.intersection.has_siblings() {
             return self.next(nodes);
@@ -608,12 +975,12 @@ where
         }
 
         let current = self.next.unwrap_or(self.start);
-        let node = &self.nodes[current];
-        self.next = Some(node.next);
+        let node = self.nodes.get(current)?;
+        self.next = Some(node.next as usize);
 
         Some((
             current,
-            T::Edge::new(&node.vertex, &self.nodes[node.next].vertex),
+            T::Edge::new(&node.vertex, &self.nodes.get(node.next as usize)?.vertex),
         ))
     }
 }
@@ -637,7 +1004,9 @@ where
     T: PartialOrd,
 {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.0.partial_cmp(&other.0).unwrap_or(Ordering::Less)
+        // Incomparable keys are treated as equal: unlike a one-sided fallback, this keeps the
+        // ordering symmetric, so the map behaves deterministically even for such keys.
+        self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal)
     }
 }
 
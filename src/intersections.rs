@@ -0,0 +1,97 @@
+//! Standalone edge-edge intersection discovery.
+
+use crate::{Edge, Either, IsClose};
+
+/// An intersection between two edges, located by their positions in the iterators that yielded
+/// them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Intersection<V> {
+    /// The position of the intersecting edge in the left iterator.
+    pub left: usize,
+    /// The position of the intersecting edge in the right iterator.
+    pub right: usize,
+    /// The crossing point, or both endpoints of the overlap when the edges run together.
+    pub intersection: Either<V, [V; 2]>,
+}
+
+/// Returns every [`Intersection`] between the edges yielded by the left iterator and those
+/// yielded by the right one.
+///
+/// This is the same edge-pairing the clipper performs while building its intersection graph,
+/// exposed on its own so custom overlay logic can be built without running a full boolean
+/// operation. The right iterator is collected upfront, so the left one may be arbitrarily large.
+pub fn intersections<'a, E>(
+    left: impl IntoIterator<Item = E>,
+    right: impl IntoIterator<Item = E>,
+    tolerance: &<E::Vertex as IsClose>::Tolerance,
+) -> Vec<Intersection<E::Vertex>>
+where
+    E: Edge<'a>,
+{
+    let right: Vec<E> = right.into_iter().collect();
+
+    left.into_iter()
+        .enumerate()
+        .flat_map(|(left_position, left_edge)| {
+            right
+                .iter()
+                .enumerate()
+                .filter_map(move |(right_position, right_edge)| {
+                    left_edge
+                        .intersection(right_edge, tolerance)
+                        .map(|intersection| Intersection {
+                            left: left_position,
+                            right: right_position,
+                            intersection,
+                        })
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[cfg(all(test, feature = "cartesian"))]
+mod tests {
+    use crate::{cartesian::Polygon, intersections, Either, Geometry, Shape, Tolerance};
+
+    #[test]
+    fn intersections_locate_every_crossing() {
+        let subject: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip: Shape<Polygon<f64>> = Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]);
+
+        let got = intersections(
+            subject.boundaries[0].edges(),
+            clip.boundaries[0].edges(),
+            &Tolerance::default(),
+        );
+
+        assert_eq!(got.len(), 2, "the squares must cross twice");
+
+        assert_eq!(got[0].left, 1, "the right side of the subject must cross");
+        assert_eq!(got[0].right, 0, "the bottom side of the clip must cross");
+        assert_eq!(got[0].intersection, Either::Left([4., 2.].into()));
+
+        assert_eq!(got[1].left, 2, "the top side of the subject must cross");
+        assert_eq!(got[1].right, 3, "the left side of the clip must cross");
+        assert_eq!(got[1].intersection, Either::Left([2., 4.].into()));
+    }
+
+    #[test]
+    fn intersections_report_overlapping_edges() {
+        let subject: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+
+        let got = intersections(
+            subject.boundaries[0].edges(),
+            subject.boundaries[0].edges(),
+            &Tolerance::default(),
+        );
+
+        assert!(
+            got.iter().any(|intersection| {
+                intersection.left == intersection.right
+                    && matches!(intersection.intersection, Either::Right(_))
+            }),
+            "each edge must overlap itself"
+        );
+    }
+}
@@ -1,29 +1,95 @@
+pub mod batch;
 mod clipper;
 mod either;
+#[cfg(feature = "fixtures")]
+pub mod fixtures;
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+#[cfg(feature = "geo")]
+mod geo;
+#[cfg(feature = "geojson")]
+mod geojson;
+#[cfg(feature = "wgpu")]
+pub mod gpu;
 mod graph;
+mod intersections;
+pub mod multi;
+mod options;
+#[cfg(feature = "oracle")]
+pub mod oracle;
+mod plan;
+#[cfg(all(feature = "cartesian", feature = "spherical"))]
+mod project;
+#[cfg(feature = "properties")]
+pub mod properties;
+mod report;
+#[cfg(feature = "proj")]
+mod reproject;
 mod shape;
+mod tagged;
 mod tolerance;
+#[cfg(any(feature = "cartesian", feature = "spherical"))]
+mod wkt;
 
 #[cfg(feature = "cartesian")]
 pub mod cartesian;
 #[cfg(feature = "spherical")]
 pub mod spherical;
 
-pub use self::clipper::Operands;
+pub use self::clipper::{Direction, Operands, Operator};
 pub use self::either::Either;
-pub use self::shape::Shape;
+pub use self::graph::{IntersectionKind, Node};
+pub use self::intersections::{intersections, Intersection};
+#[cfg(feature = "geojson")]
+pub use self::geojson::{clip_feature_collection, validated_shape, GeoJsonError};
+pub use self::options::{
+    AntipodalPolicy, AreaConvention, Cancellation, ClipError, ClipOptions, DegeneratePolicy,
+    FillRule, Progress, ProgressCallback, ToleranceOverrides,
+};
+pub use self::plan::ClipPlan;
+pub use self::report::{Diagnosed, DropReason, DroppedBoundary, GraphSizeEstimate, Touch};
+#[cfg(feature = "proj")]
+pub use self::reproject::ReprojectError;
+pub use self::shape::{BoundaryClass, Location, Orientation, Shape};
+pub use self::tagged::TaggedShape;
 pub use self::tolerance::{IsClose, Positive, Tolerance};
+#[cfg(any(feature = "cartesian", feature = "spherical"))]
+pub use self::wkt::WktError;
 
 /// A vertex from a [`Geometry`].
+///
+/// Together with [`Edge`] and [`Geometry`], this trait forms the extension API through which
+/// boolean operations become available in custom spaces. The three traits are semver-stable:
+/// new requirements are only added in a major release or with a default implementation, and the
+/// contracts documented on each method are guarantees the clipper relies on, not implementation
+/// details.
 pub trait Vertex {
     /// The scalar type in this vertex's space.
     type Scalar;
 
     /// Returns the distance between this vertex and the other.
     fn distance(&self, other: &Self) -> Self::Scalar;
+
+    /// Returns this vertex with each coordinate rounded to the given amount of decimals.
+    ///
+    /// The default implementation returns the vertex untouched; spaces whose coordinates can be
+    /// meaningfully rounded override it so [`ClipOptions::round_output`] takes effect.
+    fn rounded(self, _decimals: u32) -> Self
+    where
+        Self: Sized,
+    {
+        self
+    }
 }
 
 /// An edge delimited by two vertices in a [`Geometry`].
+///
+/// An edge is the unique "straight" path between its endpoints in the space of the geometry,
+/// such as a segment in the cartesian plane or a great-circle arc on the sphere. The clipper
+/// assumes an edge is fully determined by its endpoints: [`Edge::new`] must reconstruct the very
+/// same path the edge described before any intersection vertex was inserted along it.
+///
+/// See [`Vertex`] for the stability guarantees of this trait.
 pub trait Edge<'a> {
     /// The endpoint type of the edge.
     type Vertex: Vertex + IsClose;
@@ -48,6 +114,33 @@ pub trait Edge<'a> {
         tolerance: &<Self::Vertex as IsClose>::Tolerance,
     ) -> Option<Either<Self::Vertex, [Self::Vertex; 2]>>;
 
+    /// Returns the endpoints delimiting the portion of this edge shared with the other, if any.
+    ///
+    /// An overlap exists only when both edges follow the same path for more than a single
+    /// point, such as collinear segments in the plane or arcs of the same great circle on the
+    /// sphere. Edges merely crossing each other share a point, not a portion, and yield none.
+    fn overlap(
+        &self,
+        other: &Self,
+        tolerance: &<Self::Vertex as IsClose>::Tolerance,
+    ) -> Option<[Self::Vertex; 2]> {
+        match self.intersection(other, tolerance) {
+            Some(Either::Right(portion)) => Some(portion),
+            _ => None,
+        }
+    }
+
+    /// Returns true if, and only if, this edge alone does not determine the path between its
+    /// endpoints.
+    ///
+    /// The default implementation reports no edge, as suits spaces whose edges are always
+    /// well-defined, such as segments in the cartesian plane. The sphere overrides it for arcs
+    /// joining antipodal endpoints, through which infinitely many great circles pass. See
+    /// [`ClipOptions::antipodal_policy`] for how the clipper treats such edges.
+    fn is_ill_defined(&self, _tolerance: &<Self::Vertex as IsClose>::Tolerance) -> bool {
+        false
+    }
+
     /// Returns the starting endpoint of the edge.
     fn start(&self) -> &Self::Vertex;
 }
@@ -59,6 +152,12 @@ pub trait RightHanded {
 }
 
 /// A geometry in an arbitrary space.
+///
+/// A geometry is a single closed boundary: the edge between two consecutive vertices is implied,
+/// as is the closing edge between the last vertex and the first. Implementing this trait for a
+/// custom space is enough for [`Shape`] to provide every boolean operation in it.
+///
+/// See [`Vertex`] for the stability guarantees of this trait.
 pub trait Geometry: Sized + RightHanded {
     /// The type of the vertices this geometry is made of.
     type Vertex: Vertex + IsClose;
@@ -69,6 +168,10 @@ pub trait Geometry: Sized + RightHanded {
         Self: 'a;
 
     /// Tries to construct a geometry from the given raw data.
+    ///
+    /// The vertices come in traversal order from the clipper, and the operands provide whatever
+    /// context the space may need to complete them, such as an exterior point on the sphere.
+    /// Returning none discards the boundary from the output.
     fn from_raw(
         operands: Operands<Self>,
         vertices: Vec<Self::Vertex>,
@@ -79,11 +182,47 @@ pub trait Geometry: Sized + RightHanded {
     fn total_vertices(&self) -> usize;
 
     /// Returns an ordered iterator over all the segmentss of this geometry.
+    ///
+    /// The iterator yields one edge per vertex, the last of which closes the boundary back to
+    /// the first vertex.
     fn edges(&self) -> impl Iterator<Item = Self::Edge<'_>>;
 
     /// Returns this geometry with the reversed orientation.
     fn reversed(self) -> Self;
 
+    /// Returns the absolute area enclosed by this geometry.
+    fn area(&self) -> <Self::Vertex as Vertex>::Scalar;
+
+    /// Returns this geometry with each vertex replaced by the output of the given closure.
+    fn map(self, f: impl FnMut(Self::Vertex) -> Self::Vertex) -> Self;
+
+    /// Returns this geometry with every run of consecutive coincident vertices, including the
+    /// closing pair, collapsed into a single one.
+    ///
+    /// Coincident consecutive vertices describe zero-length edges, which carry no geometric
+    /// information but do confuse the classification of intersections during clipping.
+    fn deduped(self, tolerance: &<Self::Vertex as IsClose>::Tolerance) -> Self;
+
+    /// Returns the tolerance this geometry's space assumes when none is explicitly chosen.
+    ///
+    /// The default implementation returns the exact-comparison [`Default`] tolerance, which
+    /// suits coordinates obtained by construction in the cartesian plane. Spaces whose
+    /// predicates accumulate rounding error, such as the sphere, override it with a laxer one.
+    fn default_tolerance() -> <Self::Vertex as IsClose>::Tolerance
+    where
+        <Self::Vertex as IsClose>::Tolerance: Default,
+    {
+        Default::default()
+    }
+
+    /// Returns true if, and only if, this geometry may intersect the other.
+    ///
+    /// This is a conservative prefilter: a false result guarantees both geometries are disjoint,
+    /// while a true one concludes nothing. The default implementation discards no pair.
+    fn might_intersect(&self, _: &Self) -> bool {
+        true
+    }
+
     /// Returns the amount of times this geometry winds around the given vertex.
     fn winding(
         &self,
@@ -98,4 +237,27 @@ pub trait Geometry: Sized + RightHanded {
     ) -> bool {
         self.winding(vertex, tolerance) != 0
     }
+
+    /// Returns true if, and only if, the other geometry lies strictly inside this one.
+    ///
+    /// This is the test the clipper applies to boundaries left without intersections: when no
+    /// pair of edges intersects, the other geometry is either fully inside or fully outside,
+    /// and any single vertex of it decides which. A geometry touching this one from the inside
+    /// is not enclosed.
+    fn encloses(&self, other: &Self, tolerance: &<Self::Vertex as IsClose>::Tolerance) -> bool {
+        let crossed = self.edges().any(|edge| {
+            other
+                .edges()
+                .any(|other_edge| edge.intersection(&other_edge, tolerance).is_some())
+        });
+
+        if crossed {
+            return false;
+        }
+
+        other
+            .edges()
+            .next()
+            .is_some_and(|edge| self.contains(edge.start(), tolerance))
+    }
 }
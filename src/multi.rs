@@ -0,0 +1,334 @@
+//! Boolean operations over arbitrarily many shapes.
+
+use std::collections::BTreeMap;
+
+use num_traits::ToPrimitive;
+
+use crate::{Edge, Geometry, IsClose, Shape, Vertex};
+
+/// Returns the faces of the arrangement of the given shapes, each labeled with the amount of
+/// shapes covering it.
+///
+/// The faces partition the region covered by at least one shape: every face is covered by the
+/// same set of shapes at each of its points, and no two faces overlap. The uncovered exterior is
+/// unbounded and therefore not represented.
+pub fn coverage<T>(
+    shapes: impl IntoIterator<Item = Shape<T>>,
+    tolerance: <T::Vertex as IsClose>::Tolerance,
+) -> Vec<(usize, Shape<T>)>
+where
+    T: Geometry + Clone,
+    T::Vertex: Copy + PartialEq + PartialOrd,
+    for<'a> T::Edge<'a>: Edge<'a>,
+    <T::Vertex as Vertex>::Scalar: Copy + PartialOrd + ToPrimitive,
+    <T::Vertex as IsClose>::Tolerance: Clone,
+{
+    let mut faces: Vec<(usize, Shape<T>)> = Vec::new();
+
+    for shape in shapes {
+        let mut refined = Vec::with_capacity(faces.len() * 2 + 1);
+        let mut remainder = Some(shape.clone());
+
+        for (count, face) in &faces {
+            if let Some(covered) = face.and_ref(&shape, tolerance.clone()) {
+                refined.push((count + 1, covered));
+            }
+
+            if let Some(uncovered) = face.not_ref(&shape, tolerance.clone()) {
+                refined.push((*count, uncovered));
+            }
+
+            remainder = remainder.and_then(|remainder| remainder.not_ref(face, tolerance.clone()));
+        }
+
+        if let Some(remainder) = remainder {
+            refined.push((1, remainder));
+        }
+
+        faces = refined;
+    }
+
+    faces
+}
+
+/// Returns the polygonal heatmap of the given shapes: the faces of their arrangement grouped by
+/// coverage count, in increasing order.
+///
+/// Faces sharing a coverage count are kept as separate shapes rather than merged, preserving the
+/// arrangement they come from.
+pub fn heatmap<T>(
+    shapes: impl IntoIterator<Item = Shape<T>>,
+    tolerance: <T::Vertex as IsClose>::Tolerance,
+) -> Vec<(usize, Vec<Shape<T>>)>
+where
+    T: Geometry + Clone,
+    T::Vertex: Copy + PartialEq + PartialOrd,
+    for<'a> T::Edge<'a>: Edge<'a>,
+    <T::Vertex as Vertex>::Scalar: Copy + PartialOrd + ToPrimitive,
+    <T::Vertex as IsClose>::Tolerance: Clone,
+{
+    let mut groups: BTreeMap<usize, Vec<Shape<T>>> = BTreeMap::new();
+    for (count, face) in coverage(shapes, tolerance) {
+        groups.entry(count).or_default().push(face);
+    }
+
+    groups.into_iter().collect()
+}
+
+/// Returns the union of the arrangement faces covered by an amount of shapes satisfying the
+/// given predicate.
+///
+/// The predicate receives the coverage count of each face, enabling overlays such as "covered by
+/// at least k shapes". Only covered faces are considered: the predicate is never called with
+/// zero.
+pub fn overlay<T>(
+    shapes: impl IntoIterator<Item = Shape<T>>,
+    predicate: impl Fn(usize) -> bool,
+    tolerance: <T::Vertex as IsClose>::Tolerance,
+) -> Option<Shape<T>>
+where
+    T: Geometry + Clone,
+    T::Vertex: Copy + PartialEq + PartialOrd,
+    for<'a> T::Edge<'a>: Edge<'a>,
+    <T::Vertex as Vertex>::Scalar: Copy + PartialOrd + ToPrimitive,
+    <T::Vertex as IsClose>::Tolerance: Clone,
+{
+    coverage(shapes, tolerance.clone())
+        .into_iter()
+        .filter(|(count, _)| predicate(*count))
+        .map(|(_, face)| face)
+        .reduce(|union, face| union.or_ref(&face, tolerance.clone()).unwrap_or(face))
+}
+
+/// Apportions a numeric attribute of the subject among its intersections with each of the given
+/// zones, proportionally to the area each piece covers.
+///
+/// This is the usual follow-up to an intersection in GIS workloads: a population count, say,
+/// attached to an administrative boundary is distributed among the zones overlapping it under
+/// the assumption that the attribute is uniformly spread over the subject. Each output entry
+/// holds the share assigned to the zone at the same position, along with the piece backing it.
+///
+/// The shares add up to the whole attribute only when the zones partition the subject: any
+/// region of the subject covered by no zone keeps its share unassigned, while overlapping zones
+/// count their common regions once per zone.
+pub fn apportion<T>(
+    subject: &Shape<T>,
+    attribute: f64,
+    zones: &[Shape<T>],
+    tolerance: <T::Vertex as IsClose>::Tolerance,
+) -> Vec<(f64, Option<Shape<T>>)>
+where
+    T: Geometry,
+    T::Vertex: Copy + PartialEq + PartialOrd,
+    for<'a> T::Edge<'a>: Edge<'a>,
+    <T::Vertex as Vertex>::Scalar: Copy + PartialOrd + ToPrimitive,
+    <T::Vertex as IsClose>::Tolerance: Clone,
+{
+    /// Returns the area of the filled region of the given shape, with holes subtracted.
+    fn filled_area<T>(shape: &Shape<T>) -> f64
+    where
+        T: Geometry,
+        <T::Vertex as Vertex>::Scalar: ToPrimitive,
+    {
+        shape.boundaries.iter().fold(0., |total, boundary| {
+            let area = boundary.area().to_f64().unwrap_or_default();
+            if boundary.is_clockwise() {
+                total - area
+            } else {
+                total + area
+            }
+        })
+    }
+
+    let total = filled_area(subject);
+    zones
+        .iter()
+        .map(|zone| {
+            let piece = subject.and_ref(zone, tolerance.clone());
+            let share = piece
+                .as_ref()
+                .filter(|_| total > 0.)
+                .map(|piece| attribute * filled_area(piece) / total)
+                .unwrap_or_default();
+
+            (share, piece)
+        })
+        .collect()
+}
+
+/// Partitions the given shapes into connected groups of mutually overlapping or touching shapes.
+///
+/// Two shapes belong to the same group when a chain of pairwise intersections links them, as
+/// witnessed by [`Shape::intersects`]. Shapes whose boundaries cannot possibly intersect are
+/// discarded early through [`Geometry::might_intersect`]. Since shapes from different groups are
+/// guaranteed disjoint, unions can be computed per group, in parallel, without losing any output.
+///
+/// Groups are ordered by the first appearance of any of their members, and members keep their
+/// input order within each group.
+pub fn group_overlapping<T>(
+    shapes: Vec<Shape<T>>,
+    tolerance: &<T::Vertex as IsClose>::Tolerance,
+) -> Vec<Vec<Shape<T>>>
+where
+    T: Geometry,
+    T::Vertex: Copy + PartialEq + PartialOrd,
+    for<'a> T::Edge<'a>: Edge<'a>,
+    <T::Vertex as Vertex>::Scalar: Copy + PartialOrd + ToPrimitive,
+    <T::Vertex as IsClose>::Tolerance: Clone,
+{
+    /// Returns the root of the given position, compressing the path on the way.
+    fn root(parents: &mut [usize], mut position: usize) -> usize {
+        while parents[position] != position {
+            parents[position] = parents[parents[position]];
+            position = parents[position];
+        }
+
+        position
+    }
+
+    let mut parents: Vec<usize> = (0..shapes.len()).collect();
+    for left in 0..shapes.len() {
+        for right in left + 1..shapes.len() {
+            let may_touch = shapes[left].boundaries.iter().any(|a| {
+                shapes[right]
+                    .boundaries
+                    .iter()
+                    .any(|b| a.might_intersect(b))
+            });
+
+            if !may_touch || !shapes[left].intersects(&shapes[right], tolerance) {
+                continue;
+            }
+
+            let left = root(&mut parents, left);
+            let right = root(&mut parents, right);
+
+            // Attaching the larger root to the smaller keeps each group rooted at its first
+            // member, making the output order deterministic.
+            parents[left.max(right)] = left.min(right);
+        }
+    }
+
+    let mut groups: BTreeMap<usize, Vec<Shape<T>>> = BTreeMap::new();
+    for (position, shape) in shapes.into_iter().enumerate() {
+        groups
+            .entry(root(&mut parents, position))
+            .or_default()
+            .push(shape);
+    }
+
+    groups.into_values().collect()
+}
+
+#[cfg(test)]
+#[cfg(feature = "cartesian")]
+mod tests {
+    use crate::{
+        cartesian::Polygon,
+        multi::{apportion, coverage, group_overlapping, heatmap, overlay},
+        Shape,
+    };
+
+    #[test]
+    fn coverage_labels_arrangement_faces() {
+        let shapes: Vec<Shape<Polygon<f64>>> = vec![
+            Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+            Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]),
+        ];
+
+        let faces = coverage(shapes, Default::default());
+
+        let covered_twice: Vec<_> = faces.iter().filter(|(count, _)| *count == 2).collect();
+        assert_eq!(covered_twice.len(), 1);
+        assert_eq!(
+            covered_twice[0].1,
+            Shape::new(vec![[2., 2.], [4., 2.], [4., 4.], [2., 4.]])
+        );
+
+        assert_eq!(faces.iter().filter(|(count, _)| *count == 1).count(), 2);
+    }
+
+    #[test]
+    fn heatmap_groups_faces_by_coverage() {
+        let shapes: Vec<Shape<Polygon<f64>>> = vec![
+            Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+            Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]),
+        ];
+
+        let got = heatmap(shapes, Default::default());
+
+        assert_eq!(got.len(), 2);
+        assert_eq!(got[0].0, 1);
+        assert_eq!(got[0].1.len(), 2);
+        assert_eq!(got[1].0, 2);
+        assert_eq!(
+            got[1].1,
+            vec![Shape::new(vec![[2., 2.], [4., 2.], [4., 4.], [2., 4.]])]
+        );
+    }
+
+    #[test]
+    fn overlay_selects_majority_regions() {
+        let shapes: Vec<Shape<Polygon<f64>>> = vec![
+            Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+            Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]),
+        ];
+
+        let got = overlay(shapes.clone(), |count| count >= 2, Default::default());
+        let want = shapes[0].and_ref(&shapes[1], Default::default());
+        assert_eq!(got, want);
+
+        let got = overlay(shapes, |count| count >= 3, Default::default());
+        assert_eq!(got, None);
+    }
+
+    #[test]
+    fn apportionment_is_proportional_to_area() {
+        let subject: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+
+        let zones: Vec<Shape<Polygon<f64>>> = vec![
+            Shape::new(vec![[-1., -1.], [2., -1.], [2., 5.], [-1., 5.]]),
+            Shape::new(vec![[2., -1.], [5., -1.], [5., 5.], [2., 5.]]),
+            Shape::new(vec![[10., 10.], [12., 10.], [12., 12.], [10., 12.]]),
+        ];
+
+        let got = apportion(&subject, 100., &zones, Default::default());
+
+        assert_eq!(got.len(), 3, "every zone must receive an entry");
+        assert!(
+            (got[0].0 - 50.).abs() < 1e-9,
+            "the left zone must receive half, got {}",
+            got[0].0
+        );
+        assert!(
+            (got[1].0 - 50.).abs() < 1e-9,
+            "the right zone must receive half, got {}",
+            got[1].0
+        );
+        assert_eq!(got[2], (0., None), "a disjoint zone must receive nothing");
+    }
+
+    #[test]
+    fn grouping_connects_chains_of_overlaps() {
+        let shapes: Vec<Shape<Polygon<f64>>> = vec![
+            Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+            Shape::new(vec![[20., 20.], [24., 20.], [24., 24.], [20., 24.]]),
+            Shape::new(vec![[3., 3.], [7., 3.], [7., 7.], [3., 7.]]),
+            Shape::new(vec![[6., 6.], [10., 6.], [10., 10.], [6., 10.]]),
+        ];
+
+        let groups = group_overlapping(shapes.clone(), &Default::default());
+
+        assert_eq!(groups.len(), 2, "two disjoint groups must remain");
+        assert_eq!(
+            groups[0],
+            vec![shapes[0].clone(), shapes[2].clone(), shapes[3].clone()],
+            "chained overlaps must end up in the same group"
+        );
+        assert_eq!(
+            groups[1],
+            vec![shapes[1].clone()],
+            "the detached shape must form its own group"
+        );
+    }
+}
@@ -0,0 +1,815 @@
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use crate::IsClose;
+
+/// A handle through which an ongoing clipping operation can be cooperatively cancelled.
+#[derive(Debug, Default, Clone)]
+pub struct Cancellation(Arc<AtomicBool>);
+
+impl Cancellation {
+    /// Returns a new cancellation handle.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests the cancellation of all the operations observing this handle.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if, and only if, the cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// A snapshot of the progress of an ongoing clipping operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Progress {
+    /// The operation is pairing operand edges in search of intersections.
+    SearchingIntersections {
+        /// The amount of edge pairs already checked.
+        edges_processed: usize,
+        /// The total amount of edge pairs to check.
+        total_edges: usize,
+        /// The amount of intersections registered so far.
+        intersections: usize,
+    },
+    /// The operation is traversing the intersection graph, assembling the output.
+    TraversingBoundaries {
+        /// The amount of output boundaries assembled so far.
+        boundaries: usize,
+    },
+}
+
+/// A callback through which an ongoing clipping operation reports its [`Progress`].
+///
+/// The callback is invoked from whichever thread runs the operation, at boundary-pair
+/// granularity: often enough to drive a progress bar, without showing up in profiles.
+#[derive(Clone)]
+pub struct ProgressCallback(Arc<dyn Fn(Progress) + Send + Sync>);
+
+impl ProgressCallback {
+    /// Returns a new callback wrapping the given closure.
+    pub fn new(callback: impl Fn(Progress) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+}
+
+impl fmt::Debug for ProgressCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ProgressCallback")
+    }
+}
+
+/// The rule deciding whether a winding number counts as interior.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FillRule {
+    /// A point is interior when its winding number is non-zero.
+    #[default]
+    NonZero,
+    /// A point is interior when its winding number is odd.
+    EvenOdd,
+}
+
+impl FillRule {
+    /// Returns true if, and only if, the given winding number counts as interior under this rule.
+    pub fn is_filled(&self, winding: isize) -> bool {
+        match self {
+            Self::NonZero => winding != 0,
+            Self::EvenOdd => winding % 2 != 0,
+        }
+    }
+}
+
+/// The sign convention applied to ring areas and winding numbers.
+///
+/// OGC semantics treat clockwise rings as holes subtracting from their shell, while shapefile
+/// style consumers expect every ring to count positively regardless of its orientation. Both
+/// are in active use downstream, and a silent mismatch yields subtly wrong measures, so the
+/// convention is explicit wherever it matters.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AreaConvention {
+    /// Clockwise rings count negatively: holes subtract from the measures of their shell.
+    #[default]
+    Signed,
+    /// Every ring counts positively, regardless of its orientation.
+    Absolute,
+}
+
+/// The treatment of output boundaries that collapse to a line or a point.
+///
+/// Operands touching without overlapping can leave boundaries of fewer than three vertices in
+/// the output, such as the shared edge of two tangential squares. Whether those carry
+/// information or are noise depends on the caller, so the policy is theirs to choose.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DegeneratePolicy {
+    /// Degenerate boundaries stay in the output as boundaries of fewer than three vertices.
+    #[default]
+    Keep,
+    /// Degenerate boundaries are silently removed from the output.
+    Drop,
+    /// Degenerate boundaries are removed from the output and reported through the diagnosed
+    /// entry points as [`DropReason::Degenerate`], from where callers can promote them to
+    /// whatever point or line representation they use.
+    ///
+    /// [`DropReason::Degenerate`]: crate::DropReason::Degenerate
+    Report,
+}
+
+/// The treatment of operand edges that do not determine the path between their endpoints.
+///
+/// Infinitely many great circles pass through a pair of antipodal points, so an arc joining
+/// them does not describe a single path on its own. Whether the operation should resolve the
+/// ambiguity or refuse it depends on the caller, so the policy is theirs to choose. Spaces
+/// whose edges are always well-defined, such as the cartesian plane, are unaffected.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AntipodalPolicy {
+    /// The edge follows the deterministic plane obtained by displacing its starting endpoint a
+    /// quarter turn in both inclination and azimuth, splitting the edge at that midpoint.
+    #[default]
+    Deterministic,
+    /// The operation fails with [`ClipError::IllDefinedEdge`].
+    Error,
+}
+
+/// Optional per-stage scalings of the operation tolerance.
+///
+/// Every stage of a clipping operation compares coordinates through the same tolerance by
+/// default, which is often too coarse a knob: welding the output tends to need a laxer
+/// tolerance than the geometric predicates, since it absorbs the rounding noise the earlier
+/// stages accumulate. Each override scales the operation tolerance for its own stage through
+/// [`IsClose::scaled_tolerance`], leaving the remaining stages on the operation-wide one.
+///
+/// [`IsClose::scaled_tolerance`]: crate::IsClose::scaled_tolerance
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ToleranceOverrides {
+    /// The factor scaling the tolerance used while pairing edges in search of intersections.
+    pub intersection_scale: Option<f64>,
+    /// The factor scaling the tolerance used while classifying each intersection as an entry
+    /// or an exit.
+    pub classification_scale: Option<f64>,
+    /// The factor scaling the tolerance used while assembling and welding the output
+    /// boundaries.
+    pub welding_scale: Option<f64>,
+}
+
+impl ToleranceOverrides {
+    /// Returns the tolerance through which the intersection search must compare coordinates.
+    pub(crate) fn intersection<V>(&self, tolerance: &V::Tolerance) -> V::Tolerance
+    where
+        V: IsClose,
+        V::Tolerance: Clone,
+    {
+        Self::derived::<V>(self.intersection_scale, tolerance)
+    }
+
+    /// Returns the tolerance through which the intersection classification must compare
+    /// coordinates.
+    pub(crate) fn classification<V>(&self, tolerance: &V::Tolerance) -> V::Tolerance
+    where
+        V: IsClose,
+        V::Tolerance: Clone,
+    {
+        Self::derived::<V>(self.classification_scale, tolerance)
+    }
+
+    /// Returns the tolerance through which the output welding must compare coordinates.
+    pub(crate) fn welding<V>(&self, tolerance: &V::Tolerance) -> V::Tolerance
+    where
+        V: IsClose,
+        V::Tolerance: Clone,
+    {
+        Self::derived::<V>(self.welding_scale, tolerance)
+    }
+
+    /// Returns the given tolerance scaled by the given factor, if any.
+    fn derived<V>(scale: Option<f64>, tolerance: &V::Tolerance) -> V::Tolerance
+    where
+        V: IsClose,
+        V::Tolerance: Clone,
+    {
+        match scale {
+            Some(factor) => V::scaled_tolerance(tolerance, factor),
+            None => tolerance.clone(),
+        }
+    }
+}
+
+/// The settings driving a clipping operation.
+#[derive(Debug, Default, Clone)]
+pub struct ClipOptions {
+    /// The handle through which the operation may be cooperatively cancelled.
+    pub cancellation: Option<Cancellation>,
+    /// The maximum amount of nodes the intersection graph is allowed to hold.
+    pub max_nodes: Option<usize>,
+    /// The maximum amount of intersections the operation is allowed to register.
+    pub max_intersections: Option<usize>,
+    /// The minimum area an output boundary must enclose in order to be kept.
+    ///
+    /// Nearly-coincident edges tend to leave tolerance-scaled slivers in the output; setting
+    /// this threshold drops them before they reach downstream data.
+    pub min_area: Option<f64>,
+    /// The minimum amount of vertices an output boundary must have in order to be kept.
+    pub min_ring_vertices: Option<usize>,
+    /// The amount of decimals to round output coordinates to, if any.
+    ///
+    /// Rounding happens once the output is assembled, followed by the removal of any edge the
+    /// rounding collapses, so exported results match the precision of their target format
+    /// without a separate cleanup pass. Boundaries collapsing entirely are dropped as slivers.
+    pub round_output: Option<u32>,
+    /// The rule interpreting the winding number of each operand.
+    ///
+    /// Only self-overlapping inputs are affected: their multiply-wound regions count as interior
+    /// under [`FillRule::NonZero`] but as exterior under [`FillRule::EvenOdd`].
+    pub fill_rule: FillRule,
+    /// The treatment of output boundaries collapsing to a line or a point.
+    pub degenerate_policy: DegeneratePolicy,
+    /// The treatment of operand edges that do not determine the path between their endpoints.
+    pub antipodal_policy: AntipodalPolicy,
+    /// Whether to skip the post-pass orienting output boundaries by their containment depth.
+    ///
+    /// The pass guarantees every hole ring winds opposite to the boundary containing it, at the
+    /// cost of a winding query per boundary pair. Callers consuming the output with an
+    /// orientation-insensitive fill rule may skip it for maximum speed.
+    pub skip_orientation_pass: bool,
+    /// The callback through which the operation reports its progress, if any.
+    pub progress: Option<ProgressCallback>,
+    /// The per-stage scalings of the operation tolerance, if any.
+    pub tolerance_overrides: ToleranceOverrides,
+    /// Whether to check the validity of the output before returning it.
+    ///
+    /// When set, an output with self-intersecting boundaries, holes sheltered by no shell or no
+    /// shell at all is rejected as [`ClipError::InvalidOutput`] instead of being returned,
+    /// catching algorithm regressions close to their source. The check visits every pair of
+    /// edges in each boundary, so it is meant for debugging and validation pipelines rather
+    /// than hot paths. Outputs legitimately touching themselves at a vertex, as produced by
+    /// self-crossing inputs, are rejected too.
+    pub validate_output: bool,
+}
+
+impl ClipOptions {
+    /// Returns true if, and only if, the operation holding these options has been cancelled.
+    pub(crate) fn cancelled(&self) -> bool {
+        self.cancellation
+            .as_ref()
+            .is_some_and(Cancellation::is_cancelled)
+    }
+
+    /// Returns true if, and only if, the given amount of nodes exceeds the configured limit.
+    pub(crate) fn nodes_exceeded(&self, nodes: usize) -> bool {
+        self.max_nodes.is_some_and(|limit| nodes > limit)
+    }
+
+    /// Returns true if, and only if, the given amount of intersections exceeds the configured
+    /// limit.
+    pub(crate) fn intersections_exceeded(&self, intersections: usize) -> bool {
+        self.max_intersections
+            .is_some_and(|limit| intersections > limit)
+    }
+
+    /// Reports the given progress through the configured callback, if any.
+    pub(crate) fn report_progress(&self, progress: Progress) {
+        if let Some(callback) = &self.progress {
+            (callback.0)(progress);
+        }
+    }
+
+    /// Returns true if, and only if, any of the sliver thresholds is set.
+    pub(crate) fn filters_slivers(&self) -> bool {
+        self.min_area.is_some() || self.min_ring_vertices.is_some()
+    }
+
+    /// Returns true if, and only if, a boundary with the given amount of vertices and area falls
+    /// below any of the sliver thresholds.
+    pub(crate) fn is_sliver(&self, vertices: usize, area: Option<f64>) -> bool {
+        if self
+            .min_ring_vertices
+            .is_some_and(|minimum| vertices < minimum)
+        {
+            return true;
+        }
+
+        match (self.min_area, area) {
+            (Some(minimum), Some(area)) => area < minimum,
+            _ => false,
+        }
+    }
+}
+
+/// The reason why a clipping operation did not complete.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipError {
+    /// The operation was cancelled through its [`Cancellation`] handle.
+    Cancelled,
+    /// The intersection graph grew beyond [`ClipOptions::max_nodes`].
+    NodesLimitExceeded,
+    /// The operation registered more intersections than [`ClipOptions::max_intersections`]
+    /// allows.
+    IntersectionsLimitExceeded,
+    /// An operand edge does not determine the path between its endpoints, and
+    /// [`AntipodalPolicy::Error`] is in force.
+    IllDefinedEdge,
+    /// An internal invariant was violated, leaving the operation unable to continue.
+    Internal(&'static str),
+    /// The output failed the validity check requested by [`ClipOptions::validate_output`].
+    InvalidOutput(&'static str),
+}
+
+#[cfg(all(test, feature = "cartesian"))]
+mod tests {
+    use crate::{cartesian::Polygon, Cancellation, ClipError, ClipOptions, FillRule, Shape};
+
+    #[test]
+    fn fill_rule_winding_interpretation() {
+        struct Test {
+            name: &'static str,
+            fill_rule: FillRule,
+            winding: isize,
+            want: bool,
+        }
+
+        vec![
+            Test {
+                name: "nonzero counts simple interiors",
+                fill_rule: FillRule::NonZero,
+                winding: 1,
+                want: true,
+            },
+            Test {
+                name: "nonzero counts multiply-wound regions",
+                fill_rule: FillRule::NonZero,
+                winding: 2,
+                want: true,
+            },
+            Test {
+                name: "nonzero counts negative windings",
+                fill_rule: FillRule::NonZero,
+                winding: -1,
+                want: true,
+            },
+            Test {
+                name: "nonzero discards exteriors",
+                fill_rule: FillRule::NonZero,
+                winding: 0,
+                want: false,
+            },
+            Test {
+                name: "even-odd counts simple interiors",
+                fill_rule: FillRule::EvenOdd,
+                winding: 1,
+                want: true,
+            },
+            Test {
+                name: "even-odd discards multiply-wound regions",
+                fill_rule: FillRule::EvenOdd,
+                winding: 2,
+                want: false,
+            },
+            Test {
+                name: "even-odd counts negative odd windings",
+                fill_rule: FillRule::EvenOdd,
+                winding: -1,
+                want: true,
+            },
+            Test {
+                name: "even-odd discards exteriors",
+                fill_rule: FillRule::EvenOdd,
+                winding: 0,
+                want: false,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = test.fill_rule.is_filled(test.winding);
+            assert_eq!(got, test.want, "{}", test.name);
+        });
+    }
+
+    #[test]
+    fn fill_rules_agree_on_simple_inputs() {
+        let subject: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip: Shape<Polygon<f64>> = Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]);
+
+        let got = subject.clone().and_with(
+            clip.clone(),
+            Default::default(),
+            ClipOptions {
+                fill_rule: FillRule::EvenOdd,
+                ..Default::default()
+            },
+        );
+
+        let want = subject.and(clip, Default::default());
+        assert_eq!(got, Ok(want));
+    }
+
+    #[test]
+    fn cancelled_operation_must_not_complete() {
+        let subject: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip = Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]);
+
+        let cancellation = Cancellation::new();
+        cancellation.cancel();
+
+        let got = subject.or_with(
+            clip,
+            Default::default(),
+            ClipOptions {
+                cancellation: Some(cancellation),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(got, Err(ClipError::Cancelled));
+    }
+
+    #[test]
+    fn exceeding_limits_must_not_complete() {
+        struct Test {
+            name: &'static str,
+            options: ClipOptions,
+            want: ClipError,
+        }
+
+        vec![
+            Test {
+                name: "node limit below input size",
+                options: ClipOptions {
+                    max_nodes: Some(4),
+                    ..Default::default()
+                },
+                want: ClipError::NodesLimitExceeded,
+            },
+            Test {
+                name: "intersection limit below actual crossings",
+                options: ClipOptions {
+                    max_intersections: Some(1),
+                    ..Default::default()
+                },
+                want: ClipError::IntersectionsLimitExceeded,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let subject: Shape<Polygon<f64>> =
+                Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+            let clip = Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]);
+
+            let got = subject.or_with(clip, Default::default(), test.options);
+            assert_eq!(got, Err(test.want), "{}", test.name);
+        });
+    }
+
+    #[test]
+    fn sliver_filtering_must_drop_small_boundaries() {
+        struct Test {
+            name: &'static str,
+            options: ClipOptions,
+            want: Option<Shape<Polygon<f64>>>,
+        }
+
+        vec![
+            Test {
+                name: "no thresholds keep both boundaries",
+                options: ClipOptions::default(),
+                want: Some(Shape {
+                    boundaries: vec![
+                        vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]].into(),
+                        vec![[10., 10.], [10.1, 10.], [10.1, 10.1], [10., 10.1]].into(),
+                    ],
+                }),
+            },
+            Test {
+                name: "area threshold drops the tiny boundary",
+                options: ClipOptions {
+                    min_area: Some(1.),
+                    ..Default::default()
+                },
+                want: Some(Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]])),
+            },
+            Test {
+                name: "area threshold above both drops everything",
+                options: ClipOptions {
+                    min_area: Some(100.),
+                    ..Default::default()
+                },
+                want: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let subject: Shape<Polygon<f64>> =
+                Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+            let clip = Shape::new(vec![[10., 10.], [10.1, 10.], [10.1, 10.1], [10., 10.1]]);
+
+            let got = subject.or_with(clip, Default::default(), test.options);
+            assert_eq!(got, Ok(test.want), "{}", test.name);
+        });
+    }
+
+    #[test]
+    fn diagnosed_clipping_reports_dropped_boundaries() {
+        use crate::DropReason;
+
+        let subject: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip = Shape::new(vec![[10., 10.], [10.1, 10.], [10.1, 10.1], [10., 10.1]]);
+
+        let (output, dropped) = subject
+            .or_diagnosed(
+                &clip,
+                Default::default(),
+                ClipOptions {
+                    min_area: Some(1.),
+                    ..Default::default()
+                },
+            )
+            .expect("the operation must complete");
+
+        assert_eq!(
+            output,
+            Some(Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]])),
+            "the subject must survive the sliver threshold"
+        );
+
+        assert_eq!(dropped.len(), 1, "the tiny boundary must be reported");
+        assert_eq!(dropped[0].reason, DropReason::Sliver);
+        assert_eq!(dropped[0].vertices, 4);
+        assert!(dropped[0].vertex.is_some(), "the witness vertex must exist");
+    }
+
+    #[test]
+    fn degenerate_policy_governs_collapsed_outputs() {
+        use crate::{DegeneratePolicy, DropReason};
+
+        let subject = || Shape::<Polygon<f64>>::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip = || Shape::<Polygon<f64>>::new(vec![[4., 0.], [8., 0.], [8., 4.], [4., 4.]]);
+
+        let kept = subject().and_with(clip(), Default::default(), ClipOptions::default());
+        assert_eq!(
+            kept,
+            Ok(Some(Shape::new(vec![[4., 0.], [4., 4.]]))),
+            "the default policy must keep the shared edge"
+        );
+
+        let dropped = subject().and_with(
+            clip(),
+            Default::default(),
+            ClipOptions {
+                degenerate_policy: DegeneratePolicy::Drop,
+                ..Default::default()
+            },
+        );
+        assert_eq!(dropped, Ok(None), "the drop policy must discard the shared edge");
+
+        let (output, reported) = subject()
+            .and_diagnosed(
+                &clip(),
+                Default::default(),
+                ClipOptions {
+                    degenerate_policy: DegeneratePolicy::Report,
+                    ..Default::default()
+                },
+            )
+            .expect("the operation must complete");
+
+        assert_eq!(output, None, "the report policy must discard the shared edge");
+        assert_eq!(reported.len(), 1, "the shared edge must be reported");
+        assert_eq!(reported[0].reason, DropReason::Degenerate);
+        assert_eq!(reported[0].vertices, 2);
+    }
+
+    #[test]
+    fn orientation_pass_governs_hole_windings() {
+        use crate::Orientation;
+
+        let subject: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip: Shape<Polygon<f64>> = Shape::new(vec![[1., 1.], [3., 1.], [3., 3.], [1., 3.]]);
+
+        let got = subject
+            .clone()
+            .not_with(clip.clone(), Default::default(), ClipOptions::default())
+            .expect("the operation must complete")
+            .expect("the difference must not be empty");
+
+        assert_eq!(
+            got.orientation(0),
+            Some(Orientation::Counterclockwise),
+            "the outer boundary must stay counterclockwise"
+        );
+        assert_eq!(
+            got.orientation(1),
+            Some(Orientation::Clockwise),
+            "the hole must wind opposite to its containing boundary"
+        );
+
+        let skipped = subject.not_with(
+            clip,
+            Default::default(),
+            ClipOptions {
+                skip_orientation_pass: true,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            skipped,
+            Ok(Some(got)),
+            "a well-oriented output must not depend on the pass"
+        );
+    }
+
+    #[test]
+    fn validated_clipping_checks_the_output() {
+        let subject: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip = Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]);
+
+        let options = ClipOptions {
+            validate_output: true,
+            ..Default::default()
+        };
+
+        let got = subject.clone().or_with(clip.clone(), Default::default(), options.clone());
+        let want = subject.or(clip, Default::default());
+        assert_eq!(got, Ok(want), "a clean output must pass the check");
+
+        // The union of a self-crossing subject legitimately touches itself, which the validity
+        // check rejects by design.
+        let subject: Shape<Polygon<f64>> = Shape::new(vec![
+            [-1., 0.],
+            [-1., -1.],
+            [1., -1.],
+            [1., 0.],
+            [-1., 0.],
+            [-1., 1.],
+            [1., 1.],
+            [1., 0.],
+        ]);
+
+        let clip = Shape::new(vec![[0.75, 0.75], [0.75, 1.25], [-0.75, 1.25], [-0.75, 0.75]]);
+
+        let got = subject.or_with(clip, Default::default(), options);
+        assert!(
+            matches!(got, Err(ClipError::InvalidOutput(_))),
+            "a self-touching output must be rejected"
+        );
+    }
+
+    #[test]
+    fn output_rounding_matches_target_precision() {
+        let subject: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip = Shape::new(vec![
+            [1.2345, 1.2345],
+            [5., 1.2345],
+            [5., 5.],
+            [1.2345, 5.],
+        ]);
+
+        let got = subject.and_with(
+            clip,
+            Default::default(),
+            ClipOptions {
+                round_output: Some(2),
+                ..Default::default()
+            },
+        );
+
+        let want = Shape::new(vec![[1.23, 1.23], [4., 1.23], [4., 4.], [1.23, 4.]]);
+        assert_eq!(got, Ok(Some(want)), "coordinates must be rounded");
+
+        let subject: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip = Shape::new(vec![[10., 10.], [10.1, 10.], [10.1, 10.1], [10., 10.1]]);
+
+        let got = subject.or_with(
+            clip,
+            Default::default(),
+            ClipOptions {
+                round_output: Some(0),
+                ..Default::default()
+            },
+        );
+
+        let want = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        assert_eq!(
+            got,
+            Ok(Some(want)),
+            "boundaries collapsed by the rounding must be dropped"
+        );
+    }
+
+    #[test]
+    fn tolerance_overrides_scale_the_welding_stage() {
+        use crate::{Tolerance, ToleranceOverrides};
+
+        let subject = || {
+            Shape::<Polygon<f64>>::new(vec![
+                [0., 0.],
+                [4., 0.],
+                [4., 2.],
+                [4., 2.004],
+                [4., 4.],
+                [0., 4.],
+            ])
+        };
+
+        let clip =
+            || Shape::<Polygon<f64>>::new(vec![[10., 10.], [10.1, 10.], [10.1, 10.1], [10., 10.1]]);
+
+        let tolerance = Tolerance {
+            relative: 1e-9.into(),
+            absolute: 1e-9.into(),
+        };
+
+        let vertices = |options: ClipOptions| {
+            subject()
+                .or_with(clip(), tolerance, options)
+                .expect("the operation must complete")
+                .expect("the union must not be empty")
+                .boundaries
+                .iter()
+                .map(|boundary| boundary.vertices.len())
+                .max()
+                .expect("the union must have boundaries")
+        };
+
+        let kept = vertices(ClipOptions {
+            round_output: Some(3),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            kept, 6,
+            "without overrides the near-duplicate vertex must survive the welding"
+        );
+
+        let welded = vertices(ClipOptions {
+            round_output: Some(3),
+            tolerance_overrides: ToleranceOverrides {
+                welding_scale: Some(1e7),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        assert_eq!(
+            welded, 5,
+            "the scaled welding tolerance must collapse the near-duplicate vertex"
+        );
+    }
+
+    #[test]
+    fn progress_callback_observes_every_phase() {
+        use std::sync::{Arc, Mutex};
+
+        use crate::{Progress, ProgressCallback};
+
+        let subject: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip = Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]);
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+
+        let got = subject.or_with(
+            clip,
+            Default::default(),
+            ClipOptions {
+                progress: Some(ProgressCallback::new(move |progress| {
+                    sink.lock().expect("the events must be lockable").push(progress)
+                })),
+                ..Default::default()
+            },
+        );
+
+        assert!(got.is_ok(), "the operation must complete");
+
+        let events = events.lock().expect("the events must be lockable");
+        let searches = events
+            .iter()
+            .filter_map(|event| match event {
+                Progress::SearchingIntersections {
+                    edges_processed,
+                    total_edges,
+                    ..
+                } => Some((edges_processed, total_edges)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let (processed, total) = searches.last().expect("the search must be reported");
+        assert_eq!(processed, total, "the search must reach completion");
+
+        assert!(
+            events
+                .iter()
+                .any(|event| matches!(event, Progress::TraversingBoundaries { boundaries } if *boundaries > 0)),
+            "the traversal must be reported"
+        );
+    }
+}
+
@@ -0,0 +1,298 @@
+//! Exact-arithmetic correctness oracle over the clipping operators.
+//!
+//! The oracle never trusts the float path: every coordinate is scaled to an integer over a
+//! common power-of-two denominator, which represents it as an exact rational, and membership of
+//! each sample point in the operation output is derived exactly from the operands alone. The
+//! resulting mismatch count quantifies robustness regressions of the float path.
+
+use crate::{
+    batch::Operation,
+    cartesian::{Point, Polygon},
+    Edge, Geometry, Shape, Tolerance,
+};
+
+/// A sample point whose float membership disagrees with the exact one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mismatch {
+    /// The sampled point.
+    pub point: Point<f64>,
+    /// Whether the point belongs to the operation output according to the exact reference.
+    pub want: bool,
+    /// Whether the point belongs to the shape returned by the float path.
+    pub got: bool,
+}
+
+/// The outcome of comparing a clipping operation against the exact reference.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Comparison {
+    /// The amount of sample points whose membership was checked.
+    pub samples: usize,
+    /// The amount of sample points discarded for lying on a boundary or exceeding the range of
+    /// the exact predicates.
+    pub skipped: usize,
+    /// The sample points at which both paths disagree.
+    pub mismatches: Vec<Mismatch>,
+}
+
+/// Runs the given operation through both the float path and the exact reference, comparing the
+/// membership of up to resolution by resolution sample points over the joint bounding box of the
+/// operands.
+///
+/// Sample coordinates advance by powers of two, so each one is exactly representable over the
+/// denominators of the operands. Points lying on a boundary of either operand or of the output
+/// within the tolerance are skipped: membership there is ambiguous under any arithmetic.
+pub fn compare(
+    subject: &Shape<Polygon<f64>>,
+    clip: &Shape<Polygon<f64>>,
+    operation: Operation,
+    resolution: usize,
+    tolerance: Tolerance<f64>,
+) -> Comparison {
+    let (Some(subject_stats), Some(clip_stats)) = (subject.stats(), clip.stats()) else {
+        return Comparison::default();
+    };
+
+    let output = match operation {
+        Operation::Union => subject.or_ref(clip, tolerance),
+        Operation::Difference => subject.not_ref(clip, tolerance),
+        Operation::Intersection => subject.and_ref(clip, tolerance),
+    };
+
+    let min = Point {
+        x: subject_stats.min.x.min(clip_stats.min.x),
+        y: subject_stats.min.y.min(clip_stats.min.y),
+    };
+    let max = Point {
+        x: subject_stats.max.x.max(clip_stats.max.x),
+        y: subject_stats.max.y.max(clip_stats.max.y),
+    };
+
+    let step = |extent: f64| (extent / resolution as f64).log2().ceil().exp2();
+    let step_x = step(max.x - min.x);
+    let step_y = step(max.y - min.y);
+    if !step_x.is_finite() || !step_y.is_finite() || step_x <= 0. || step_y <= 0. {
+        return Comparison::default();
+    }
+
+    let mut comparison = Comparison::default();
+    for row in 0..resolution {
+        let y = min.y + step_y * (row as f64 + 0.5);
+        if y > max.y {
+            break;
+        }
+
+        for column in 0..resolution {
+            let x = min.x + step_x * (column as f64 + 0.5);
+            if x > max.x {
+                break;
+            }
+
+            let point = Point { x, y };
+            let on_output = output
+                .as_ref()
+                .is_some_and(|shape| on_boundary(shape, &point, &tolerance));
+
+            if on_output
+                || on_boundary(subject, &point, &tolerance)
+                || on_boundary(clip, &point, &tolerance)
+            {
+                comparison.skipped += 1;
+                continue;
+            }
+
+            let (Some(in_subject), Some(in_clip)) =
+                (exact_contains(subject, &point), exact_contains(clip, &point))
+            else {
+                comparison.skipped += 1;
+                continue;
+            };
+
+            let want = match operation {
+                Operation::Union => in_subject || in_clip,
+                Operation::Difference => in_subject && !in_clip,
+                Operation::Intersection => in_subject && in_clip,
+            };
+
+            let got = output
+                .as_ref()
+                .is_some_and(|shape| shape.winding(&point, &tolerance) != 0);
+
+            comparison.samples += 1;
+            if want != got {
+                comparison.mismatches.push(Mismatch { point, want, got });
+            }
+        }
+    }
+
+    comparison
+}
+
+/// Returns true if, and only if, the point lies on any edge of the shape within the tolerance.
+fn on_boundary(shape: &Shape<Polygon<f64>>, point: &Point<f64>, tolerance: &Tolerance<f64>) -> bool {
+    shape.boundaries.iter().any(|boundary| {
+        boundary
+            .edges()
+            .any(|edge| edge.contains(point, tolerance))
+    })
+}
+
+/// Returns whether the point lies inside the shape by the non-zero rule, evaluated exactly, or
+/// none if the scaled coordinates exceed the range in which the predicates stay exact.
+fn exact_contains(shape: &Shape<Polygon<f64>>, point: &Point<f64>) -> Option<bool> {
+    shape
+        .boundaries
+        .iter()
+        .try_fold(0isize, |winding, boundary| {
+            Some(winding + exact_winding(boundary, point)?)
+        })
+        .map(|winding| winding != 0)
+}
+
+/// Returns the amount of times the polygon winds around the point, evaluated exactly over the
+/// scaled coordinates, or none if the scaling overflows.
+fn exact_winding(polygon: &Polygon<f64>, point: &Point<f64>) -> Option<isize> {
+    let total = polygon.vertices.len();
+    let scaled = scale(
+        polygon
+            .vertices
+            .iter()
+            .flat_map(|vertex| [vertex.x, vertex.y])
+            .chain([point.x, point.y]),
+    )?;
+
+    let (point_x, point_y) = (scaled[2 * total], scaled[2 * total + 1]);
+
+    let mut winding = 0isize;
+    for position in 0..total {
+        let (from_x, from_y) = (scaled[2 * position], scaled[2 * position + 1]);
+        let next = (position + 1) % total;
+        let (to_x, to_y) = (scaled[2 * next], scaled[2 * next + 1]);
+
+        let cross = (to_x - from_x) * (point_y - from_y) - (point_x - from_x) * (to_y - from_y);
+        if from_y <= point_y && point_y < to_y && cross > 0 {
+            winding += 1;
+        } else if to_y <= point_y && point_y < from_y && cross < 0 {
+            winding -= 1;
+        }
+    }
+
+    Some(winding)
+}
+
+/// Scales the given coordinates to integers over their common power-of-two denominator.
+///
+/// Returns none if any scaled coordinate needs more than 61 bits, past which the winding
+/// predicate could no longer be evaluated within 128 bits.
+fn scale(values: impl Iterator<Item = f64>) -> Option<Vec<i128>> {
+    let decomposed = values.map(decompose).collect::<Vec<_>>();
+    let exponent = decomposed
+        .iter()
+        .filter(|(mantissa, _)| *mantissa != 0)
+        .map(|&(_, exponent)| exponent)
+        .min()
+        .unwrap_or_default();
+
+    decomposed
+        .into_iter()
+        .map(|(mantissa, own)| {
+            if mantissa == 0 {
+                return Some(0);
+            }
+
+            let shift = (own - exponent) as u32;
+            let bits = 128 - mantissa.unsigned_abs().leading_zeros();
+            (bits + shift <= 61).then(|| mantissa << shift)
+        })
+        .collect()
+}
+
+/// Returns the integer mantissa and power-of-two exponent exactly representing the coordinate,
+/// with the mantissa odd whenever it is non-zero.
+fn decompose(value: f64) -> (i128, i32) {
+    let bits = value.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = (bits & 0x000f_ffff_ffff_ffff) as i128;
+
+    let mut mantissa = if biased == 0 {
+        fraction
+    } else {
+        fraction | (1 << 52)
+    };
+
+    let mut exponent = biased.max(1) - 1075;
+    while mantissa != 0 && mantissa & 1 == 0 {
+        mantissa >>= 1;
+        exponent += 1;
+    }
+
+    if bits >> 63 == 1 {
+        mantissa = -mantissa;
+    }
+
+    (mantissa, exponent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compare;
+    use crate::{batch::Operation, cartesian::Polygon, Shape};
+
+    #[test]
+    fn oracle_agrees_with_the_float_path() {
+        struct Test {
+            name: &'static str,
+            subject: Shape<Polygon<f64>>,
+            clip: Shape<Polygon<f64>>,
+            operation: Operation,
+        }
+
+        vec![
+            Test {
+                name: "union of overlapping squares",
+                subject: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                clip: Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]),
+                operation: Operation::Union,
+            },
+            Test {
+                name: "intersection of overlapping squares",
+                subject: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                clip: Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]),
+                operation: Operation::Intersection,
+            },
+            Test {
+                name: "difference of overlapping squares",
+                subject: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                clip: Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]),
+                operation: Operation::Difference,
+            },
+            Test {
+                name: "difference consuming the whole subject",
+                subject: Shape::new(vec![[1., 1.], [3., 1.], [3., 3.], [1., 3.]]),
+                clip: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                operation: Operation::Difference,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let comparison = compare(
+                &test.subject,
+                &test.clip,
+                test.operation,
+                16,
+                Default::default(),
+            );
+
+            assert!(
+                comparison.samples > 0,
+                "{}: the grid must yield comparable samples",
+                test.name
+            );
+            assert!(
+                comparison.mismatches.is_empty(),
+                "{}: both paths must agree, got {:?}",
+                test.name,
+                comparison.mismatches
+            );
+        });
+    }
+}
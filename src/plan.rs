@@ -0,0 +1,264 @@
+//! Reusable clipping plans.
+
+use num_traits::ToPrimitive;
+
+use crate::{
+    clipper::{Clipper, Operator},
+    graph::{Graph, GraphBuilder},
+    options::{AntipodalPolicy, ClipError, ClipOptions},
+    shape::{AndOperator, NotOperator, OrOperator, XorOperator},
+    Edge, Geometry, IsClose, Operands, Shape, Vertex,
+};
+
+/// A clipping operation split at its operator-independent half.
+///
+/// Building the intersection graph and classifying its nodes is where a clipping operation
+/// spends its time, yet none of it depends on whether a union, a difference or an intersection
+/// is being computed. A plan performs that work once and can then execute any amount of
+/// operators over the same pair of shapes, each run paying only for the graph traversal.
+pub struct ClipPlan<T>
+where
+    T: Geometry,
+{
+    subject: Shape<T>,
+    clip: Shape<T>,
+    tolerance: <T::Vertex as IsClose>::Tolerance,
+    options: ClipOptions,
+    graph: Graph<T>,
+}
+
+impl<T> ClipPlan<T>
+where
+    T: Geometry,
+    T::Vertex: Copy + PartialEq + PartialOrd,
+    for<'a> T::Edge<'a>: Edge<'a>,
+    <T::Vertex as Vertex>::Scalar: Copy + PartialOrd + ToPrimitive,
+    <T::Vertex as IsClose>::Tolerance: Clone,
+{
+    /// Returns the plan for clipping the given pair of shapes under the given tolerance.
+    pub fn new(
+        subject: Shape<T>,
+        clip: Shape<T>,
+        tolerance: <T::Vertex as IsClose>::Tolerance,
+    ) -> Result<Self, ClipError> {
+        Self::with_options(subject, clip, tolerance, Default::default())
+    }
+
+    /// Like [`Self::new`], but driven by the given options.
+    pub fn with_options(
+        subject: Shape<T>,
+        clip: Shape<T>,
+        tolerance: <T::Vertex as IsClose>::Tolerance,
+        options: ClipOptions,
+    ) -> Result<Self, ClipError> {
+        if options.antipodal_policy == AntipodalPolicy::Error {
+            let ill_defined = [&subject, &clip].into_iter().any(|shape| {
+                shape
+                    .boundaries
+                    .iter()
+                    .any(|boundary| boundary.edges().any(|edge| edge.is_ill_defined(&tolerance)))
+            });
+
+            if ill_defined {
+                return Err(ClipError::IllDefinedEdge);
+            }
+        }
+
+        let graph = GraphBuilder::new(&tolerance, &options)
+            .with_subject(&subject)
+            .with_clip(&clip)
+            .build()?;
+
+        Ok(Self {
+            subject,
+            clip,
+            tolerance,
+            options,
+            graph,
+        })
+    }
+
+    /// Executes the given [`Operator`] over this plan and returns the resulting [`Shape`], if
+    /// any.
+    ///
+    /// Fails if, and only if, the operation is interrupted before completing.
+    pub fn execute<Op>(&self) -> Result<Option<Shape<T>>, ClipError>
+    where
+        Op: Operator<T>,
+    {
+        Clipper::default()
+            .with_operator::<Op>()
+            .with_options(self.options.clone())
+            .with_tolerance(self.tolerance.clone())
+            .with_subject_ref(&self.subject)
+            .with_clip_ref(&self.clip)
+            .try_execute_prebuilt(self.graph.clone())
+            .map(|(output, _)| output)
+    }
+
+    /// Returns every intersection node of this plan together with its stable identifier.
+    ///
+    /// Identifiers are positions in the plan's intersection graph. The construction of that
+    /// graph is fully deterministic and executing the plan never perturbs it, so the same
+    /// operands, tolerance and options always yield the same identifiers — across executions
+    /// of one plan as well as across plans built anew. Successive results can thus be diffed
+    /// or animated against these nodes without re-matching vertices geometrically.
+    pub fn intersection_nodes(&self) -> impl Iterator<Item = (usize, &T::Vertex)> {
+        self.graph
+            .nodes
+            .iter()
+            .enumerate()
+            .filter_map(|(id, node)| {
+                node.as_ref()
+                    .filter(|node| node.intersection.has_siblings())
+                    .map(|node| (id, node.vertex()))
+            })
+    }
+
+    /// Returns the identifiers of the intersection nodes the given [`Operator`] would include
+    /// in its output, in ascending order.
+    ///
+    /// Diffing these sets between two operators, or between plans differing only in their
+    /// options, locates exactly where the outputs diverge.
+    pub fn output_nodes<Op>(&self) -> Vec<usize>
+    where
+        Op: Operator<T>,
+    {
+        let operands = Operands {
+            subject: &self.subject,
+            clip: &self.clip,
+        };
+
+        self.graph
+            .nodes
+            .iter()
+            .enumerate()
+            .filter_map(|(id, node)| {
+                node.as_ref()
+                    .filter(|node| node.intersection.has_siblings())
+                    .filter(|node| Op::is_output(operands, node, &self.tolerance, &self.options))
+                    .map(|_| id)
+            })
+            .collect()
+    }
+
+    /// Executes the union of the planned shapes.
+    pub fn or(&self) -> Result<Option<Shape<T>>, ClipError> {
+        self.execute::<OrOperator<T>>()
+    }
+
+    /// Executes the difference of the planned shapes.
+    pub fn not(&self) -> Result<Option<Shape<T>>, ClipError> {
+        self.execute::<NotOperator<T>>()
+    }
+
+    /// Executes the intersection of the planned shapes.
+    pub fn and(&self) -> Result<Option<Shape<T>>, ClipError> {
+        self.execute::<AndOperator<T>>()
+    }
+
+    /// Executes the symmetric difference of the planned shapes.
+    pub fn xor(&self) -> Result<Option<Shape<T>>, ClipError> {
+        Clipper::default()
+            .with_operator::<XorOperator<T>>()
+            .with_options(self.options.clone())
+            .with_tolerance(self.tolerance.clone())
+            .with_subject_ref(&self.subject)
+            .with_clip_ref(&self.clip)
+            .try_execute_xor_prebuilt(self.graph.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cartesian::{Point, Polygon},
+        shape::{NotOperator, OrOperator},
+        Shape, Tolerance,
+    };
+
+    use super::ClipPlan;
+
+    #[test]
+    fn node_identifiers_are_stable_across_reuse() {
+        let subject: Shape<Polygon<f64>> =
+            Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip: Shape<Polygon<f64>> = Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]);
+
+        let plan = ClipPlan::new(subject.clone(), clip.clone(), Tolerance::default())
+            .expect("the plan must build");
+
+        let nodes = |plan: &ClipPlan<Polygon<f64>>| -> Vec<(usize, Point<f64>)> {
+            plan.intersection_nodes()
+                .map(|(id, &vertex)| (id, vertex))
+                .collect()
+        };
+
+        let before = nodes(&plan);
+        assert!(
+            !before.is_empty(),
+            "overlapping shapes must intersect somewhere"
+        );
+
+        plan.or().expect("the union must complete");
+        assert_eq!(
+            before,
+            nodes(&plan),
+            "executing the plan must not perturb its node identifiers"
+        );
+
+        let rebuilt = ClipPlan::new(subject, clip, Tolerance::default())
+            .expect("the rebuilt plan must build");
+        assert_eq!(
+            before,
+            nodes(&rebuilt),
+            "plans built from identical inputs must agree on identifiers"
+        );
+
+        assert_eq!(
+            plan.output_nodes::<OrOperator<Polygon<f64>>>(),
+            before.iter().map(|&(id, _)| id).collect::<Vec<_>>(),
+            "the union must include every intersection node"
+        );
+        assert!(
+            plan.output_nodes::<NotOperator<Polygon<f64>>>().is_empty(),
+            "the difference must exclude nodes lying on both boundaries"
+        );
+    }
+
+    #[test]
+    fn one_plan_runs_every_operator() {
+        let subject: Shape<Polygon<f64>> =
+            Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let clip: Shape<Polygon<f64>> = Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]);
+
+        let plan = ClipPlan::new(subject.clone(), clip.clone(), Tolerance::default())
+            .expect("the plan must build");
+
+        assert_eq!(
+            plan.or().expect("the union must complete"),
+            subject.or_ref(&clip, Tolerance::default()),
+            "the planned union must match the direct one"
+        );
+        assert_eq!(
+            plan.not().expect("the difference must complete"),
+            subject.not_ref(&clip, Tolerance::default()),
+            "the planned difference must match the direct one"
+        );
+        assert_eq!(
+            plan.and().expect("the intersection must complete"),
+            subject.and_ref(&clip, Tolerance::default()),
+            "the planned intersection must match the direct one"
+        );
+        assert_eq!(
+            plan.xor().expect("the symmetric difference must complete"),
+            subject.xor_ref(&clip, Tolerance::default()),
+            "the planned symmetric difference must match the direct one"
+        );
+        assert_eq!(
+            plan.or().expect("the union must complete"),
+            subject.or_ref(&clip, Tolerance::default()),
+            "executing the same plan twice must yield the same output"
+        );
+    }
+}
@@ -0,0 +1,185 @@
+//! Clipping across geometries through user-supplied projections.
+//!
+//! Operands living in different spaces cannot be clipped against each other directly: every
+//! boolean operation happens in the space of its subject. The adapters in this module move one
+//! operand into the space of the other through a caller-chosen projection, so the conversion is
+//! handled once at the entry point instead of by every caller. How faithful the result is
+//! depends entirely on the projection: distances and areas are measured in the space where the
+//! clipping runs.
+
+use num_traits::{Euclid, Float, FloatConst, Signed};
+
+use crate::{cartesian, spherical, Shape, Tolerance};
+
+impl<T> Shape<spherical::Polygon<T>>
+where
+    T: Signed + Float + FloatConst + Euclid + 'static,
+{
+    /// Returns this shape with each vertex moved into the cartesian plane through the given
+    /// projection.
+    ///
+    /// The orientation of each boundary is preserved as long as the projection preserves it,
+    /// which is the case for any conformal projection away from its singularities.
+    pub fn projected(
+        self,
+        mut projection: impl FnMut(spherical::Point<T>) -> cartesian::Point<T>,
+    ) -> Shape<cartesian::Polygon<T>> {
+        Shape {
+            boundaries: self
+                .boundaries
+                .into_iter()
+                .map(|boundary| cartesian::Polygon {
+                    vertices: boundary.into_iter().map(&mut projection).collect(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the union of this shape and the given cartesian clip, lifted onto the sphere
+    /// through the given projection.
+    pub fn or_projected(
+        self,
+        clip: Shape<cartesian::Polygon<T>>,
+        projection: impl FnMut(cartesian::Point<T>) -> spherical::Point<T>,
+        exterior: spherical::Point<T>,
+        tolerance: Tolerance<T>,
+    ) -> Option<Self> {
+        self.or(clip.lifted(projection, exterior), tolerance)
+    }
+
+    /// Returns the intersection of this shape and the given cartesian clip, lifted onto the
+    /// sphere through the given projection.
+    pub fn and_projected(
+        self,
+        clip: Shape<cartesian::Polygon<T>>,
+        projection: impl FnMut(cartesian::Point<T>) -> spherical::Point<T>,
+        exterior: spherical::Point<T>,
+        tolerance: Tolerance<T>,
+    ) -> Option<Self> {
+        self.and(clip.lifted(projection, exterior), tolerance)
+    }
+
+    /// Returns the difference between this shape and the given cartesian clip, lifted onto the
+    /// sphere through the given projection.
+    pub fn not_projected(
+        self,
+        clip: Shape<cartesian::Polygon<T>>,
+        projection: impl FnMut(cartesian::Point<T>) -> spherical::Point<T>,
+        exterior: spherical::Point<T>,
+        tolerance: Tolerance<T>,
+    ) -> Option<Self> {
+        self.not(clip.lifted(projection, exterior), tolerance)
+    }
+}
+
+impl<T> Shape<cartesian::Polygon<T>>
+where
+    T: Signed + Float + FloatConst + Euclid + 'static,
+{
+    /// Returns this shape with each vertex lifted onto the sphere through the given projection.
+    ///
+    /// Every boundary is given the same exterior point, which must lie outside all of them for
+    /// the resulting shape to keep its orientation.
+    pub fn lifted(
+        self,
+        mut projection: impl FnMut(cartesian::Point<T>) -> spherical::Point<T>,
+        exterior: spherical::Point<T>,
+    ) -> Shape<spherical::Polygon<T>> {
+        Shape {
+            boundaries: self
+                .boundaries
+                .into_iter()
+                .map(|boundary| spherical::Polygon {
+                    vertices: boundary.into_iter().map(&mut projection).collect(),
+                    exterior,
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the union of this shape and the given spherical clip, projected into the plane
+    /// through the given projection.
+    pub fn or_projected(
+        self,
+        clip: Shape<spherical::Polygon<T>>,
+        projection: impl FnMut(spherical::Point<T>) -> cartesian::Point<T>,
+        tolerance: Tolerance<T>,
+    ) -> Option<Self> {
+        self.or(clip.projected(projection), tolerance)
+    }
+
+    /// Returns the intersection of this shape and the given spherical clip, projected into the
+    /// plane through the given projection.
+    pub fn and_projected(
+        self,
+        clip: Shape<spherical::Polygon<T>>,
+        projection: impl FnMut(spherical::Point<T>) -> cartesian::Point<T>,
+        tolerance: Tolerance<T>,
+    ) -> Option<Self> {
+        self.and(clip.projected(projection), tolerance)
+    }
+
+    /// Returns the difference between this shape and the given spherical clip, projected into
+    /// the plane through the given projection.
+    pub fn not_projected(
+        self,
+        clip: Shape<spherical::Polygon<T>>,
+        projection: impl FnMut(spherical::Point<T>) -> cartesian::Point<T>,
+        tolerance: Tolerance<T>,
+    ) -> Option<Self> {
+        self.not(clip.projected(projection), tolerance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::{cartesian, spherical, Shape, Tolerance};
+
+    /// An equirectangular projection mapping azimuth to x and inclination to y.
+    fn unroll(point: spherical::Point<f64>) -> cartesian::Point<f64> {
+        cartesian::Point {
+            x: point.azimuth.into_inner(),
+            y: point.inclination.into_inner(),
+        }
+    }
+
+    #[test]
+    fn cross_geometry_clipping_projects_the_clip() {
+        let subject: Shape<cartesian::Polygon<f64>> =
+            Shape::new(vec![[0.2, 0.2], [1.4, 0.2], [1.4, 1.4], [0.2, 1.4]]);
+
+        let clip: Shape<spherical::Polygon<f64>> = Shape::new(spherical::Polygon::new(
+            vec![[0.8, 0.8], [0.8, 2.], [2., 2.], [2., 0.8]],
+            [PI, 0.],
+        ));
+
+        let got = subject
+            .clone()
+            .and_projected(clip.clone(), unroll, Tolerance::default());
+
+        assert_eq!(
+            got,
+            subject.and(clip.projected(unroll), Tolerance::default()),
+            "the entry point must match projecting by hand"
+        );
+    }
+
+    #[test]
+    fn lifting_preserves_the_vertices() {
+        let subject: Shape<cartesian::Polygon<f64>> =
+            Shape::new(vec![[0.2, 0.2], [1.4, 0.2], [1.4, 1.4], [0.2, 1.4]]);
+
+        let lifted = subject.clone().lifted(
+            |point| spherical::Point::from([point.y, point.x]),
+            [PI, 0.].into(),
+        );
+
+        assert_eq!(
+            lifted.projected(unroll),
+            subject,
+            "projecting back must restore the original shape"
+        );
+    }
+}
@@ -0,0 +1,245 @@
+//! Reusable algebraic invariants for property-testing [`Geometry`] implementations.
+//!
+//! Each checker returns true when the invariant holds, so they compose directly with any
+//! property-testing framework driving arbitrary shapes through them.
+
+use num_traits::{Signed, ToPrimitive, Zero};
+
+use crate::{Edge, Geometry, IsClose, Shape, Vertex};
+
+/// Returns the filled area of the given shape: holes subtract from it.
+pub fn filled_area<T>(shape: &Shape<T>) -> <T::Vertex as Vertex>::Scalar
+where
+    T: Geometry,
+    <T::Vertex as Vertex>::Scalar: Signed + Copy,
+{
+    shape
+        .boundaries
+        .iter()
+        .fold(<T::Vertex as Vertex>::Scalar::zero(), |sum, boundary| {
+            if boundary.is_clockwise() {
+                sum - boundary.area()
+            } else {
+                sum + boundary.area()
+            }
+        })
+}
+
+/// Returns true if, and only if, both shapes describe the same region, up to the order of their
+/// boundaries and the starting vertex of each one.
+pub fn equivalent<T>(
+    left: &Shape<T>,
+    right: &Shape<T>,
+    tolerance: &<T::Vertex as IsClose>::Tolerance,
+) -> bool
+where
+    T: Geometry,
+    T::Vertex: Copy,
+{
+    let boundaries = |shape: &Shape<T>| -> Vec<Vec<T::Vertex>> {
+        shape
+            .boundaries
+            .iter()
+            .map(|boundary| boundary.edges().map(|edge| *edge.start()).collect())
+            .collect()
+    };
+
+    let left = boundaries(left);
+    let right = boundaries(right);
+    if left.len() != right.len() {
+        return false;
+    }
+
+    let mut matched = vec![false; right.len()];
+    left.iter().all(|boundary| {
+        right.iter().enumerate().any(|(position, candidate)| {
+            if matched[position] || !is_rotation(boundary, candidate, tolerance) {
+                return false;
+            }
+
+            matched[position] = true;
+            true
+        })
+    })
+}
+
+/// Returns true if, and only if, the union of both shapes does not depend on the operand order.
+pub fn or_commutes<T>(
+    left: &Shape<T>,
+    right: &Shape<T>,
+    tolerance: <T::Vertex as IsClose>::Tolerance,
+) -> bool
+where
+    T: Geometry + Clone,
+    T::Vertex: Copy + PartialEq + PartialOrd,
+    for<'a> T::Edge<'a>: Edge<'a>,
+    <T::Vertex as Vertex>::Scalar: Copy + PartialOrd + ToPrimitive,
+    <T::Vertex as IsClose>::Tolerance: Clone,
+{
+    agree(
+        left.or_ref(right, tolerance.clone()),
+        right.or_ref(left, tolerance.clone()),
+        &tolerance,
+    )
+}
+
+/// Returns true if, and only if, the intersection of both shapes does not depend on the operand
+/// order.
+pub fn and_commutes<T>(
+    left: &Shape<T>,
+    right: &Shape<T>,
+    tolerance: <T::Vertex as IsClose>::Tolerance,
+) -> bool
+where
+    T: Geometry + Clone,
+    T::Vertex: Copy + PartialEq + PartialOrd,
+    for<'a> T::Edge<'a>: Edge<'a>,
+    <T::Vertex as Vertex>::Scalar: Copy + PartialOrd + ToPrimitive,
+    <T::Vertex as IsClose>::Tolerance: Clone,
+{
+    agree(
+        left.and_ref(right, tolerance.clone()),
+        right.and_ref(left, tolerance.clone()),
+        &tolerance,
+    )
+}
+
+/// Returns true if, and only if, combining the shape with itself yields the shape back, both
+/// under union and intersection.
+pub fn idempotent<T>(shape: &Shape<T>, tolerance: <T::Vertex as IsClose>::Tolerance) -> bool
+where
+    T: Geometry + Clone,
+    T::Vertex: Copy + PartialEq + PartialOrd,
+    for<'a> T::Edge<'a>: Edge<'a>,
+    <T::Vertex as Vertex>::Scalar: Copy + PartialOrd + ToPrimitive,
+    <T::Vertex as IsClose>::Tolerance: Clone,
+{
+    [
+        shape.or_ref(shape, tolerance.clone()),
+        shape.and_ref(shape, tolerance.clone()),
+    ]
+    .iter()
+    .all(|combined| {
+        combined
+            .as_ref()
+            .is_some_and(|combined| equivalent(combined, shape, &tolerance))
+    })
+}
+
+/// Returns true if, and only if, the areas of both shapes add up to the areas of their union and
+/// intersection, within the given epsilon.
+pub fn areas_additive<T>(
+    left: &Shape<T>,
+    right: &Shape<T>,
+    tolerance: <T::Vertex as IsClose>::Tolerance,
+    epsilon: <T::Vertex as Vertex>::Scalar,
+) -> bool
+where
+    T: Geometry + Clone,
+    T::Vertex: Copy + PartialEq + PartialOrd,
+    for<'a> T::Edge<'a>: Edge<'a>,
+    <T::Vertex as Vertex>::Scalar: Signed + Copy + PartialOrd + ToPrimitive,
+    <T::Vertex as IsClose>::Tolerance: Clone,
+{
+    let union = left.or_ref(right, tolerance.clone());
+    let intersection = left.and_ref(right, tolerance);
+
+    let separate = filled_area(left) + filled_area(right);
+    let combined = area_of(&union) + area_of(&intersection);
+
+    (separate - combined).abs() <= epsilon
+}
+
+/// Returns true if, and only if, the difference and the intersection of both shapes partition
+/// the left one, area-wise, within the given epsilon.
+pub fn difference_partitions<T>(
+    left: &Shape<T>,
+    right: &Shape<T>,
+    tolerance: <T::Vertex as IsClose>::Tolerance,
+    epsilon: <T::Vertex as Vertex>::Scalar,
+) -> bool
+where
+    T: Geometry + Clone,
+    T::Vertex: Copy + PartialEq + PartialOrd,
+    for<'a> T::Edge<'a>: Edge<'a>,
+    <T::Vertex as Vertex>::Scalar: Signed + Copy + PartialOrd + ToPrimitive,
+    <T::Vertex as IsClose>::Tolerance: Clone,
+{
+    let difference = left.not_ref(right, tolerance.clone());
+    let intersection = left.and_ref(right, tolerance);
+
+    let combined = area_of(&difference) + area_of(&intersection);
+    (filled_area(left) - combined).abs() <= epsilon
+}
+
+/// Returns true if, and only if, both optional shapes are equivalent, or both are absent.
+fn agree<T>(
+    left: Option<Shape<T>>,
+    right: Option<Shape<T>>,
+    tolerance: &<T::Vertex as IsClose>::Tolerance,
+) -> bool
+where
+    T: Geometry,
+    T::Vertex: Copy,
+{
+    match (left, right) {
+        (Some(left), Some(right)) => equivalent(&left, &right, tolerance),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Returns the filled area of the given optional shape, zero when absent.
+fn area_of<T>(shape: &Option<Shape<T>>) -> <T::Vertex as Vertex>::Scalar
+where
+    T: Geometry,
+    <T::Vertex as Vertex>::Scalar: Signed + Copy,
+{
+    shape
+        .as_ref()
+        .map(filled_area)
+        .unwrap_or_else(<T::Vertex as Vertex>::Scalar::zero)
+}
+
+/// Returns true if, and only if, one vertex sequence is a cyclic rotation of the other.
+fn is_rotation<V>(left: &[V], right: &[V], tolerance: &V::Tolerance) -> bool
+where
+    V: IsClose,
+{
+    left.len() == right.len()
+        && (left.is_empty()
+            || (0..left.len()).any(|shift| {
+                left.iter()
+                    .zip(right.iter().cycle().skip(shift))
+                    .all(|(this, other)| this.is_close(other, tolerance))
+            }))
+}
+
+#[cfg(test)]
+#[cfg(feature = "cartesian")]
+mod tests {
+    use crate::{cartesian::Polygon, Shape};
+
+    use super::{and_commutes, areas_additive, difference_partitions, equivalent, or_commutes};
+
+    #[test]
+    fn overlapping_squares_satisfy_invariants() {
+        let left: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let right: Shape<Polygon<f64>> = Shape::new(vec![[2., 2.], [6., 2.], [6., 6.], [2., 6.]]);
+
+        assert!(or_commutes(&left, &right, Default::default()));
+        assert!(and_commutes(&left, &right, Default::default()));
+        assert!(areas_additive(&left, &right, Default::default(), 1e-9));
+        assert!(difference_partitions(&left, &right, Default::default(), 1e-9));
+    }
+
+    #[test]
+    fn equivalence_ignores_boundary_rotation() {
+        let shape: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let rotated: Shape<Polygon<f64>> = Shape::new(vec![[4., 4.], [0., 4.], [0., 0.], [4., 0.]]);
+        let other: Shape<Polygon<f64>> = Shape::new(vec![[0., 0.], [4., 0.], [4., 4.]]);
+
+        assert!(equivalent(&shape, &rotated, &Default::default()));
+        assert!(!equivalent(&shape, &other, &Default::default()));
+    }
+}
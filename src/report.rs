@@ -0,0 +1,64 @@
+//! Diagnostic information about clipping operations.
+
+use crate::{Geometry, Shape};
+
+/// The output of a diagnosed clipping operation: the resulting shape, if any, alongside the
+/// boundaries discarded while assembling it.
+pub type Diagnosed<G> = (
+    Option<Shape<G>>,
+    Vec<DroppedBoundary<<G as Geometry>::Vertex>>,
+);
+
+/// A boundary discarded while assembling the output of a clipping operation.
+///
+/// Discarded boundaries are not errors: the operation completes without them. They are, however,
+/// the reason why a clip may yield less output than expected, so carrying a witness vertex makes
+/// the offending geometry easy to locate in the input data.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DroppedBoundary<V> {
+    /// A vertex of the discarded boundary, if it had any.
+    pub vertex: Option<V>,
+    /// The amount of vertices the boundary held when discarded.
+    pub vertices: usize,
+    /// The reason why the boundary was discarded.
+    pub reason: DropReason,
+}
+
+/// The reason why a boundary was discarded from the output of a clipping operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropReason {
+    /// The traversed vertices could not be assembled back into a valid geometry.
+    Unconstructible,
+    /// The boundary fell below one of the sliver thresholds of the operation.
+    Sliver,
+    /// The boundary collapsed to a line or a point, which the operation's
+    /// [`DegeneratePolicy`](crate::DegeneratePolicy) excludes from the output.
+    Degenerate,
+}
+
+/// An upper-bound estimate of the workload of clipping a pair of shapes.
+///
+/// Estimates are computed from the operands alone, before any clipping work is done, so
+/// services can reject or shard pathological jobs proactively instead of discovering their
+/// cost by running them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GraphSizeEstimate {
+    /// The amount of edge pairs the intersection search will visit.
+    pub edge_pairs: usize,
+    /// The maximum amount of intersections the visited pairs can register.
+    pub intersections: usize,
+    /// The maximum amount of nodes the intersection graph can hold.
+    pub nodes: usize,
+}
+
+/// A point where two boundaries touch without crossing each other.
+///
+/// Tangential intersections do not contribute to the output of a clipping operation, but they
+/// are what distinguishes a "touching" pair of shapes from a "disjoint" one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Touch<V> {
+    /// The point shared by both boundaries.
+    pub vertex: V,
+    /// The amount of coincident vertices collapsed at this point.
+    pub multiplicity: usize,
+}
@@ -0,0 +1,46 @@
+use proj::Proj;
+
+use crate::{cartesian::Point, cartesian::Polygon, Shape};
+
+/// The reason why a reprojection did not complete.
+#[derive(Debug)]
+pub enum ReprojectError {
+    /// The transformation between the two coordinate reference systems could not be created.
+    Create(proj::ProjCreateError),
+    /// A vertex could not be transformed.
+    Transform(proj::ProjError),
+}
+
+impl From<proj::ProjCreateError> for ReprojectError {
+    fn from(error: proj::ProjCreateError) -> Self {
+        Self::Create(error)
+    }
+}
+
+impl From<proj::ProjError> for ReprojectError {
+    fn from(error: proj::ProjError) -> Self {
+        Self::Transform(error)
+    }
+}
+
+impl Shape<Polygon<f64>> {
+    /// Returns this shape reprojected from the given coordinate reference system into the target
+    /// one.
+    pub fn reproject(self, from: &str, to: &str) -> Result<Self, ReprojectError> {
+        let projection = Proj::new_known_crs(from, to, None)?;
+
+        let mut error = None;
+        let shape = self.transform_coords(|point| match projection.convert((point.x, point.y)) {
+            Ok((x, y)) => Point { x, y },
+            Err(err) => {
+                error = error.take().or(Some(err));
+                point
+            }
+        });
+
+        match error {
+            Some(error) => Err(error.into()),
+            None => Ok(shape),
+        }
+    }
+}
@@ -1,11 +1,257 @@
 use std::{fmt::Debug, marker::PhantomData};
 
+use num_traits::{Signed, ToPrimitive, Zero};
+
 use crate::{
     clipper::{Clipper, Direction, Operator},
-    graph::{BoundaryRole, IntersectionKind, Node},
+    either::Either,
+    graph::{BoundaryRole, GraphBuilder, IntersectionKind, Node},
+    options::{AreaConvention, ClipError, ClipOptions, FillRule},
+    report::{Diagnosed, GraphSizeEstimate, Touch},
     Edge, Geometry, IsClose, Operands, Vertex,
 };
 
+/// The [`Operator`] implementing the union of two shapes.
+pub(crate) struct OrOperator<T>(PhantomData<T>);
+
+impl<T> Operator<T> for OrOperator<T>
+where
+    T: Geometry,
+{
+    fn is_output<'a>(
+        ops: Operands<'a, T>,
+        node: &'a Node<T>,
+        tolerance: &<T::Vertex as IsClose>::Tolerance,
+        options: &ClipOptions,
+    ) -> bool {
+        match node.boundary {
+            BoundaryRole::Subject(_) => {
+                !ops.clip
+                    .contains_with(&node.vertex, tolerance, options.fill_rule)
+                    || ops.clip.is_boundary(&node.vertex, tolerance)
+            }
+            BoundaryRole::Clip(_) => {
+                !ops.subject
+                    .contains_with(&node.vertex, tolerance, options.fill_rule)
+                    || ops.subject.is_boundary(&node.vertex, tolerance)
+            }
+        }
+    }
+
+    fn direction(node: &Node<T>) -> Direction {
+        let Some(intersection) = node.intersection.kind else {
+            return Direction::Forward;
+        };
+
+        match intersection {
+            IntersectionKind::Entry => Direction::Backward,
+            IntersectionKind::Exit => Direction::Forward,
+        }
+    }
+}
+
+/// The [`Operator`] implementing the difference of two shapes.
+pub(crate) struct NotOperator<T>(PhantomData<T>);
+
+impl<T> Operator<T> for NotOperator<T>
+where
+    T: Geometry,
+{
+    fn is_output<'a>(
+        ops: Operands<'a, T>,
+        node: &'a Node<T>,
+        tolerance: &<T::Vertex as IsClose>::Tolerance,
+        options: &ClipOptions,
+    ) -> bool {
+        match node.boundary {
+            BoundaryRole::Subject(_) => {
+                !ops.clip
+                    .contains_with(&node.vertex, tolerance, options.fill_rule)
+                    && !ops.clip.is_boundary(&node.vertex, tolerance)
+            }
+            BoundaryRole::Clip(_) => {
+                ops.subject
+                    .contains_with(&node.vertex, tolerance, options.fill_rule)
+                    && !ops.subject.is_boundary(&node.vertex, tolerance)
+            }
+        }
+    }
+
+    fn direction(node: &Node<T>) -> Direction {
+        let Some(intersection) = node.intersection.kind else {
+            return if node.boundary.is_subject() {
+                Direction::Forward
+            } else {
+                Direction::Backward
+            };
+        };
+
+        match (node.boundary, intersection) {
+            (BoundaryRole::Subject(_), IntersectionKind::Entry) => Direction::Backward,
+            (BoundaryRole::Subject(_), IntersectionKind::Exit) => Direction::Forward,
+            (BoundaryRole::Clip(_), IntersectionKind::Entry) => Direction::Forward,
+            (BoundaryRole::Clip(_), IntersectionKind::Exit) => Direction::Backward,
+        }
+    }
+}
+
+/// The [`Operator`] implementing the difference of two shapes with their roles reversed.
+///
+/// This computes the clip shape minus the subject, letting a reusable plan yield both
+/// directions of the difference without rebuilding its graph.
+pub(crate) struct ReverseNotOperator<T>(PhantomData<T>);
+
+impl<T> Operator<T> for ReverseNotOperator<T>
+where
+    T: Geometry,
+{
+    fn is_output<'a>(
+        ops: Operands<'a, T>,
+        node: &'a Node<T>,
+        tolerance: &<T::Vertex as IsClose>::Tolerance,
+        options: &ClipOptions,
+    ) -> bool {
+        match node.boundary {
+            BoundaryRole::Subject(_) => {
+                ops.clip
+                    .contains_with(&node.vertex, tolerance, options.fill_rule)
+                    && !ops.clip.is_boundary(&node.vertex, tolerance)
+            }
+            BoundaryRole::Clip(_) => {
+                !ops.subject
+                    .contains_with(&node.vertex, tolerance, options.fill_rule)
+                    && !ops.subject.is_boundary(&node.vertex, tolerance)
+            }
+        }
+    }
+
+    fn direction(node: &Node<T>) -> Direction {
+        let Some(intersection) = node.intersection.kind else {
+            return if node.boundary.is_subject() {
+                Direction::Backward
+            } else {
+                Direction::Forward
+            };
+        };
+
+        match (node.boundary, intersection) {
+            (BoundaryRole::Subject(_), IntersectionKind::Entry) => Direction::Forward,
+            (BoundaryRole::Subject(_), IntersectionKind::Exit) => Direction::Backward,
+            (BoundaryRole::Clip(_), IntersectionKind::Entry) => Direction::Backward,
+            (BoundaryRole::Clip(_), IntersectionKind::Exit) => Direction::Forward,
+        }
+    }
+}
+
+/// The [`Operator`] implementing the symmetric difference of two shapes.
+///
+/// The symmetric difference keeps the regions covered by exactly one of the operands. No
+/// single traversal can assemble it, since every intersection node lies on both of its lobes
+/// yet is consumed at most once: the clipper resolves this operator by traversing the same
+/// graph once per direction of the difference, as documented on
+/// [`Clipper::try_execute_xor`].
+pub(crate) struct XorOperator<T>(PhantomData<T>);
+
+impl<T> Operator<T> for XorOperator<T>
+where
+    T: Geometry,
+{
+    fn is_output<'a>(
+        ops: Operands<'a, T>,
+        node: &'a Node<T>,
+        tolerance: &<T::Vertex as IsClose>::Tolerance,
+        _options: &ClipOptions,
+    ) -> bool {
+        // A vertex strictly inside or strictly outside the opposite shape lies on the boundary
+        // of exactly one lobe; only coincident geometry, interior to neither lobe, is excluded.
+        match node.boundary {
+            BoundaryRole::Subject(_) => !ops.clip.is_boundary(&node.vertex, tolerance),
+            BoundaryRole::Clip(_) => !ops.subject.is_boundary(&node.vertex, tolerance),
+        }
+    }
+
+    /// The directions of the subject lobe; the clip lobe is traversed with those of
+    /// [`ReverseNotOperator`].
+    fn direction(node: &Node<T>) -> Direction {
+        <NotOperator<T> as Operator<T>>::direction(node)
+    }
+}
+
+/// The [`Operator`] implementing the intersection of two shapes.
+pub(crate) struct AndOperator<T>(PhantomData<T>);
+
+impl<T> Operator<T> for AndOperator<T>
+where
+    T: Geometry,
+{
+    fn is_output<'a>(
+        ops: Operands<'a, T>,
+        node: &'a Node<T>,
+        tolerance: &<T::Vertex as IsClose>::Tolerance,
+        options: &ClipOptions,
+    ) -> bool {
+        match node.boundary {
+            BoundaryRole::Subject(_) => {
+                ops.clip
+                    .contains_with(&node.vertex, tolerance, options.fill_rule)
+                    || ops.clip.is_boundary(&node.vertex, tolerance)
+            }
+            BoundaryRole::Clip(_) => {
+                ops.subject
+                    .contains_with(&node.vertex, tolerance, options.fill_rule)
+                    || ops.subject.is_boundary(&node.vertex, tolerance)
+            }
+        }
+    }
+
+    fn direction(node: &Node<T>) -> Direction {
+        let Some(intersection) = node.intersection.kind else {
+            return Direction::Forward;
+        };
+
+        match intersection {
+            IntersectionKind::Entry => Direction::Forward,
+            IntersectionKind::Exit => Direction::Backward,
+        }
+    }
+}
+
+/// The orientation of a boundary in a [`Shape`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// The boundary is oriented clockwise, delimiting a hole.
+    Clockwise,
+    /// The boundary is oriented counterclockwise, delimiting a filled region.
+    Counterclockwise,
+}
+
+/// The position of a vertex relative to a [`Shape`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    /// The vertex lies strictly inside the filled region of the shape.
+    Inside,
+    /// The vertex lies strictly outside the filled region of the shape.
+    Outside,
+    /// The vertex lies on one of the boundaries of the shape.
+    OnBoundary {
+        /// The index of the boundary containing the vertex.
+        boundary: usize,
+        /// The index of the edge in that boundary containing the vertex.
+        edge: usize,
+    },
+}
+
+/// The relation of a single boundary to a clipping operand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundaryClass {
+    /// Every point of the boundary lies inside the filled region of the other shape.
+    Inside,
+    /// Every point of the boundary lies outside the filled region of the other shape.
+    Outside,
+    /// The boundary intersects a boundary of the other shape.
+    Crossing,
+}
+
 /// A combination of disjoint boundaries.
 #[derive(Debug, Clone)]
 pub struct Shape<T> {
@@ -22,6 +268,89 @@ where
     }
 }
 
+impl<T> IsClose for Shape<T>
+where
+    T: IsClose,
+{
+    type Tolerance = T::Tolerance;
+
+    /// Two shapes are close if, and only if, each boundary of one has a close counterpart in
+    /// the other.
+    fn is_close(&self, other: &Self, tolerance: &Self::Tolerance) -> bool {
+        if self.boundaries.len() != other.boundaries.len() {
+            return false;
+        }
+
+        self.boundaries
+            .iter()
+            .all(|a| other.boundaries.iter().any(|b| a.is_close(b, tolerance)))
+    }
+}
+
+impl<T> Shape<T>
+where
+    T: IsClose + Debug,
+{
+    /// Returns a line per mismatch between this shape and the other, for the failure report of
+    /// [`assert_shapes_close!`].
+    #[doc(hidden)]
+    pub fn closeness_mismatches(&self, other: &Self, tolerance: &T::Tolerance) -> Vec<String> {
+        let mut mismatches = Vec::new();
+        if self.boundaries.len() != other.boundaries.len() {
+            mismatches.push(format!(
+                "amount of boundaries: got {}, want {}",
+                self.boundaries.len(),
+                other.boundaries.len(),
+            ));
+        }
+
+        for (position, boundary) in self.boundaries.iter().enumerate() {
+            if !other
+                .boundaries
+                .iter()
+                .any(|candidate| boundary.is_close(candidate, tolerance))
+            {
+                mismatches.push(format!(
+                    "boundary {position} has no close counterpart: {boundary:?}"
+                ));
+            }
+        }
+
+        for (position, boundary) in other.boundaries.iter().enumerate() {
+            if !self
+                .boundaries
+                .iter()
+                .any(|candidate| candidate.is_close(boundary, tolerance))
+            {
+                mismatches.push(format!(
+                    "wanted boundary {position} is unmatched: {boundary:?}"
+                ));
+            }
+        }
+
+        mismatches
+    }
+}
+
+/// Asserts that two shapes are close under the given tolerance.
+///
+/// On failure, panics with a report of every boundary lacking a close counterpart in the other
+/// shape, which locates the offending geometry faster than the exact comparison of
+/// [`assert_eq!`] on floating point outputs.
+#[macro_export]
+macro_rules! assert_shapes_close {
+    ($got:expr, $want:expr, $tolerance:expr $(,)?) => {
+        match (&$got, &$want, &$tolerance) {
+            (got, want, tolerance) => {
+                let mismatches = got.closeness_mismatches(want, tolerance);
+                if !mismatches.is_empty() {
+                    panic!("shapes are not close:\n  {}", mismatches.join("\n  "));
+                }
+            }
+        }
+    };
+}
+
 impl<T> PartialEq for Shape<T>
 where
     T: PartialEq + Clone,
@@ -39,150 +368,453 @@ where
 
 impl<T> Shape<T>
 where
-    T: Geometry + Clone + IntoIterator<Item = T::Vertex>,
+    T: Geometry,
     T::Vertex: Copy + PartialEq + PartialOrd,
     for<'a> T::Edge<'a>: Edge<'a>,
-    <T::Vertex as Vertex>::Scalar: Copy + PartialOrd,
+    <T::Vertex as Vertex>::Scalar: Copy + PartialOrd + ToPrimitive,
+    <T::Vertex as IsClose>::Tolerance: Clone,
 {
     /// Returns the union of this shape and the other.
     pub fn or(self, other: Self, tolerance: <T::Vertex as IsClose>::Tolerance) -> Option<Self> {
-        struct OrOperator<T>(PhantomData<T>);
-
-        impl<T> Operator<T> for OrOperator<T>
-        where
-            T: Geometry,
-        {
-            fn is_output<'a>(
-                ops: Operands<'a, T>,
-                node: &'a Node<T>,
-                tolerance: &<T::Vertex as IsClose>::Tolerance,
-            ) -> bool {
-                match node.boundary {
-                    BoundaryRole::Subject(_) => {
-                        !ops.clip.contains(&node.vertex, tolerance)
-                            || ops.clip.is_boundary(&node.vertex, tolerance)
-                    }
-                    BoundaryRole::Clip(_) => {
-                        !ops.subject.contains(&node.vertex, tolerance)
-                            || ops.subject.is_boundary(&node.vertex, tolerance)
-                    }
-                }
-            }
-
-            fn direction(node: &Node<T>) -> Direction {
-                let Some(intersection) = node.intersection.kind else {
-                    return Direction::Forward;
-                };
-
-                match intersection {
-                    IntersectionKind::Entry => Direction::Backward,
-                    IntersectionKind::Exit => Direction::Forward,
-                }
-            }
-        }
+        self.or_with(other, tolerance, Default::default())
+            .unwrap_or_default()
+    }
 
+    /// Returns the union of this shape and the other, driven by the given options.
+    pub fn or_with(
+        self,
+        other: Self,
+        tolerance: <T::Vertex as IsClose>::Tolerance,
+        options: ClipOptions,
+    ) -> Result<Option<Self>, ClipError> {
         Clipper::default()
             .with_operator::<OrOperator<T>>()
+            .with_options(options)
             .with_tolerance(tolerance)
             .with_subject(self)
             .with_clip(other)
-            .execute()
+            .try_execute()
+    }
+
+    /// Returns the union of this shape and the other, borrowing both operands.
+    ///
+    /// Unlike [`Self::or`], no input shape is ever copied, which makes this the cheapest entry
+    /// point when the operands must outlive the operation.
+    pub fn or_ref(
+        &self,
+        other: &Self,
+        tolerance: <T::Vertex as IsClose>::Tolerance,
+    ) -> Option<Self> {
+        Clipper::default()
+            .with_operator::<OrOperator<T>>()
+            .with_tolerance(tolerance)
+            .with_subject_ref(self)
+            .with_clip_ref(other)
+            .try_execute()
+            .unwrap_or_default()
+    }
+
+    /// Replaces this shape with the union of itself and the other.
+    ///
+    /// This is the cheapest way to grow an accumulator shape from many small additions: the
+    /// other shape is only borrowed and the accumulator keeps its boundaries buffer between
+    /// calls. If the union yields no output the accumulator is left untouched.
+    pub fn union_in_place(&mut self, other: &Self, tolerance: <T::Vertex as IsClose>::Tolerance) {
+        if let Some(union) = self.or_ref(other, tolerance) {
+            self.boundaries.clear();
+            self.boundaries.extend(union.boundaries);
+        }
     }
 
     /// Returns the difference of the other shape on this one.
     pub fn not(self, other: Self, tolerance: <T::Vertex as IsClose>::Tolerance) -> Option<Self> {
-        struct NotOperator<T>(PhantomData<T>);
-
-        impl<T> Operator<T> for NotOperator<T>
-        where
-            T: Geometry,
-        {
-            fn is_output<'a>(
-                ops: Operands<'a, T>,
-                node: &'a Node<T>,
-                tolerance: &<T::Vertex as IsClose>::Tolerance,
-            ) -> bool {
-                match node.boundary {
-                    BoundaryRole::Subject(_) => {
-                        !ops.clip.contains(&node.vertex, tolerance)
-                            && !ops.clip.is_boundary(&node.vertex, tolerance)
-                    }
-                    BoundaryRole::Clip(_) => {
-                        ops.subject.contains(&node.vertex, tolerance)
-                            && !ops.subject.is_boundary(&node.vertex, tolerance)
-                    }
-                }
-            }
+        self.not_with(other, tolerance, Default::default())
+            .unwrap_or_default()
+    }
 
-            fn direction(node: &Node<T>) -> Direction {
-                let Some(intersection) = node.intersection.kind else {
-                    return if node.boundary.is_subject() {
-                        Direction::Forward
-                    } else {
-                        Direction::Backward
-                    };
-                };
+    /// Returns the difference of the other shape on this one, driven by the given options.
+    pub fn not_with(
+        self,
+        other: Self,
+        tolerance: <T::Vertex as IsClose>::Tolerance,
+        options: ClipOptions,
+    ) -> Result<Option<Self>, ClipError> {
+        Clipper::default()
+            .with_operator::<NotOperator<T>>()
+            .with_options(options)
+            .with_tolerance(tolerance)
+            .with_clip(other)
+            .with_subject(self)
+            .try_execute()
+    }
 
-                match (node.boundary, intersection) {
-                    (BoundaryRole::Subject(_), IntersectionKind::Entry) => Direction::Backward,
-                    (BoundaryRole::Subject(_), IntersectionKind::Exit) => Direction::Forward,
-                    (BoundaryRole::Clip(_), IntersectionKind::Entry) => Direction::Forward,
-                    (BoundaryRole::Clip(_), IntersectionKind::Exit) => Direction::Backward,
-                }
+    /// Returns the difference of the other shape on this one, borrowing both operands.
+    pub fn not_ref(
+        &self,
+        other: &Self,
+        tolerance: <T::Vertex as IsClose>::Tolerance,
+    ) -> Option<Self> {
+        Clipper::default()
+            .with_operator::<NotOperator<T>>()
+            .with_tolerance(tolerance)
+            .with_clip_ref(other)
+            .with_subject_ref(self)
+            .try_execute()
+            .unwrap_or_default()
+    }
+
+    /// Returns the result of clipping this shape against the other with the given [`Operator`].
+    ///
+    /// This is the extension point for output rules beyond the built-in union, difference and
+    /// intersection.
+    pub fn clip_with<Op>(
+        &self,
+        other: &Self,
+        tolerance: <T::Vertex as IsClose>::Tolerance,
+    ) -> Option<Self>
+    where
+        Op: Operator<T>,
+    {
+        Clipper::default()
+            .with_operator::<Op>()
+            .with_tolerance(tolerance)
+            .with_subject_ref(self)
+            .with_clip_ref(other)
+            .try_execute()
+            .unwrap_or_default()
+    }
+
+    /// Like [`Self::clip_with`], but driven by the given options and also returning a
+    /// [`DroppedBoundary`] for every boundary discarded while assembling the output.
+    ///
+    /// A discarded boundary is not an error: the operation completes without it. The diagnostics
+    /// exist to explain an unexpectedly empty or incomplete result, carrying a witness vertex
+    /// that locates the offending geometry in the input data.
+    pub fn clip_with_diagnosed<Op>(
+        &self,
+        other: &Self,
+        tolerance: <T::Vertex as IsClose>::Tolerance,
+        options: ClipOptions,
+    ) -> Result<Diagnosed<T>, ClipError>
+    where
+        Op: Operator<T>,
+    {
+        Clipper::default()
+            .with_operator::<Op>()
+            .with_options(options)
+            .with_tolerance(tolerance)
+            .with_subject_ref(self)
+            .with_clip_ref(other)
+            .try_execute_diagnosed()
+    }
+
+    /// Like [`Self::or_with`], but borrowing both operands and also returning the boundaries
+    /// discarded while assembling the output.
+    pub fn or_diagnosed(
+        &self,
+        other: &Self,
+        tolerance: <T::Vertex as IsClose>::Tolerance,
+        options: ClipOptions,
+    ) -> Result<Diagnosed<T>, ClipError> {
+        self.clip_with_diagnosed::<OrOperator<T>>(other, tolerance, options)
+    }
+
+    /// Like [`Self::not_with`], but borrowing both operands and also returning the boundaries
+    /// discarded while assembling the output.
+    pub fn not_diagnosed(
+        &self,
+        other: &Self,
+        tolerance: <T::Vertex as IsClose>::Tolerance,
+        options: ClipOptions,
+    ) -> Result<Diagnosed<T>, ClipError> {
+        self.clip_with_diagnosed::<NotOperator<T>>(other, tolerance, options)
+    }
+
+    /// Like [`Self::and_with`], but borrowing both operands and also returning the boundaries
+    /// discarded while assembling the output.
+    pub fn and_diagnosed(
+        &self,
+        other: &Self,
+        tolerance: <T::Vertex as IsClose>::Tolerance,
+        options: ClipOptions,
+    ) -> Result<Diagnosed<T>, ClipError> {
+        self.clip_with_diagnosed::<AndOperator<T>>(other, tolerance, options)
+    }
+
+    /// Returns the difference between this shape and all the given clips.
+    ///
+    /// The clips are merged into a single shape first, so the subject is walked once instead of
+    /// once per clip.
+    pub fn subtract_all(
+        self,
+        clips: impl IntoIterator<Item = Self>,
+        tolerance: <T::Vertex as IsClose>::Tolerance,
+    ) -> Option<Self>
+    where
+        <T::Vertex as IsClose>::Tolerance: Clone,
+    {
+        let mut clips = clips.into_iter();
+        let Some(mut merged) = clips.next() else {
+            return Some(self);
+        };
+
+        for clip in clips {
+            if let Some(union) = merged.or_ref(&clip, tolerance.clone()) {
+                merged = union;
             }
         }
 
+        self.not(merged, tolerance)
+    }
+
+    /// Returns the intersection of this shape and the other.
+    pub fn and(self, other: Self, tolerance: <T::Vertex as IsClose>::Tolerance) -> Option<Self> {
+        self.and_with(other, tolerance, Default::default())
+            .unwrap_or_default()
+    }
+
+    /// Returns the intersection of this shape and the other, driven by the given options.
+    pub fn and_with(
+        self,
+        other: Self,
+        tolerance: <T::Vertex as IsClose>::Tolerance,
+        options: ClipOptions,
+    ) -> Result<Option<Self>, ClipError> {
         Clipper::default()
-            .with_operator::<NotOperator<T>>()
+            .with_operator::<AndOperator<T>>()
+            .with_options(options)
             .with_tolerance(tolerance)
+            .with_subject(self)
             .with_clip(other)
+            .try_execute()
+    }
+
+    /// Returns the symmetric difference of this shape and the other, keeping the regions
+    /// covered by exactly one of them.
+    pub fn xor(self, other: Self, tolerance: <T::Vertex as IsClose>::Tolerance) -> Option<Self> {
+        self.xor_with(other, tolerance, Default::default())
+            .unwrap_or_default()
+    }
+
+    /// Returns the symmetric difference of this shape and the other, driven by the given
+    /// options.
+    pub fn xor_with(
+        self,
+        other: Self,
+        tolerance: <T::Vertex as IsClose>::Tolerance,
+        options: ClipOptions,
+    ) -> Result<Option<Self>, ClipError> {
+        Clipper::default()
+            .with_operator::<XorOperator<T>>()
+            .with_options(options)
+            .with_tolerance(tolerance)
             .with_subject(self)
-            .execute()
+            .with_clip(other)
+            .try_execute_xor()
     }
 
-    /// Returns the intersection of this shape and the other.
-    pub fn and(self, other: Self, tolerance: <T::Vertex as IsClose>::Tolerance) -> Option<Self> {
-        struct AndOperator<T>(PhantomData<T>);
-
-        impl<T> Operator<T> for AndOperator<T>
-        where
-            T: Geometry,
-        {
-            fn is_output<'a>(
-                ops: Operands<'a, T>,
-                node: &'a Node<T>,
-                tolerance: &<T::Vertex as IsClose>::Tolerance,
-            ) -> bool {
-                match node.boundary {
-                    BoundaryRole::Subject(_) => {
-                        ops.clip.contains(&node.vertex, tolerance)
-                            || ops.clip.is_boundary(&node.vertex, tolerance)
-                    }
-                    BoundaryRole::Clip(_) => {
-                        ops.subject.contains(&node.vertex, tolerance)
-                            || ops.subject.is_boundary(&node.vertex, tolerance)
+    /// Returns the symmetric difference of this shape and the other, borrowing both operands.
+    pub fn xor_ref(
+        &self,
+        other: &Self,
+        tolerance: <T::Vertex as IsClose>::Tolerance,
+    ) -> Option<Self> {
+        Clipper::default()
+            .with_operator::<XorOperator<T>>()
+            .with_tolerance(tolerance)
+            .with_subject_ref(self)
+            .with_clip_ref(other)
+            .try_execute_xor()
+            .unwrap_or_default()
+    }
+
+    /// Like [`Self::or`], but using the [`Geometry::default_tolerance`] of the space.
+    pub fn or_default_tol(self, other: Self) -> Option<Self>
+    where
+        <T::Vertex as IsClose>::Tolerance: Default,
+    {
+        self.or(other, T::default_tolerance())
+    }
+
+    /// Like [`Self::not`], but using the [`Geometry::default_tolerance`] of the space.
+    pub fn not_default_tol(self, other: Self) -> Option<Self>
+    where
+        <T::Vertex as IsClose>::Tolerance: Default,
+    {
+        self.not(other, T::default_tolerance())
+    }
+
+    /// Like [`Self::and`], but using the [`Geometry::default_tolerance`] of the space.
+    pub fn and_default_tol(self, other: Self) -> Option<Self>
+    where
+        <T::Vertex as IsClose>::Tolerance: Default,
+    {
+        self.and(other, T::default_tolerance())
+    }
+
+    /// Like [`Self::xor`], but using the [`Geometry::default_tolerance`] of the space.
+    pub fn xor_default_tol(self, other: Self) -> Option<Self>
+    where
+        <T::Vertex as IsClose>::Tolerance: Default,
+    {
+        self.xor(other, T::default_tolerance())
+    }
+
+    /// Returns true if, and only if, the other shape lies entirely inside the filled region of
+    /// this one.
+    ///
+    /// Containment is closed: boundaries are part of the filled region, so a shape touching
+    /// this one from the inside is still contained. Holes subtract from the filled region,
+    /// which means a shape inside a hole, or covering one, is not contained.
+    pub fn contains_shape(
+        &self,
+        other: &Self,
+        tolerance: &<T::Vertex as IsClose>::Tolerance,
+    ) -> bool {
+        // An edge of the other shape properly crossing a boundary of this one is an immediate
+        // witness of escape. Touching intersections at endpoints are tolerated.
+        for (a, b) in self.endpoints() {
+            for (c, d) in other.endpoints() {
+                let edge = T::Edge::new(&a, &b);
+                let other_edge = T::Edge::new(&c, &d);
+
+                if let Some(Either::Left(vertex)) = edge.intersection(&other_edge, tolerance) {
+                    let touching = vertex.is_close(&a, tolerance)
+                        || vertex.is_close(&b, tolerance)
+                        || vertex.is_close(&c, tolerance)
+                        || vertex.is_close(&d, tolerance);
+
+                    if !touching {
+                        return false;
                     }
                 }
             }
+        }
+
+        // With no crossing left, the other shape is contained if all its vertices are, as long
+        // as none of this shape's boundaries lies strictly inside the other: such a boundary
+        // would put some of the other's filled region on the wrong side of it.
+        other.edges().all(|(_, edge)| {
+            self.contains(edge.start(), tolerance) || self.is_boundary(edge.start(), tolerance)
+        }) && !self.edges().any(|(_, edge)| {
+            other.contains(edge.start(), tolerance) && !other.is_boundary(edge.start(), tolerance)
+        })
+    }
+
+    /// Returns true if, and only if, this shape and the other have any point in common.
+    ///
+    /// The search stops at the first witness found, be it an edge intersection or a vertex of
+    /// one shape lying inside the other, avoiding the full intersection enumeration the clipper
+    /// performs.
+    pub fn intersects(&self, other: &Self, tolerance: &<T::Vertex as IsClose>::Tolerance) -> bool {
+        self.edges().any(|(_, edge)| {
+            other
+                .edges()
+                .any(|(_, other_edge)| edge.intersection(&other_edge, tolerance).is_some())
+        }) || other
+            .edges()
+            .next()
+            .is_some_and(|(_, edge)| self.contains(edge.start(), tolerance))
+            || self
+                .edges()
+                .next()
+                .is_some_and(|(_, edge)| other.contains(edge.start(), tolerance))
+    }
 
-            fn direction(node: &Node<T>) -> Direction {
-                let Some(intersection) = node.intersection.kind else {
-                    return Direction::Forward;
-                };
+    /// Returns the [`BoundaryClass`] of each of this shape's boundaries relative to the other
+    /// shape, in boundary order.
+    ///
+    /// This is a cheap preview of a boolean operation: it pairs edges in search of an
+    /// intersection witness and classifies intersection-free boundaries by a single containment
+    /// query, without building the intersection graph. Boundaries touching the other shape
+    /// without overlapping it count as crossing, since their edges do intersect.
+    pub fn classify_boundaries(
+        &self,
+        other: &Self,
+        tolerance: &<T::Vertex as IsClose>::Tolerance,
+    ) -> Vec<BoundaryClass> {
+        self.boundaries
+            .iter()
+            .map(|boundary| {
+                let crossing = other
+                    .boundaries
+                    .iter()
+                    .filter(|clip| boundary.might_intersect(clip))
+                    .any(|clip| {
+                        boundary.edges().any(|edge| {
+                            clip.edges()
+                                .any(|other_edge| edge.intersection(&other_edge, tolerance).is_some())
+                        })
+                    });
 
-                match intersection {
-                    IntersectionKind::Entry => Direction::Forward,
-                    IntersectionKind::Exit => Direction::Backward,
+                if crossing {
+                    return BoundaryClass::Crossing;
                 }
-            }
-        }
 
+                let inside = boundary
+                    .edges()
+                    .next()
+                    .is_some_and(|edge| other.contains(edge.start(), tolerance));
+
+                if inside {
+                    BoundaryClass::Inside
+                } else {
+                    BoundaryClass::Outside
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the endpoint pairs of every edge in this shape.
+    fn endpoints(&self) -> Vec<(T::Vertex, T::Vertex)> {
+        self.boundaries
+            .iter()
+            .flat_map(|boundary| {
+                let vertices = boundary
+                    .edges()
+                    .map(|edge| *edge.start())
+                    .collect::<Vec<_>>();
+
+                let len = vertices.len();
+                (0..len)
+                    .map(|position| (vertices[position], vertices[(position + 1) % len]))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Returns the points where the boundaries of this shape and the other touch without
+    /// crossing each other.
+    pub fn touches(
+        &self,
+        other: &Self,
+        tolerance: &<T::Vertex as IsClose>::Tolerance,
+    ) -> Vec<Touch<T::Vertex>>
+    where
+        <T::Vertex as IsClose>::Tolerance: Clone,
+    {
+        let options = ClipOptions::default();
+        GraphBuilder::new(tolerance, &options)
+            .with_subject(self)
+            .with_clip(other)
+            .build()
+            .map(|graph| graph.touches)
+            .unwrap_or_default()
+    }
+
+    /// Returns the intersection of this shape and the other, borrowing both operands.
+    pub fn and_ref(
+        &self,
+        other: &Self,
+        tolerance: <T::Vertex as IsClose>::Tolerance,
+    ) -> Option<Self> {
         Clipper::default()
             .with_operator::<AndOperator<T>>()
             .with_tolerance(tolerance)
-            .with_subject(self)
-            .with_clip(other)
-            .execute()
+            .with_subject_ref(self)
+            .with_clip_ref(other)
+            .try_execute()
+            .unwrap_or_default()
     }
 }
 
@@ -192,20 +824,73 @@ where
     T::Vertex: Vertex,
 {
     /// Returns the amount of times this shape winds around the given [`Vertex`].
-    fn winding(&self, vertex: &T::Vertex, tolerance: &<T::Vertex as IsClose>::Tolerance) -> isize {
+    pub fn winding(
+        &self,
+        vertex: &T::Vertex,
+        tolerance: &<T::Vertex as IsClose>::Tolerance,
+    ) -> isize {
         self.boundaries
             .iter()
             .map(|boundary| boundary.winding(vertex, tolerance))
             .sum()
     }
 
+    /// Like [`Self::winding`], but interpreting boundary orientations under the given
+    /// [`AreaConvention`].
+    ///
+    /// Under [`AreaConvention::Absolute`], each boundary contributes the magnitude of its
+    /// winding, so points enclosed by a clockwise ring still count as wound around.
+    pub fn winding_with(
+        &self,
+        vertex: &T::Vertex,
+        tolerance: &<T::Vertex as IsClose>::Tolerance,
+        convention: AreaConvention,
+    ) -> isize {
+        match convention {
+            AreaConvention::Signed => self.winding(vertex, tolerance),
+            AreaConvention::Absolute => self
+                .boundaries
+                .iter()
+                .map(|boundary| boundary.winding(vertex, tolerance).abs())
+                .sum(),
+        }
+    }
+
+    /// Returns the total area of this shape under the given [`AreaConvention`].
+    ///
+    /// Under [`AreaConvention::Signed`], clockwise rings subtract from the total, yielding the
+    /// area of the filled region. Under [`AreaConvention::Absolute`], every ring adds to it.
+    pub fn area_with(&self, convention: AreaConvention) -> <T::Vertex as Vertex>::Scalar
+    where
+        <T::Vertex as Vertex>::Scalar: Signed,
+    {
+        self.boundaries.iter().fold(
+            <T::Vertex as Vertex>::Scalar::zero(),
+            |sum, boundary| match convention {
+                AreaConvention::Signed if boundary.is_clockwise() => sum - boundary.area(),
+                _ => sum + boundary.area(),
+            },
+        )
+    }
+
     /// Returns true if, and only if, the given [`Vertex`] lies inside this shape.
     pub(crate) fn contains(
         &self,
         vertex: &T::Vertex,
         tolerance: &<T::Vertex as IsClose>::Tolerance,
     ) -> bool {
-        self.winding(vertex, tolerance) != 0
+        self.contains_with(vertex, tolerance, FillRule::NonZero)
+    }
+
+    /// Returns true if, and only if, the given [`Vertex`] lies inside this shape under the given
+    /// [`FillRule`].
+    pub(crate) fn contains_with(
+        &self,
+        vertex: &T::Vertex,
+        tolerance: &<T::Vertex as IsClose>::Tolerance,
+        fill_rule: FillRule,
+    ) -> bool {
+        fill_rule.is_filled(self.winding(vertex, tolerance))
     }
 }
 
@@ -226,6 +911,105 @@ where
         }
     }
 
+    /// Returns this shape with each vertex replaced by the output of the given closure.
+    ///
+    /// This is the entry point for reprojecting a shape between coordinate reference systems
+    /// without leaving the crate's types.
+    pub fn transform_coords(mut self, mut f: impl FnMut(T::Vertex) -> T::Vertex) -> Self {
+        self.boundaries = self
+            .boundaries
+            .into_iter()
+            .map(|boundary| boundary.map(&mut f))
+            .collect();
+
+        self
+    }
+
+    /// Returns this shape with every run of consecutive coincident vertices collapsed into a
+    /// single one, dropping any boundary left with too few vertices to enclose an area.
+    ///
+    /// Repeated consecutive vertices describe zero-length edges whose classification misbehaves
+    /// during clipping, so inputs of dubious provenance are better deduped beforehand.
+    pub fn deduped(mut self, tolerance: &<T::Vertex as IsClose>::Tolerance) -> Self {
+        self.boundaries = self
+            .boundaries
+            .into_iter()
+            .map(|boundary| boundary.deduped(tolerance))
+            .filter(|boundary| boundary.total_vertices() > 2)
+            .collect();
+
+        self
+    }
+
+    /// Returns this shape with the orientation of every boundary reversed.
+    pub fn reversed(mut self) -> Self {
+        self.boundaries = self
+            .boundaries
+            .into_iter()
+            .map(Geometry::reversed)
+            .collect();
+
+        self
+    }
+
+    /// Returns this shape with the orientation of the boundary at the given position reversed.
+    ///
+    /// The shape is left untouched if there is no boundary at that position.
+    pub fn reverse_boundary(mut self, position: usize) -> Self {
+        self.boundaries = self
+            .boundaries
+            .into_iter()
+            .enumerate()
+            .map(|(index, boundary)| {
+                if index == position {
+                    boundary.reversed()
+                } else {
+                    boundary
+                }
+            })
+            .collect();
+
+        self
+    }
+
+    /// Returns the [`Orientation`] of the boundary at the given position, if any.
+    pub fn orientation(&self, position: usize) -> Option<Orientation> {
+        self.boundaries.get(position).map(|boundary| {
+            if boundary.is_clockwise() {
+                Orientation::Clockwise
+            } else {
+                Orientation::Counterclockwise
+            }
+        })
+    }
+
+    /// Returns an upper-bound [`GraphSizeEstimate`] of clipping this shape against the other.
+    ///
+    /// The boundaries are paired through [`Geometry::might_intersect`], the same prefilter the
+    /// clipper applies, and every surviving edge pair is assumed to intersect twice. Comparing
+    /// the estimate against [`ClipOptions::max_nodes`] and
+    /// [`ClipOptions::max_intersections`](crate::ClipOptions::max_intersections) before clipping
+    /// lets a service bound the cost of a job without running it.
+    pub fn estimated_graph_size(&self, other: &Self) -> GraphSizeEstimate {
+        let edge_pairs = self
+            .boundaries
+            .iter()
+            .flat_map(|subject| other.boundaries.iter().map(move |clip| (subject, clip)))
+            .filter(|(subject, clip)| subject.might_intersect(clip))
+            .map(|(subject, clip)| subject.total_vertices() * clip.total_vertices())
+            .sum::<usize>();
+
+        // Each pair can register two intersection points, each inserting a node in both the
+        // subject and the clip boundary.
+        let intersections = edge_pairs * 2;
+
+        GraphSizeEstimate {
+            edge_pairs,
+            intersections,
+            nodes: self.total_vertices() + other.total_vertices() + intersections * 2,
+        }
+    }
+
     /// Returns true if, and only if, the given [`Vertex`] lies on the boundaries of this shape.
     pub(crate) fn is_boundary(
         &self,
@@ -238,6 +1022,32 @@ where
             .any(|segment| segment.contains(vertex, tolerance))
     }
 
+    /// Returns the [`Location`] of the given [`Vertex`] relative to this shape.
+    ///
+    /// Unlike the winding number, the location distinguishes boundary hits from interior ones.
+    pub fn locate(
+        &self,
+        vertex: &T::Vertex,
+        tolerance: &<T::Vertex as IsClose>::Tolerance,
+    ) -> Location {
+        for (boundary_index, boundary) in self.boundaries.iter().enumerate() {
+            for (edge_index, segment) in boundary.edges().enumerate() {
+                if segment.contains(vertex, tolerance) {
+                    return Location::OnBoundary {
+                        boundary: boundary_index,
+                        edge: edge_index,
+                    };
+                }
+            }
+        }
+
+        if self.contains(vertex, tolerance) {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+
     /// Returns the amount of vertices in this shape.
     pub(crate) fn total_vertices(&self) -> usize {
         self.boundaries
@@ -246,7 +1056,12 @@ where
             .sum()
     }
 
-    pub(crate) fn edges(&self) -> impl Iterator<Item = T::Edge<'_>> {
-        self.boundaries.iter().flat_map(|boundary| boundary.edges())
+    /// Returns an ordered iterator over all the edges of this shape, each paired with the index
+    /// of the boundary it belongs to.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, T::Edge<'_>)> {
+        self.boundaries
+            .iter()
+            .enumerate()
+            .flat_map(|(index, boundary)| boundary.edges().map(move |edge| (index, edge)))
     }
 }
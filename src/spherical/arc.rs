@@ -1,7 +1,26 @@
 use geocart::Cartesian;
 use num_traits::{Euclid, Float, FloatConst, Signed};
 
-use crate::{either::Either, spherical::Point, Edge, IsClose, Tolerance, Vertex as _};
+use crate::{
+    either::Either,
+    spherical::{Point, Polygon},
+    Edge, IsClose, Tolerance, Vertex as _,
+};
+
+/// Returns true if, and only if, the three given points lie on the same great circle within
+/// the given tolerance.
+///
+/// This matches the coplanarity test arcs perform when detecting co-great-circular overlaps,
+/// so decisions taken by user code at boundaries agree with those taken during clipping.
+pub fn coplanar<T>(a: &Point<T>, b: &Point<T>, c: &Point<T>, tolerance: &Tolerance<T>) -> bool
+where
+    T: Signed + Float + FloatConst + Euclid,
+{
+    Arc { from: a, to: b }
+        .normal()
+        .dot(&Cartesian::from(*c))
+        .is_close(&T::zero(), tolerance)
+}
 
 /// The undirected arc between two endpoints.
 #[derive(Debug)]
@@ -23,7 +42,7 @@ where
     }
 
     fn midpoint(&self) -> Self::Vertex {
-        if self.is_antipodal() {
+        if self.is_antipodal(&Polygon::<T>::lax_tolerance()) {
             return Point {
                 inclination: (T::FRAC_PI_2() + self.from.inclination.into_inner()).into(),
                 azimuth: (T::FRAC_PI_2() + self.from.azimuth.into_inner()).into(),
@@ -49,7 +68,7 @@ where
         other: &Self,
         tolerance: &Tolerance<T>,
     ) -> Option<Either<Self::Vertex, [Self::Vertex; 2]>> {
-        if self.is_antipodal() {
+        if self.is_antipodal(tolerance) {
             let point = self.midpoint();
             let first_half = Arc::new(self.from, &point);
             let second_half = Arc::new(&point, self.to);
@@ -110,6 +129,10 @@ where
         None
     }
 
+    fn is_ill_defined(&self, tolerance: &Tolerance<T>) -> bool {
+        self.is_antipodal(tolerance)
+    }
+
     fn start(&self) -> &Self::Vertex {
         self.from
     }
@@ -183,11 +206,16 @@ where
         self.from.distance(self.to)
     }
 
-    /// Returns true if, and only if, the endpoints in the arc are antipodals.
-    fn is_antipodal(&self) -> bool {
+    /// Returns true if, and only if, the endpoints in the arc are antipodals within the given
+    /// tolerance.
+    ///
+    /// Nearly-antipodal endpoints leave the cross product of their positions with barely any
+    /// magnitude, making the general intersection path as unstable as the exactly antipodal
+    /// case; both deserve the same treatment.
+    fn is_antipodal(&self, tolerance: &Tolerance<T>) -> bool {
         let from = Cartesian::from(*self.from);
         let to = Cartesian::from(*self.to);
-        from.dot(&to) == -T::one()
+        from.dot(&to).is_close(&-T::one(), tolerance)
     }
 }
 
@@ -201,6 +229,57 @@ mod tests {
         Edge, Tolerance,
     };
 
+    use super::coplanar;
+
+    #[test]
+    fn coplanarity_of_points() {
+        struct Test {
+            name: &'static str,
+            points: [Point<f64>; 3],
+            want: bool,
+        }
+
+        vec![
+            Test {
+                name: "points on the same meridian",
+                points: [
+                    [FRAC_PI_8, 0.].into(),
+                    [FRAC_PI_4, 0.].into(),
+                    [FRAC_PI_2, 0.].into(),
+                ],
+                want: true,
+            },
+            Test {
+                name: "points on the equator",
+                points: [
+                    [FRAC_PI_2, 0.].into(),
+                    [FRAC_PI_2, FRAC_PI_4].into(),
+                    [FRAC_PI_2, PI].into(),
+                ],
+                want: true,
+            },
+            Test {
+                name: "point off the great circle",
+                points: [
+                    [FRAC_PI_2, 0.].into(),
+                    [FRAC_PI_2, FRAC_PI_4].into(),
+                    [FRAC_PI_4, PI].into(),
+                ],
+                want: false,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let tolerance = Tolerance {
+                relative: 1e-09.into(),
+                absolute: 1e-09.into(),
+            };
+
+            let [a, b, c] = test.points;
+            assert_eq!(coplanar(&a, &b, &c, &tolerance), test.want, "{}", test.name);
+        });
+    }
+
     #[test]
     fn arc_intersection() {
         struct Test<'a> {
@@ -0,0 +1,219 @@
+//! Latitude band clipping for spherical shapes.
+
+use geocart::Cartesian;
+use num_traits::{Euclid, Float, FloatConst, Signed};
+
+use crate::{
+    spherical::{Point, Polygon},
+    Geometry, IsClose, Shape, Tolerance, Vertex as _,
+};
+
+/// The amount of great-circle arcs approximating a full turn along a parallel.
+const BAND_RESOLUTION: usize = 64;
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float + FloatConst + Euclid + IsClose<Tolerance = Tolerance<T>>,
+{
+    /// Returns this shape clipped to the latitude band between the two given inclinations, or
+    /// none if nothing remains of it.
+    ///
+    /// Each boundary is clipped directly against both parallels: cut edges are walked to their
+    /// analytic crossing, and the boundary follows the parallel between consecutive crossings,
+    /// approximated at the same resolution as a sector rim. This stays both faster and sharper
+    /// than a boolean operation against a band assembled from many arcs. Boundaries lying
+    /// entirely outside the band are dropped, even when their filled region reaches into it.
+    pub fn clip_band(&self, min: T, max: T, tolerance: &Tolerance<T>) -> Option<Self> {
+        let (min, max) = if min <= max { (min, max) } else { (max, min) };
+
+        let boundaries = self
+            .boundaries
+            .iter()
+            .filter_map(|boundary| clipped(boundary, max, true))
+            .filter_map(|boundary| clipped(&boundary, min, false))
+            .map(|boundary| boundary.deduped(tolerance))
+            .filter(|boundary| boundary.vertices.len() >= 3)
+            .collect::<Vec<_>>();
+
+        (!boundaries.is_empty()).then_some(Self { boundaries })
+    }
+}
+
+/// Returns the given ring clipped against the parallel at the given inclination, keeping the
+/// side towards the north pole when `northwards`, or none if nothing remains of it.
+fn clipped<T>(ring: &Polygon<T>, bound: T, northwards: bool) -> Option<Polygon<T>>
+where
+    T: Signed + Float + FloatConst + Euclid,
+{
+    let inside = |point: &Point<T>| {
+        if northwards {
+            point.inclination.into_inner() <= bound
+        } else {
+            point.inclination.into_inner() >= bound
+        }
+    };
+
+    let vertices = &ring.vertices;
+    let len = vertices.len();
+    let start = vertices.iter().position(inside)?;
+
+    let wrap = |delta: T| (delta + T::PI()).rem_euclid(&T::TAU()) - T::PI();
+
+    let mut output = Vec::with_capacity(len);
+    // The crossing at which the ring last left the band, together with the azimuth of the
+    // latest point walked outside and the signed azimuth travelled since the exit.
+    let mut outside: Option<(Point<T>, T, T)> = None;
+
+    for step in 0..len {
+        let from = &vertices[(start + step) % len];
+        let to = &vertices[(start + step + 1) % len];
+
+        if inside(from) {
+            output.push(*from);
+        } else if let Some((_, last, travel)) = outside.as_mut() {
+            let azimuth = from.azimuth.into_inner();
+            *travel = *travel + wrap(azimuth - *last);
+            *last = azimuth;
+        }
+
+        if inside(from) == inside(to) {
+            continue;
+        }
+
+        let crossing = crossing(from, to, bound);
+        if inside(from) {
+            outside = Some((crossing, crossing.azimuth.into_inner(), T::zero()));
+            continue;
+        }
+
+        let Some((exit, last, travel)) = outside.take() else {
+            output.push(crossing);
+            continue;
+        };
+
+        // Follow the parallel from the exit to this entry by the azimuth travelled outside,
+        // so the replacement edge hugs the small circle instead of bulging poleward.
+        let azimuth = crossing.azimuth.into_inner();
+        let travel = travel + wrap(azimuth - last);
+
+        output.push(exit);
+        let pieces = (travel.abs() / T::TAU() * T::from(BAND_RESOLUTION).unwrap_or_else(T::one))
+            .ceil()
+            .to_usize()
+            .unwrap_or_default();
+
+        for piece in 1..pieces {
+            let fraction = T::from(piece).unwrap_or_else(T::zero) / T::from(pieces).unwrap_or_else(T::one);
+            output.push([bound, exit.azimuth.into_inner() + travel * fraction].into());
+        }
+
+        output.push(crossing);
+    }
+
+    (output.len() >= 3).then_some(Polygon {
+        vertices: output,
+        exterior: ring.exterior,
+    })
+}
+
+/// Returns the point at the given inclination along the great-circle arc between the given
+/// endpoints, which are assumed to straddle that parallel.
+fn crossing<T>(from: &Point<T>, to: &Point<T>, inclination: T) -> Point<T>
+where
+    T: Signed + Float + FloatConst + Euclid,
+{
+    let from_cartesian = Cartesian::from(*from);
+    let to_cartesian = Cartesian::from(*to);
+
+    let omega = from.distance(to);
+    if omega.is_zero() || omega.sin().is_zero() {
+        return *from;
+    }
+
+    // Along the arc, z(x) = A·cos(x) + B·sin(x) with x the angle walked from the first
+    // endpoint; the crossing solves z(x) = cos(inclination).
+    let a = from_cartesian.z * omega.sin();
+    let b = to_cartesian.z - from_cartesian.z * omega.cos();
+    let magnitude = (a * a + b * b).sqrt();
+    if magnitude.is_zero() {
+        return *from;
+    }
+
+    let target = (inclination.cos() * omega.sin() / magnitude)
+        .max(-T::one())
+        .min(T::one());
+    let phase = b.atan2(a);
+    let delta = target.acos();
+
+    let x = [phase - delta, phase + delta]
+        .into_iter()
+        .find(|x| (T::zero()..=omega).contains(x))
+        .unwrap_or_else(|| (phase + delta).max(T::zero()).min(omega));
+
+    let point = (from_cartesian * ((omega - x).sin() / omega.sin()))
+        + (to_cartesian * (x.sin() / omega.sin()));
+
+    point.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use crate::{
+        spherical::{Point, Polygon},
+        Shape, Tolerance,
+    };
+
+    #[test]
+    fn band_clipping_must_cut_at_the_parallels() {
+        let subject: Shape<Polygon<f64>> = Shape::new(Polygon::new(
+            vec![[0., 0.], [FRAC_PI_2, 0.], [FRAC_PI_2, FRAC_PI_2]],
+            [PI, 0.],
+        ));
+
+        let tolerance = Tolerance {
+            relative: 1e-09.into(),
+            absolute: 0.0.into(),
+        };
+
+        let got = subject
+            .clip_band(FRAC_PI_4, FRAC_PI_2, &tolerance)
+            .expect("the band must retain the equatorial part of the subject");
+
+        for boundary in &got.boundaries {
+            for vertex in &boundary.vertices {
+                let inclination = vertex.inclination.into_inner();
+                assert!(
+                    (FRAC_PI_4 - 1e-9..=FRAC_PI_2 + 1e-9).contains(&inclination),
+                    "every output vertex must lie within the band, got {inclination}"
+                );
+            }
+        }
+
+        let inside: Point<f64> = [FRAC_PI_2 - 0.1, 0.2].into();
+        let polar: Point<f64> = [0.1, 0.1].into();
+        let beyond: Point<f64> = [FRAC_PI_2 - 0.1, PI].into();
+
+        assert_ne!(
+            got.winding(&inside, &tolerance),
+            0,
+            "a point of the subject within the band must remain inside"
+        );
+        assert_eq!(
+            got.winding(&polar, &tolerance),
+            0,
+            "the polar part of the subject must be cut away"
+        );
+        assert_eq!(
+            got.winding(&beyond, &tolerance),
+            0,
+            "a point outside the subject must remain outside"
+        );
+
+        assert!(
+            subject.clip_band(3. * FRAC_PI_4, PI, &tolerance).is_none(),
+            "a band disjoint with the subject must clip it away entirely"
+        );
+    }
+}
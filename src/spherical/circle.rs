@@ -0,0 +1,116 @@
+//! Great-circle clipping for spherical shapes.
+
+use num_traits::{Euclid, Float, FloatConst, Signed};
+
+use crate::{
+    spherical::{destination, Point, Polygon},
+    Geometry, RightHanded, Shape, Tolerance,
+};
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float + FloatConst + Euclid + 'static,
+{
+    /// Returns the hemisphere of all the points within a quarter turn of the given pole.
+    ///
+    /// The boundary of the hemisphere is the full great circle whose pole is the given point,
+    /// described by four quarter arcs. Like [`Self::sector`], the construction is degenerate
+    /// when the pole lies at a pole of the sphere itself.
+    pub fn hemisphere(pole: Point<T>) -> Self {
+        let quarter = T::FRAC_PI_2();
+
+        let mut vertices = Vec::with_capacity(4);
+        let mut bearing = T::zero();
+        for _ in 0..4 {
+            vertices.push(destination(&pole, bearing, quarter));
+            bearing = bearing + quarter;
+        }
+
+        // Every point of the hemisphere is within a quarter turn of its pole, leaving the
+        // antipode of the latter always outside.
+        let exterior = Point::from([
+            T::PI() - pole.inclination.into_inner(),
+            pole.azimuth.into_inner() + T::PI(),
+        ]);
+
+        let mut hemisphere = Polygon { vertices, exterior };
+        if hemisphere.is_clockwise() {
+            hemisphere = hemisphere.reversed();
+        }
+
+        Self {
+            boundaries: vec![hemisphere],
+        }
+    }
+
+    /// Splits this shape along the full great circle whose pole is the given point.
+    ///
+    /// The first piece lies on the side of the circle containing the pole, the second on the
+    /// side containing its antipode, making day/night terminator style computations a single
+    /// call. A piece is none when the shape does not reach its side.
+    pub fn split_by_great_circle(
+        &self,
+        pole: Point<T>,
+        tolerance: Tolerance<T>,
+    ) -> (Option<Self>, Option<Self>) {
+        let antipode = Point::from([
+            T::PI() - pole.inclination.into_inner(),
+            pole.azimuth.into_inner() + T::PI(),
+        ]);
+
+        (
+            self.and_ref(&Self::hemisphere(pole), tolerance),
+            self.and_ref(&Self::hemisphere(antipode), tolerance),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    use crate::{spherical::Polygon, Geometry, Shape, Tolerance};
+
+    #[test]
+    fn great_circle_splits_into_hemispherical_pieces() {
+        let tolerance = Tolerance {
+            relative: 1e-09.into(),
+            absolute: 0.0.into(),
+        };
+
+        let cap = Shape::<Polygon<f64>>::sector([FRAC_PI_2, 0.].into(), 0., 0., FRAC_PI_4)
+            .expect("the cap must exist");
+
+        let area = |shape: &Shape<Polygon<f64>>| {
+            shape
+                .boundaries
+                .iter()
+                .map(Geometry::area)
+                .sum::<f64>()
+        };
+
+        // The circle whose pole is the cap center leaves the whole cap on the near side.
+        let (near, far) = cap.split_by_great_circle([FRAC_PI_2, 0.].into(), tolerance);
+        let near = near.expect("the near piece must exist");
+        assert!(
+            (area(&near) - area(&cap)).abs() < 1e-9,
+            "the near piece must cover the whole cap"
+        );
+        assert!(far.is_none(), "the far piece must be empty");
+
+        // The circle through the poles of the sphere cuts the cap in two equal halves.
+        let (near, far) = cap.split_by_great_circle([FRAC_PI_2, FRAC_PI_2].into(), tolerance);
+        let near = near.expect("the near piece must exist");
+        let far = far.expect("the far piece must exist");
+
+        assert!(
+            (area(&near) - area(&far)).abs() < 1e-6,
+            "the pieces must be symmetric"
+        );
+
+        assert!(
+            (area(&near) + area(&far) - area(&cap)).abs() < 1e-6,
+            "the pieces must cover the whole cap"
+        );
+    }
+}
@@ -1,10 +1,15 @@
 mod arc;
+mod band;
+mod circle;
 mod point;
 mod polygon;
+mod sector;
 
-pub use self::arc::Arc;
+pub use self::arc::{coplanar, Arc};
 pub use self::point::{Azimuth, Inclination, Point};
-pub use self::polygon::{spherical_polygon, Polygon};
+pub use self::polygon::{spherical_polygon, Cap, Polygon};
+
+pub(crate) use self::sector::destination;
 
 #[cfg(test)]
 mod tests {
@@ -890,4 +895,278 @@ mod tests {
             assert_eq!(got, test.want, "{}", test.name);
         });
     }
+
+    #[test]
+    fn symmetric_difference() {
+        struct Test {
+            name: &'static str,
+            subject: Shape<Polygon<f64>>,
+            clip: Shape<Polygon<f64>>,
+            want: Option<Shape<Polygon<f64>>>,
+        }
+
+        vec![
+            Test {
+                name: "same geometry",
+                subject: Shape::new(spherical_polygon!(
+                    [0., 0.],
+                    [FRAC_PI_2, 0.],
+                    [FRAC_PI_2, FRAC_PI_2];
+                    [PI, 0.]
+                )),
+                clip: Shape::new(spherical_polygon!(
+                    [0., 0.],
+                    [FRAC_PI_2, 0.],
+                    [FRAC_PI_2, FRAC_PI_2];
+                    [PI, 0.]
+                )),
+                want: None,
+            },
+            Test {
+                name: "non-overlapping triangles",
+                subject: Shape::new(spherical_polygon!(
+                    [0., 0.],
+                    [FRAC_PI_2, 0.],
+                    [FRAC_PI_2, FRAC_PI_2];
+                    [PI, 0.]
+                )),
+                clip: Shape::new(spherical_polygon!(
+                    [PI, 0.],
+                    [FRAC_PI_2, PI],
+                    [FRAC_PI_2, 3. * FRAC_PI_2];
+                    [0., 0.]
+                )),
+                want: Some(Shape {
+                    boundaries: vec![
+                        spherical_polygon!(
+                            [0., 0.],
+                            [FRAC_PI_2, 0.],
+                            [FRAC_PI_2, FRAC_PI_2];
+                            [PI, 0.]
+                        ),
+                        spherical_polygon!(
+                            [PI, 0.],
+                            [FRAC_PI_2, PI],
+                            [FRAC_PI_2, 3. * FRAC_PI_2];
+                            [0., 0.]
+                        ),
+                    ],
+                }),
+            },
+            Test {
+                name: "subject enclosing clip",
+                subject: Shape::new(spherical_polygon!(
+                    [0., 0.],
+                    [FRAC_PI_2, 0.],
+                    [FRAC_PI_2, FRAC_PI_2];
+                    [PI, 0.]
+                )),
+                clip: Shape::new(spherical_polygon!(
+                    [FRAC_PI_8, FRAC_PI_4],
+                    [FRAC_PI_2 - FRAC_PI_8, FRAC_PI_8],
+                    [FRAC_PI_2 - FRAC_PI_8, FRAC_PI_2 - FRAC_PI_8];
+                    [PI, 0.]
+                )),
+                want: Some(Shape {
+                    boundaries: vec![
+                        spherical_polygon!(
+                            [0., 0.],
+                            [FRAC_PI_2, 0.],
+                            [FRAC_PI_2, FRAC_PI_2];
+                            [PI, PI]
+                        ),
+                        spherical_polygon!(
+                            [FRAC_PI_8, FRAC_PI_4],
+                            [FRAC_PI_2 - FRAC_PI_8, FRAC_PI_2 - FRAC_PI_8],
+                            [FRAC_PI_2 - FRAC_PI_8, FRAC_PI_8];
+                            [PI, PI]
+                        ),
+                    ],
+                }),
+            },
+            Test {
+                name: "subject with hole enclosing clip",
+                subject: Shape {
+                    boundaries: vec![
+                        spherical_polygon!(
+                            [FRAC_PI_2, 0.],
+                            [FRAC_PI_2, FRAC_PI_2],
+                            [FRAC_PI_2, PI],
+                            [FRAC_PI_2, 3. * FRAC_PI_2];
+                            [PI, 0.]
+                        ),
+                        spherical_polygon!(
+                            [FRAC_PI_4, 3. * FRAC_PI_2],
+                            [FRAC_PI_4, PI],
+                            [FRAC_PI_4, FRAC_PI_2],
+                            [FRAC_PI_4, 0.];
+                            [PI, 0.]
+                        ),
+                    ],
+                },
+                clip: Shape::new(spherical_polygon!(
+                    [FRAC_PI_4 + FRAC_PI_8, 0.],
+                    [FRAC_PI_4 + FRAC_PI_8, FRAC_PI_2],
+                    [FRAC_PI_4 + FRAC_PI_8, PI],
+                    [FRAC_PI_4 + FRAC_PI_8, 3. * FRAC_PI_2];
+                    [PI, 0.]
+                )),
+                want: Some(Shape {
+                    boundaries: vec![
+                        spherical_polygon!(
+                            [FRAC_PI_2, 0.],
+                            [FRAC_PI_2, FRAC_PI_2],
+                            [FRAC_PI_2, PI],
+                            [FRAC_PI_2, 3. * FRAC_PI_2];
+                            [PI, 0.]
+                        ),
+                        spherical_polygon!(
+                            [FRAC_PI_4 + FRAC_PI_8, 3. * FRAC_PI_2],
+                            [FRAC_PI_4 + FRAC_PI_8, PI],
+                            [FRAC_PI_4 + FRAC_PI_8, FRAC_PI_2],
+                            [FRAC_PI_4 + FRAC_PI_8, 0.];
+                            [PI, 0.]
+                        ),
+                        spherical_polygon!(
+                            [FRAC_PI_4, 0.],
+                            [FRAC_PI_4, FRAC_PI_2],
+                            [FRAC_PI_4, PI],
+                            [FRAC_PI_4, 3. * FRAC_PI_2];
+                            [PI, 0.]
+                        ),
+                    ],
+                }),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let tolerance = Tolerance {
+                relative: 1e-09.into(),
+                absolute: 0.0.into(),
+            };
+
+            let got = test.subject.xor(test.clip, tolerance);
+            assert_eq!(got, test.want, "{}", test.name);
+        });
+    }
+
+    #[test]
+    fn default_tolerance_suits_the_sphere() {
+        use crate::Geometry;
+
+        let tolerance = Polygon::<f64>::default_tolerance();
+        assert_eq!(
+            tolerance.relative.into_inner(),
+            1e-9,
+            "the relative part must absorb trigonometric rounding noise"
+        );
+        assert_eq!(tolerance.absolute.into_inner(), 0.);
+
+        let subject = || -> Shape<Polygon<f64>> {
+            Shape::new(spherical_polygon!(
+                [0., 0.],
+                [FRAC_PI_2, 0.],
+                [FRAC_PI_2, FRAC_PI_2];
+                [PI, 0.]
+            ))
+        };
+
+        let clip = || {
+            Shape::new(spherical_polygon!(
+                [0., 0.],
+                [FRAC_PI_2, PI],
+                [FRAC_PI_2, 3. * FRAC_PI_2];
+                [PI, 0.]
+            ))
+        };
+
+        let got = subject().or_default_tol(clip());
+        let want = subject().or(clip(), tolerance);
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn exterior_search_is_deterministic() {
+        use crate::Geometry;
+
+        let subject = |rotation: usize| -> Shape<Polygon<f64>> {
+            let mut vertices = vec![[0., 0.], [FRAC_PI_2, 0.], [FRAC_PI_2, FRAC_PI_2]];
+            vertices.rotate_left(rotation);
+            Shape::new(Polygon::new(vertices, [PI, 0.]))
+        };
+
+        let clip = || {
+            Shape::new(spherical_polygon!(
+                [0., 0.],
+                [FRAC_PI_2, PI],
+                [FRAC_PI_2, 3. * FRAC_PI_2];
+                [PI, 0.]
+            ))
+        };
+
+        let want = subject(0)
+            .or(clip(), Polygon::default_tolerance())
+            .expect("the union must exist");
+
+        for rotation in 1..3 {
+            let got = subject(rotation)
+                .or(clip(), Polygon::default_tolerance())
+                .expect("the union must exist");
+
+            assert_eq!(got, want, "rotated inputs must yield the same output");
+        }
+
+        for boundary in &want.boundaries {
+            assert_eq!(
+                boundary.exterior,
+                [PI, 0.].into(),
+                "the caller's exterior point must be reused as the hint"
+            );
+        }
+    }
+
+    #[test]
+    fn antipodal_policy_rejects_ill_defined_edges() {
+        use crate::{AntipodalPolicy, ClipError, ClipOptions, Geometry};
+
+        let subject = |inclination: f64| -> Shape<Polygon<f64>> {
+            Shape::new(spherical_polygon!(
+                [0., 0.],
+                [inclination, 0.],
+                [FRAC_PI_2, FRAC_PI_2];
+                [FRAC_PI_2, 3. * FRAC_PI_2]
+            ))
+        };
+
+        let clip = || -> Shape<Polygon<f64>> {
+            Shape::new(spherical_polygon!(
+                [0., 0.],
+                [FRAC_PI_2, PI],
+                [FRAC_PI_2, 3. * FRAC_PI_2];
+                [PI, 0.]
+            ))
+        };
+
+        let options = || ClipOptions {
+            antipodal_policy: AntipodalPolicy::Error,
+            ..Default::default()
+        };
+
+        vec![
+            ("exactly antipodal edge", PI),
+            ("nearly antipodal edge", PI - 1e-12),
+        ]
+        .into_iter()
+        .for_each(|(name, inclination)| {
+            let got =
+                subject(inclination).or_with(clip(), Polygon::default_tolerance(), options());
+            assert_eq!(got, Err(ClipError::IllDefinedEdge), "{name}");
+        });
+
+        let got = subject(FRAC_PI_2).or_with(clip(), Polygon::default_tolerance(), options());
+        assert!(
+            got.is_ok(),
+            "a subject of well-defined edges must clip under the policy"
+        );
+    }
 }
@@ -160,12 +160,55 @@ where
 
 impl<T> IsClose for Azimuth<T>
 where
-    T: IsClose<Tolerance = Tolerance<T>>,
+    T: Float + FloatConst + IsClose<Tolerance = Tolerance<T>>,
 {
     type Tolerance = Tolerance<T>;
 
+    /// Two azimuths are close if, and only if, the rotations they describe are; both boundaries
+    /// of the azimuth range are consecutive, so values on either side of the seam compare by
+    /// their distance across it.
     fn is_close(&self, other: &Self, tolerance: &Self::Tolerance) -> bool {
         self.0.is_close(&other.0, tolerance)
+            || (self.0 + T::TAU()).is_close(&other.0, tolerance)
+            || self.0.is_close(&(other.0 + T::TAU()), tolerance)
+    }
+}
+
+impl<T> Azimuth<T>
+where
+    T: Float + FloatConst,
+{
+    /// Returns the azimuth equivalent to the given angle expressed in the __\[0, 2π\)__
+    /// convention.
+    ///
+    /// Any angle is accepted: this constructor, like [`From`], stores its equivalent within
+    /// the range.
+    pub fn positive(value: T) -> Self {
+        value.into()
+    }
+
+    /// Returns the azimuth equivalent to the given angle expressed in the signed
+    /// __\(−π, π\]__ convention.
+    ///
+    /// Both conventions describe the same rotation, so this constructor is interchangeable
+    /// with [`Self::positive`]; it exists for callers whose data is signed, such as
+    /// longitudes, to state their convention explicitly.
+    pub fn signed(value: T) -> Self {
+        value.into()
+    }
+
+    /// Returns the inner value normalized to the __\[0, 2π\)__ convention.
+    pub fn into_positive(self) -> T {
+        self.0
+    }
+
+    /// Returns the inner value normalized to the signed __\(−π, π\]__ convention.
+    pub fn into_signed(self) -> T {
+        if self.0 > T::PI() {
+            self.0 - T::TAU()
+        } else {
+            self.0
+        }
     }
 }
 
@@ -252,11 +295,21 @@ where
     fn distance(&self, other: &Self) -> T {
         Geographic::from(*self).distance(&(*other).into())
     }
+
+    fn rounded(self, decimals: u32) -> Self {
+        let factor = T::from(10).unwrap_or_else(T::one).powi(decimals as i32);
+        let round = |angle: T| (angle * factor).round() / factor;
+
+        Self {
+            inclination: round(self.inclination.into_inner()).into(),
+            azimuth: round(self.azimuth.into_inner()).into(),
+        }
+    }
 }
 
 impl<T> IsClose for Point<T>
 where
-    T: IsClose<Tolerance = Tolerance<T>>,
+    T: Float + FloatConst + IsClose<Tolerance = Tolerance<T>>,
 {
     type Tolerance = Tolerance<T>;
 
@@ -264,6 +317,13 @@ where
         self.inclination.is_close(&other.inclination, tolerance)
             && self.azimuth.is_close(&other.azimuth, tolerance)
     }
+
+    fn scaled_tolerance(tolerance: &Self::Tolerance, factor: f64) -> Self::Tolerance
+    where
+        Self::Tolerance: Clone,
+    {
+        T::scaled_tolerance(tolerance, factor)
+    }
 }
 
 #[cfg(test)]
@@ -361,4 +421,72 @@ mod tests {
             assert_eq!(azimuth, test.output, "{}", test.name);
         });
     }
+
+    #[test]
+    fn azimuth_wrapping_conventions() {
+        struct Test {
+            name: &'static str,
+            azimuth: Azimuth<f64>,
+            positive: f64,
+            signed: f64,
+        }
+
+        vec![
+            Test {
+                name: "angle in the first half keeps its sign",
+                azimuth: Azimuth::positive(FRAC_PI_2),
+                positive: FRAC_PI_2,
+                signed: FRAC_PI_2,
+            },
+            Test {
+                name: "angle in the second half becomes negative",
+                azimuth: Azimuth::positive(3. * FRAC_PI_2),
+                positive: 3. * FRAC_PI_2,
+                signed: -FRAC_PI_2,
+            },
+            Test {
+                name: "signed input wraps into the positive range",
+                azimuth: Azimuth::signed(-FRAC_PI_2),
+                positive: 3. * FRAC_PI_2,
+                signed: -FRAC_PI_2,
+            },
+            Test {
+                name: "half a turn belongs to the signed range",
+                azimuth: Azimuth::positive(PI),
+                positive: PI,
+                signed: PI,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.azimuth.into_positive(),
+                test.positive,
+                "{}",
+                test.name
+            );
+            assert_eq!(test.azimuth.into_signed(), test.signed, "{}", test.name);
+        });
+    }
+
+    #[test]
+    fn azimuth_closeness_wraps_the_seam() {
+        let tolerance = Tolerance {
+            relative: 1e-9.into(),
+            ..Default::default()
+        };
+
+        let west = Azimuth::from(TAU - 1e-12);
+        let east = Azimuth::from(0.);
+        assert!(
+            west.is_close(&east, &tolerance) && east.is_close(&west, &tolerance),
+            "azimuths on either side of the seam must be close"
+        );
+
+        let apart = Azimuth::from(PI);
+        assert!(
+            !apart.is_close(&east, &tolerance),
+            "azimuths half a turn apart must not be close"
+        );
+    }
 }
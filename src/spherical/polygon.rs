@@ -4,10 +4,23 @@ use geocart::{
 };
 use num_traits::{Euclid, Float, FloatConst, Signed};
 
-use crate::{clipper::Operands, spherical::Arc, Edge, Geometry, RightHanded, Tolerance};
+use std::cmp::Ordering;
+
+use crate::{
+    clipper::Operands, spherical::Arc, Edge, Geometry, IsClose, RightHanded, Tolerance, Vertex,
+};
 
 use super::Point;
 
+/// The region of the sphere within an angular radius of a center point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cap<T> {
+    /// The center of the cap.
+    pub center: Point<T>,
+    /// The angular radius of the cap, in radians.
+    pub radius: T,
+}
+
 /// A spherical polygon.
 #[derive(Debug, Clone)]
 pub struct Polygon<T> {
@@ -40,6 +53,28 @@ where
     }
 }
 
+impl<T> IsClose for Polygon<T>
+where
+    T: Float + FloatConst + IsClose<Tolerance = Tolerance<T>>,
+{
+    type Tolerance = Tolerance<T>;
+
+    /// Two polygons are close if, and only if, their vertices describe the same boundary within
+    /// the tolerance, regardless of which vertex each one starts at.
+    fn is_close(&self, other: &Self, tolerance: &Self::Tolerance) -> bool {
+        let len = self.vertices.len();
+        if len != other.vertices.len() {
+            return false;
+        }
+
+        (0..len).any(|padding| {
+            self.vertices.iter().enumerate().all(|(position, vertex)| {
+                vertex.is_close(&other.vertices[(position + padding) % len], tolerance)
+            })
+        })
+    }
+}
+
 impl<T> RightHanded for Polygon<T>
 where
     T: Signed + Float + FloatConst + Euclid,
@@ -97,7 +132,39 @@ where
             None
         };
 
-        let mut exterior = None;
+        let is_exterior = |candidate: &Point<T>| {
+            !operands.subject.contains(candidate, tolerance)
+                && !operands.clip.contains(candidate, tolerance)
+        };
+
+        // The exterior points the caller supplied on the operands double as hints: reusing the
+        // first one still exterior to both shapes keeps the output deterministic regardless of
+        // how the operands are traversed.
+        let hinted = operands
+            .subject
+            .boundaries
+            .iter()
+            .chain(operands.clip.boundaries.iter())
+            .map(|boundary| boundary.exterior)
+            .find(is_exterior);
+
+        // Failing the hints, a fixed candidate set covers most operands without depending on
+        // their edge iteration order: both poles and four equidistant equatorial points.
+        let fixed = || {
+            [
+                [T::zero(), T::zero()],
+                [T::PI(), T::zero()],
+                [T::FRAC_PI_2(), T::zero()],
+                [T::FRAC_PI_2(), T::FRAC_PI_2()],
+                [T::FRAC_PI_2(), T::PI()],
+                [T::FRAC_PI_2(), T::PI() + T::FRAC_PI_2()],
+            ]
+            .into_iter()
+            .map(Point::from)
+            .find(|candidate| is_exterior(candidate))
+        };
+
+        let mut exterior = hinted.or_else(fixed);
         let mut theta = T::PI() * tolerance.relative.into_inner();
 
         while exterior.is_none() && theta < T::FRAC_PI_8() {
@@ -105,7 +172,7 @@ where
                 .subject
                 .edges()
                 .chain(operands.clip.edges())
-                .find_map(|arc| {
+                .find_map(|(_, arc)| {
                     closest_exterior_point(&arc, theta)
                         .or_else(|| closest_exterior_point(&arc, -theta))
                 });
@@ -126,11 +193,74 @@ where
             .map(|(from, to)| Arc { from, to })
     }
 
+    fn area(&self) -> T {
+        // The area of a spherical polygon is the solid angle it subtends, computed here as the
+        // sum of the excesses of the triangles fanned out from the first vertex. The excess of
+        // each triangle comes from the Van Oosterom-Strackee formula.
+        let Some(&origin) = self.vertices.first() else {
+            return T::zero();
+        };
+
+        let origin = Cartesian::from(origin);
+        self.vertices[1..]
+            .windows(2)
+            .fold(T::zero(), |total, pair| {
+                let a = Cartesian::from(pair[0]);
+                let b = Cartesian::from(pair[1]);
+
+                let numerator = origin.dot(&a.cross(&b));
+                let denominator = T::one() + origin.dot(&a) + a.dot(&b) + b.dot(&origin);
+
+                total + (T::one() + T::one()) * numerator.atan2(denominator)
+            })
+            .abs()
+    }
+
     fn reversed(mut self) -> Self {
         self.vertices.reverse();
         self
     }
 
+    fn map(mut self, mut f: impl FnMut(Point<T>) -> Point<T>) -> Self {
+        self.vertices = self.vertices.into_iter().map(&mut f).collect();
+        self.exterior = f(self.exterior);
+        self
+    }
+
+    fn deduped(mut self, tolerance: &Tolerance<T>) -> Self {
+        self.vertices
+            .dedup_by(|vertex, kept| vertex.is_close(kept, tolerance));
+
+        let closed = self
+            .vertices
+            .first()
+            .zip(self.vertices.last())
+            .is_some_and(|(first, last)| first.is_close(last, tolerance));
+
+        if self.vertices.len() > 1 && closed {
+            self.vertices.pop();
+        }
+
+        self
+    }
+
+    fn default_tolerance() -> Tolerance<T> {
+        Self::lax_tolerance()
+    }
+
+    fn might_intersect(&self, other: &Self) -> bool {
+        let cap = self.bounding_cap();
+        let other = other.bounding_cap();
+
+        // A degenerate cap yields an incomparable distance, which must not discard the pair.
+        !matches!(
+            cap.center
+                .distance(&other.center)
+                .partial_cmp(&(cap.radius + other.radius)),
+            Some(Ordering::Greater)
+        )
+    }
+
     fn winding(&self, point: &Point<T>, tolerance: &Tolerance<T>) -> isize {
         // Returns true if, and only if, the point is on the left of the great circle containing
         // the given arc.
@@ -158,6 +288,22 @@ impl<T> IntoIterator for Polygon<T> {
     }
 }
 
+impl<T> Polygon<T>
+where
+    T: Signed + Float,
+{
+    /// Returns the tolerance this space assumes when none is explicitly chosen.
+    ///
+    /// Spherical predicates go through trigonometry, leaving rounding noise far above the
+    /// exact comparison the cartesian plane defaults to.
+    pub(crate) fn lax_tolerance() -> Tolerance<T> {
+        Tolerance {
+            relative: T::from(1e-9).unwrap_or_else(T::epsilon).into(),
+            absolute: T::zero().into(),
+        }
+    }
+}
+
 impl<T> Polygon<T> {
     /// Returns a polygon with the given vertices and exterior.
     pub fn new<U>(vertices: Vec<U>, exterior: U) -> Self
@@ -174,9 +320,89 @@ impl<T> Polygon<T> {
     ///
     /// By definition, a polygon is a closed shape, hence the latest point of the iterator equals
     /// the very first.
-    fn vertices(&self) -> impl Iterator<Item = &Point<T>> {
+    pub fn vertices(&self) -> impl Iterator<Item = &Point<T>> {
         self.vertices.iter().chain(self.vertices.first())
     }
+
+    /// Returns the vertex of the polygon at the given position, if any.
+    pub fn vertex(&self, position: usize) -> Option<&Point<T>> {
+        self.vertices.get(position)
+    }
+
+    /// Returns the amount of vertices in the polygon, not counting the implicit closing one.
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Returns true if, and only if, the polygon has no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+}
+
+impl<T> Polygon<T>
+where
+    T: Signed + Float + FloatConst + Euclid,
+{
+    /// Returns the [`Cap`] centered at the normalized centroid of this polygon containing all of
+    /// its vertices.
+    pub fn bounding_cap(&self) -> Cap<T> {
+        let center: Point<T> = self
+            .vertices
+            .iter()
+            .fold(Cartesian::origin(), |sum, &vertex| {
+                sum + Cartesian::from(vertex)
+            })
+            .normal()
+            .into();
+
+        let radius = self
+            .vertices
+            .iter()
+            .map(|vertex| vertex.distance(&center))
+            .fold(T::zero(), T::max);
+
+        Cap { center, radius }
+    }
+
+    /// Returns this polygon oriented counterclockwise and rotated to start at the vertex with
+    /// the smallest inclination, breaking ties by azimuth.
+    ///
+    /// Any two polygons describing the same boundary share the same normalized form, so their
+    /// vertex lists can be compared, hashed or snapshotted directly, without the rotation-aware
+    /// comparison performed by `PartialEq`.
+    pub fn normalized(self) -> Self {
+        let mut polygon = if self.is_clockwise() {
+            self.reversed()
+        } else {
+            self
+        };
+
+        let start = polygon
+            .vertices
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                (a.inclination.into_inner(), a.azimuth.into_inner())
+                    .partial_cmp(&(b.inclination.into_inner(), b.azimuth.into_inner()))
+                    .unwrap_or(Ordering::Equal)
+            })
+            .map(|(position, _)| position)
+            .unwrap_or_default();
+
+        polygon.vertices.rotate_left(start);
+        polygon
+    }
+
+    /// Returns true if, and only if, the given point lies inside this polygon.
+    ///
+    /// This is the [`Geometry::contains`] winding test exposed without importing the trait, so
+    /// geofence checks can use the crate without performing a full clip. Points on the boundary
+    /// count as inside: the crossing the winding test registers at the point itself already
+    /// leaves the winding number non-zero, which makes the fence part of the fenced region.
+    pub fn contains(&self, point: &Point<T>, tolerance: &Tolerance<T>) -> bool {
+        Geometry::contains(self, point, tolerance)
+    }
 }
 
 /// A constructor macro for the spherical [`Polygon`].
@@ -195,7 +421,7 @@ mod tests {
 
     use crate::{
         spherical::{Point, Polygon},
-        Geometry, RightHanded, Tolerance,
+        Geometry, RightHanded, Tolerance, Vertex,
     };
 
     #[test]
@@ -301,6 +527,121 @@ mod tests {
         });
     }
 
+    #[test]
+    fn polygon_point_containment() {
+        struct Test {
+            name: &'static str,
+            point: Point<f64>,
+            want: bool,
+        }
+
+        let polygon: Polygon<f64> = spherical_polygon!(
+            [0., 0.],
+            [FRAC_PI_2, 0.],
+            [FRAC_PI_2, FRAC_PI_2];
+            [FRAC_PI_2, 3. * FRAC_PI_2]
+        );
+
+        vec![
+            Test {
+                name: "point inside the polygon",
+                point: [FRAC_PI_4, FRAC_PI_4].into(),
+                want: true,
+            },
+            Test {
+                name: "point outside the polygon",
+                point: [FRAC_PI_2, PI].into(),
+                want: false,
+            },
+            Test {
+                name: "point on an edge counts as inside",
+                point: [FRAC_PI_4, 0.].into(),
+                want: true,
+            },
+            Test {
+                name: "vertex of the polygon counts as inside",
+                point: [FRAC_PI_2, 0.].into(),
+                want: true,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let tolerance = Tolerance {
+                relative: 1e-09.into(),
+                absolute: 0.0.into(),
+            };
+
+            let got = polygon.contains(&test.point, &tolerance);
+            assert_eq!(got, test.want, "{}", test.name);
+        });
+    }
+
+    #[test]
+    fn polygon_bounding_cap_covers_vertices() {
+        let polygon: Polygon<f64> = spherical_polygon!(
+            [0., 0.],
+            [FRAC_PI_2, 0.],
+            [FRAC_PI_2, FRAC_PI_2];
+            [PI, PI]
+        );
+
+        let cap = polygon.bounding_cap();
+        assert!(polygon
+            .vertices
+            .iter()
+            .all(|vertex| vertex.distance(&cap.center) <= cap.radius + 1e-9));
+    }
+
+    #[test]
+    fn polygon_bounding_cap_prefilter() {
+        struct Test {
+            name: &'static str,
+            left: Polygon<f64>,
+            right: Polygon<f64>,
+            want: bool,
+        }
+
+        vec![
+            Test {
+                name: "triangles at opposite poles",
+                left: spherical_polygon!(
+                    [0., 0.],
+                    [FRAC_PI_8, 0.],
+                    [FRAC_PI_8, FRAC_PI_2];
+                    [PI, 0.]
+                ),
+                right: spherical_polygon!(
+                    [PI, 0.],
+                    [PI - FRAC_PI_8, 0.],
+                    [PI - FRAC_PI_8, FRAC_PI_2];
+                    [0., 0.]
+                ),
+                want: false,
+            },
+            Test {
+                name: "triangle against itself",
+                left: spherical_polygon!(
+                    [0., 0.],
+                    [FRAC_PI_8, 0.],
+                    [FRAC_PI_8, FRAC_PI_2];
+                    [PI, 0.]
+                ),
+                right: spherical_polygon!(
+                    [0., 0.],
+                    [FRAC_PI_8, 0.],
+                    [FRAC_PI_8, FRAC_PI_2];
+                    [PI, 0.]
+                ),
+                want: true,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = test.left.might_intersect(&test.right);
+            assert_eq!(got, test.want, "{}", test.name);
+        });
+    }
+
     #[test]
     fn polygon_clockwise_orientation() {
         struct Test {
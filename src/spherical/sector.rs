@@ -0,0 +1,175 @@
+//! Sector clipping for spherical shapes.
+
+use num_traits::{Euclid, Float, FloatConst, Signed};
+
+use crate::{
+    spherical::{Point, Polygon},
+    Geometry, RightHanded, Shape, Tolerance,
+};
+
+/// The amount of great-circle arcs approximating the rim of a full spherical cap.
+const SECTOR_RESOLUTION: usize = 64;
+
+impl<T> Shape<Polygon<T>>
+where
+    T: Signed + Float + FloatConst + Euclid + 'static,
+{
+    /// Returns the spherical sector centered at the given point, swept from the start bearing to
+    /// the end one, or none if the angular radius is not in the range __(0, π)__.
+    ///
+    /// Bearings are measured from the direction towards the north pole, increasing with the
+    /// azimuth, and are degenerate when the center lies at a pole. The sides of the sector are
+    /// great-circle arcs, while its rim approximates the small circle at the given angular
+    /// radius. A span of zero is interpreted as the full cap.
+    pub fn sector(center: Point<T>, start_bearing: T, end_bearing: T, radius: T) -> Option<Self> {
+        if !radius.is_positive() || radius >= T::PI() {
+            return None;
+        }
+
+        let tau = T::TAU();
+        let mut span = (end_bearing - start_bearing).rem_euclid(&tau);
+        if span.is_zero() {
+            span = tau;
+        }
+
+        let amount = (span / tau * T::from(SECTOR_RESOLUTION)?).ceil().to_usize()?.max(2);
+        let steps = T::from(amount)?;
+        let full_turn = span == tau;
+
+        let mut vertices = Vec::with_capacity(amount + 2);
+        if !full_turn {
+            vertices.push(center);
+        }
+
+        // A full turn must not repeat its closing vertex.
+        let positions = if full_turn { amount } else { amount + 1 };
+        for position in 0..positions {
+            let bearing = start_bearing + span * T::from(position)? / steps;
+            vertices.push(destination(&center, bearing, radius));
+        }
+
+        // Every point of the sector is within the angular radius of its center, leaving the
+        // antipode of the latter always outside.
+        let exterior = Point::from([
+            T::PI() - center.inclination.into_inner(),
+            center.azimuth.into_inner() + T::PI(),
+        ]);
+
+        let mut sector = Polygon { vertices, exterior };
+        if sector.is_clockwise() {
+            sector = sector.reversed();
+        }
+
+        Some(Self {
+            boundaries: vec![sector],
+        })
+    }
+
+    /// Returns the intersection of this shape with the spherical sector described by the given
+    /// center, bearings and angular radius.
+    pub fn clip_sector(
+        &self,
+        center: Point<T>,
+        start_bearing: T,
+        end_bearing: T,
+        radius: T,
+        tolerance: Tolerance<T>,
+    ) -> Option<Self> {
+        let sector = Self::sector(center, start_bearing, end_bearing, radius)?;
+        self.and_ref(&sector, tolerance)
+    }
+}
+
+/// Returns the point at the given angular distance from the origin along the given bearing.
+pub(crate) fn destination<T>(origin: &Point<T>, bearing: T, distance: T) -> Point<T>
+where
+    T: Signed + Float + FloatConst + Euclid,
+{
+    let inclination = origin.inclination.into_inner();
+    let azimuth = origin.azimuth.into_inner();
+
+    let cos_inclination =
+        inclination.cos() * distance.cos() + inclination.sin() * distance.sin() * bearing.cos();
+
+    let delta = (bearing.sin() * distance.sin() * inclination.sin())
+        .atan2(distance.cos() - inclination.cos() * cos_inclination);
+
+    [cos_inclination.acos(), azimuth + delta].into()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, FRAC_PI_8, PI};
+
+    use crate::{
+        spherical::{Point, Polygon},
+        Shape, Tolerance, Vertex,
+    };
+
+    use super::destination;
+
+    #[test]
+    fn destination_preserves_distance_and_bearing() {
+        struct Test {
+            name: &'static str,
+            origin: [f64; 2],
+            bearing: f64,
+            distance: f64,
+            want: [f64; 2],
+        }
+
+        vec![
+            Test {
+                name: "northwards from the equator",
+                origin: [FRAC_PI_2, 0.],
+                bearing: 0.,
+                distance: FRAC_PI_4,
+                want: [FRAC_PI_4, 0.],
+            },
+            Test {
+                name: "eastwards along the equator",
+                origin: [FRAC_PI_2, 0.],
+                bearing: FRAC_PI_2,
+                distance: FRAC_PI_4,
+                want: [FRAC_PI_2, FRAC_PI_4],
+            },
+            Test {
+                name: "southwards across the equator",
+                origin: [FRAC_PI_4, FRAC_PI_2],
+                bearing: PI,
+                distance: FRAC_PI_2,
+                want: [3. * FRAC_PI_4, FRAC_PI_2],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = destination(&test.origin.into(), test.bearing, test.distance);
+            let want = Point::from(test.want);
+
+            assert!(
+                got.distance(&want) < 1e-9,
+                "{}: got {:?}, want {:?}",
+                test.name,
+                got,
+                want
+            );
+        });
+    }
+
+    #[test]
+    fn sector_covers_its_cap() {
+        let cap = Shape::<Polygon<f64>>::sector([FRAC_PI_2, FRAC_PI_2].into(), 0., 0., FRAC_PI_4)
+            .expect("the full cap must exist");
+
+        let tolerance = Tolerance {
+            relative: 1e-09.into(),
+            absolute: 0.0.into(),
+        };
+
+        let inside: Point<f64> = [FRAC_PI_2, FRAC_PI_2 + FRAC_PI_8].into();
+        let outside: Point<f64> = [FRAC_PI_2, FRAC_PI_2 + PI].into();
+
+        assert_ne!(cap.winding(&inside, &tolerance), 0, "center must be inside");
+        assert_eq!(cap.winding(&outside, &tolerance), 0, "antipode must be outside");
+    }
+}
@@ -0,0 +1,143 @@
+//! Boundary metadata surviving boolean operations.
+
+use num_traits::ToPrimitive;
+
+use crate::{batch::Operation, Edge, Geometry, IsClose, Shape, Vertex};
+
+/// A [`Shape`] whose boundaries carry an opaque payload each.
+///
+/// Boolean operations rebuild every boundary they cut, so geometry-level metadata such as
+/// feature identifiers cannot survive them in general. This wrapper carries the payloads of the
+/// boundaries an operation takes verbatim — those not intersecting the other operand — into
+/// the output, which is where such metadata remains meaningful.
+#[derive(Debug, Clone)]
+pub struct TaggedShape<T, M> {
+    /// The underlying shape.
+    pub shape: Shape<T>,
+    /// The payload attached to each boundary of the shape, in the same order.
+    pub tags: Vec<Option<M>>,
+}
+
+impl<T, M> TaggedShape<T, M>
+where
+    T: Geometry,
+{
+    /// Creates a new tagged shape with no payload attached to any boundary.
+    pub fn new(shape: Shape<T>) -> Self {
+        let tags = (0..shape.boundaries.len()).map(|_| None).collect();
+        Self { shape, tags }
+    }
+
+    /// Returns this tagged shape with the given payload attached to the boundary at the given
+    /// position.
+    ///
+    /// The tagged shape is left untouched if there is no boundary at that position.
+    pub fn with_tag(mut self, position: usize, metadata: M) -> Self {
+        if let Some(tag) = self.tags.get_mut(position) {
+            *tag = Some(metadata);
+        }
+
+        self
+    }
+
+    /// Returns the payload attached to the boundary at the given position, if any.
+    pub fn tag(&self, position: usize) -> Option<&M> {
+        self.tags.get(position).and_then(Option::as_ref)
+    }
+}
+
+impl<T, M> TaggedShape<T, M>
+where
+    T: Geometry + IsClose<Tolerance = <T::Vertex as IsClose>::Tolerance>,
+    T::Vertex: Copy + PartialEq + PartialOrd,
+    for<'a> T::Edge<'a>: Edge<'a>,
+    <T::Vertex as Vertex>::Scalar: Copy + PartialOrd + ToPrimitive,
+    <T::Vertex as IsClose>::Tolerance: Clone,
+    M: Clone,
+{
+    /// Returns the given boolean operation between this tagged shape and the other, or none if
+    /// it yields no output.
+    ///
+    /// Every output boundary close to an input boundary under the given tolerance is deemed
+    /// taken verbatim and keeps that boundary's payload, looking at this shape first and the
+    /// other second. Boundaries the operation rebuilds come out untagged.
+    pub fn clip(
+        &self,
+        other: &Self,
+        operation: Operation,
+        tolerance: <T::Vertex as IsClose>::Tolerance,
+    ) -> Option<Self> {
+        let output = match operation {
+            Operation::Union => self.shape.or_ref(&other.shape, tolerance.clone()),
+            Operation::Difference => self.shape.not_ref(&other.shape, tolerance.clone()),
+            Operation::Intersection => self.shape.and_ref(&other.shape, tolerance.clone()),
+        }?;
+
+        let tags = output
+            .boundaries
+            .iter()
+            .map(|boundary| {
+                [self, other]
+                    .into_iter()
+                    .flat_map(|operand| operand.shape.boundaries.iter().zip(&operand.tags))
+                    .find(|(original, _)| boundary.is_close(original, &tolerance))
+                    .and_then(|(_, tag)| tag.clone())
+            })
+            .collect();
+
+        Some(Self {
+            shape: output,
+            tags,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{batch::Operation, cartesian::Polygon, Shape, Tolerance};
+
+    use super::TaggedShape;
+
+    #[test]
+    fn tags_survive_untouched_boundaries() {
+        let subject = TaggedShape::new(Shape {
+            boundaries: vec![
+                Polygon::from(vec![[0., 0.], [2., 0.], [2., 2.], [0., 2.]]),
+                Polygon::from(vec![[10., 0.], [12., 0.], [12., 2.], [10., 2.]]),
+            ],
+        })
+        .with_tag(0, "overlapping")
+        .with_tag(1, "distant");
+
+        let clip = TaggedShape::new(Shape::new(vec![[1., 1.], [3., 1.], [3., 3.], [1., 3.]]))
+            .with_tag(0, "clip");
+
+        let got = subject
+            .clip(&clip, Operation::Union, Tolerance::default())
+            .expect("the union must yield an output");
+
+        let distant = got
+            .shape
+            .boundaries
+            .iter()
+            .position(|boundary| boundary.vertices.contains(&[10., 0.].into()))
+            .expect("the distant boundary must survive the union");
+
+        assert_eq!(
+            got.tag(distant),
+            Some(&"distant"),
+            "a boundary taken verbatim must keep its payload"
+        );
+
+        got.tags
+            .iter()
+            .enumerate()
+            .filter(|&(position, _)| position != distant)
+            .for_each(|(_, tag)| {
+                assert_eq!(
+                    tag, &None,
+                    "a boundary rebuilt by the operation must come out untagged"
+                );
+            });
+    }
+}
@@ -21,6 +21,11 @@ impl<T> Positive<T> {
 }
 
 /// The acceptable deviation between two values.
+///
+/// The `Default` tolerance is zero in both parts, meaning only exact matches compare as close.
+/// That is a safe assumption for coordinates obtained by construction, but measured or
+/// reprojected data carries rounding noise at the scale of its coordinates: such inputs are
+/// better served by [`Tolerance::for_extent`] than by defaulting blindly.
 #[derive(Debug, Default, Clone, Copy)]
 pub struct Tolerance<T> {
     /// The maximum allowed difference between two values.
@@ -29,6 +34,26 @@ pub struct Tolerance<T> {
     pub absolute: Positive<T>,
 }
 
+impl<T> Tolerance<T>
+where
+    T: Float + Signed,
+{
+    /// Returns a tolerance suited to data spanning the given extent.
+    ///
+    /// The extent is the magnitude of the largest coordinate the data may hold, such as the
+    /// farthest corner of its bounding box, or __π__ for spherical data. The relative part
+    /// absorbs a few dozen representation errors, while the absolute part scales that by the
+    /// extent so values near zero are compared at the precision the dataset actually carries.
+    pub fn for_extent(extent: T) -> Self {
+        let relative = T::epsilon() * T::from(32).unwrap_or_else(T::one);
+
+        Self {
+            relative: relative.into(),
+            absolute: (relative * extent.abs()).into(),
+        }
+    }
+}
+
 /// A value whose equality depends on a tolerance.
 pub trait IsClose {
     type Tolerance;
@@ -36,6 +61,21 @@ pub trait IsClose {
     /// Returns true if, and only if, this and the other are close enough given a
     /// tolerance; otherwise returns false.
     fn is_close(&self, other: &Self, tolerance: &Self::Tolerance) -> bool;
+
+    /// Returns the given tolerance with its parts uniformly scaled by the given factor.
+    ///
+    /// This is how the per-stage overrides of [`ClipOptions`] derive each stage's tolerance
+    /// from the operation-wide one. The default implementation returns the tolerance
+    /// unchanged, as suits tolerances carrying no scalable parts; values compared through
+    /// [`Tolerance`] override it so the overrides take effect.
+    ///
+    /// [`ClipOptions`]: crate::ClipOptions
+    fn scaled_tolerance(tolerance: &Self::Tolerance, _factor: f64) -> Self::Tolerance
+    where
+        Self::Tolerance: Clone,
+    {
+        tolerance.clone()
+    }
 }
 
 impl<T> IsClose for T
@@ -51,4 +91,63 @@ where
                 tolerance.absolute.0,
             )
     }
+
+    fn scaled_tolerance(tolerance: &Self::Tolerance, factor: f64) -> Self::Tolerance
+    where
+        Self::Tolerance: Clone,
+    {
+        let factor = Self::from(factor).unwrap_or_else(Self::one).abs();
+        Tolerance {
+            relative: Positive(tolerance.relative.0 * factor),
+            absolute: Positive(tolerance.absolute.0 * factor),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IsClose, Tolerance};
+
+    #[test]
+    fn scaled_tolerance_scales_both_parts() {
+        let tolerance = Tolerance {
+            relative: 1e-9.into(),
+            absolute: 1e-9.into(),
+        };
+
+        let scaled = f64::scaled_tolerance(&tolerance, 1e3);
+
+        assert!(
+            1_f64.is_close(&(1. + 1e-7), &scaled),
+            "the scaled relative part must absorb the deviation"
+        );
+        assert!(
+            0_f64.is_close(&1e-7, &scaled),
+            "the scaled absolute part must absorb the deviation"
+        );
+        assert!(
+            !1_f64.is_close(&(1. + 1e-7), &tolerance),
+            "the original tolerance must remain untouched"
+        );
+    }
+
+    #[test]
+    fn extent_scaled_tolerance_absorbs_rounding_noise() {
+        let tolerance = Tolerance::for_extent(1e6);
+
+        assert!(
+            1e6.is_close(&(1e6 + 1e-9), &tolerance),
+            "rounding noise at the extent must be absorbed"
+        );
+
+        assert!(
+            0_f64.is_close(&1e-11, &tolerance),
+            "values near zero must be compared at the extent's precision"
+        );
+
+        assert!(
+            !1e6.is_close(&(1e6 + 1.), &tolerance),
+            "deviations beyond rounding noise must remain distinct"
+        );
+    }
 }
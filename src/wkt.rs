@@ -0,0 +1,297 @@
+//! A WKT-like textual representation for shapes.
+//!
+//! The representation is meant for debugging: a failing shape can be printed, copy-pasted into
+//! an issue and parsed back in a test. Cartesian shapes follow the standard `POLYGON` layout,
+//! where every boundary is written as a closed ring. Spherical shapes extend that layout with
+//! the exterior point of each boundary, written after a semicolon just like in the
+//! `spherical_polygon!` macro.
+
+use std::{fmt, num::ParseFloatError, str::FromStr};
+
+/// The reason why a shape could not be parsed from its textual representation.
+#[derive(Debug, PartialEq, Eq)]
+pub enum WktError {
+    /// The representation does not match the expected `POLYGON ((…))` layout.
+    Layout,
+    /// A coordinate could not be parsed as a number.
+    Coordinate(ParseFloatError),
+}
+
+impl From<ParseFloatError> for WktError {
+    fn from(error: ParseFloatError) -> Self {
+        Self::Coordinate(error)
+    }
+}
+
+impl fmt::Display for WktError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Layout => write!(f, "malformed polygon layout"),
+            Self::Coordinate(error) => write!(f, "malformed coordinate: {error}"),
+        }
+    }
+}
+
+/// Returns the comma-separated groups delimited by the innermost parentheses of the given body.
+fn groups(body: &str) -> Result<Vec<&str>, WktError> {
+    let mut groups = Vec::new();
+    let mut rest = body;
+    while let Some(start) = rest.find('(') {
+        let after = &rest[start + 1..];
+        let end = after.find(')').ok_or(WktError::Layout)?;
+
+        groups.push(&after[..end]);
+        rest = &after[end + 1..];
+    }
+
+    if groups.is_empty() {
+        return Err(WktError::Layout);
+    }
+
+    Ok(groups)
+}
+
+/// Returns the body of the given `POLYGON (…)` representation.
+fn polygon_body(s: &str) -> Result<&str, WktError> {
+    s.trim()
+        .strip_prefix("POLYGON")
+        .and_then(|body| body.trim_start().strip_prefix('('))
+        .and_then(|body| body.strip_suffix(')'))
+        .ok_or(WktError::Layout)
+}
+
+/// Parses the given comma-separated list of coordinate pairs.
+fn pairs<T>(ring: &str) -> Result<Vec<[T; 2]>, WktError>
+where
+    T: FromStr<Err = ParseFloatError>,
+{
+    let vertices = ring
+        .split(',')
+        .map(|pair| {
+            let mut coordinates = pair.split_whitespace();
+            let first = coordinates.next().ok_or(WktError::Layout)?.parse()?;
+            let second = coordinates.next().ok_or(WktError::Layout)?.parse()?;
+            if coordinates.next().is_some() {
+                return Err(WktError::Layout);
+            }
+
+            Ok([first, second])
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if vertices.is_empty() {
+        return Err(WktError::Layout);
+    }
+
+    Ok(vertices)
+}
+
+#[cfg(feature = "cartesian")]
+mod cartesian {
+    use std::{fmt, num::ParseFloatError, str::FromStr};
+
+    use num_traits::{Float, Signed};
+
+    use crate::{cartesian::Polygon, Shape};
+
+    use super::WktError;
+
+    impl<T> fmt::Display for Shape<Polygon<T>>
+    where
+        T: fmt::Display,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "POLYGON (")?;
+            for (position, boundary) in self.boundaries.iter().enumerate() {
+                if position > 0 {
+                    write!(f, ", ")?;
+                }
+
+                write!(f, "(")?;
+                let closed = boundary.vertices.iter().chain(boundary.vertices.first());
+                for (position, point) in closed.enumerate() {
+                    if position > 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "{} {}", point.x, point.y)?;
+                }
+
+                write!(f, ")")?;
+            }
+
+            write!(f, ")")
+        }
+    }
+
+    impl<T> FromStr for Shape<Polygon<T>>
+    where
+        T: Signed + Float + FromStr<Err = ParseFloatError>,
+    {
+        type Err = WktError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let boundaries = super::groups(super::polygon_body(s)?)?
+                .into_iter()
+                .map(|ring| {
+                    let mut vertices = super::pairs::<T>(ring)?;
+                    if vertices.len() > 1 && vertices.first() == vertices.last() {
+                        vertices.pop();
+                    }
+
+                    Ok(vertices.into())
+                })
+                .collect::<Result<Vec<Polygon<T>>, WktError>>()?;
+
+            Ok(Shape { boundaries })
+        }
+    }
+}
+
+#[cfg(feature = "spherical")]
+mod spherical {
+    use std::{fmt, num::ParseFloatError, str::FromStr};
+
+    use num_traits::{Euclid, Float, FloatConst, Signed};
+
+    use crate::{spherical::Polygon, Shape};
+
+    use super::WktError;
+
+    impl<T> fmt::Display for Shape<Polygon<T>>
+    where
+        T: fmt::Display + Copy,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "POLYGON (")?;
+            for (position, boundary) in self.boundaries.iter().enumerate() {
+                if position > 0 {
+                    write!(f, ", ")?;
+                }
+
+                write!(f, "(")?;
+                for (position, point) in boundary.vertices.iter().enumerate() {
+                    if position > 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(
+                        f,
+                        "{} {}",
+                        point.inclination.into_inner(),
+                        point.azimuth.into_inner()
+                    )?;
+                }
+
+                write!(
+                    f,
+                    "; {} {})",
+                    boundary.exterior.inclination.into_inner(),
+                    boundary.exterior.azimuth.into_inner()
+                )?;
+            }
+
+            write!(f, ")")
+        }
+    }
+
+    impl<T> FromStr for Shape<Polygon<T>>
+    where
+        T: PartialOrd + Signed + Float + FloatConst + Euclid + FromStr<Err = ParseFloatError>,
+    {
+        type Err = WktError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let boundaries = super::groups(super::polygon_body(s)?)?
+                .into_iter()
+                .map(|ring| {
+                    let (vertices, exterior) = ring.split_once(';').ok_or(WktError::Layout)?;
+
+                    let vertices = super::pairs::<T>(vertices)?;
+                    let [exterior] = super::pairs::<T>(exterior)?[..] else {
+                        return Err(WktError::Layout);
+                    };
+
+                    Ok(Polygon::new(vertices, exterior))
+                })
+                .collect::<Result<Vec<Polygon<T>>, WktError>>()?;
+
+            Ok(Shape { boundaries })
+        }
+    }
+}
+
+#[cfg(all(test, feature = "cartesian"))]
+mod tests {
+    use crate::{cartesian::Polygon, Shape};
+
+    #[test]
+    fn shape_display_round_trip() {
+        struct Test {
+            name: &'static str,
+            shape: Shape<Polygon<f64>>,
+            want: &'static str,
+        }
+
+        vec![
+            Test {
+                name: "single boundary",
+                shape: Shape::new(vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]]),
+                want: "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0))",
+            },
+            Test {
+                name: "boundary with hole",
+                shape: Shape {
+                    boundaries: vec![
+                        vec![[0., 0.], [4., 0.], [4., 4.], [0., 4.]].into(),
+                        vec![[1., 1.], [1., 3.], [3., 3.], [3., 1.]].into(),
+                    ],
+                },
+                want: "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 1 3, 3 3, 3 1, 1 1))",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = test.shape.to_string();
+            assert_eq!(got, test.want, "{}", test.name);
+
+            let parsed: Shape<Polygon<f64>> = got.parse().expect(test.name);
+            assert_eq!(parsed, test.shape, "{}", test.name);
+        });
+    }
+
+    #[test]
+    fn malformed_shape_must_not_parse() {
+        struct Test {
+            name: &'static str,
+            s: &'static str,
+        }
+
+        vec![
+            Test {
+                name: "missing keyword",
+                s: "((0 0, 4 0, 4 4))",
+            },
+            Test {
+                name: "missing rings",
+                s: "POLYGON ()",
+            },
+            Test {
+                name: "unbalanced parentheses",
+                s: "POLYGON ((0 0, 4 0, 4 4)",
+            },
+            Test {
+                name: "malformed coordinate",
+                s: "POLYGON ((0 zero, 4 0, 4 4))",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert!(
+                test.s.parse::<Shape<Polygon<f64>>>().is_err(),
+                "{}",
+                test.name
+            );
+        });
+    }
+}